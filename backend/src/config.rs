@@ -1,5 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
 pub mod cartridge;
+pub mod console;
+pub mod label_format;
+pub mod part_catalog;
@@ -1,8 +1,9 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
 use anyhow::Error;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use serde_json;
 use std::{
@@ -140,6 +141,53 @@ impl BoardLayout {
     }
 }
 
+/// Memory sizes encoded in a Nintendo board label (e.g. DMG-KFDN-10)
+///
+/// The second letter of the board code gives the largest supported ROM and the third letter
+/// the RAM size. Numbered boards (e.g. DMG-A08) don't encode any sizes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoardMemory {
+    /// Maximum ROM capacity in bits
+    pub max_rom: u32,
+    /// RAM capacity in bits, Some(0) if the board has no RAM, or None if unknown
+    pub ram: Option<u32>,
+}
+
+impl BoardMemory {
+    /// ```
+    /// use gbhwdb_backend::config::cartridge::BoardMemory;
+    ///
+    /// let memory = BoardMemory::from_label("DMG-KFDN-10").unwrap();
+    /// assert_eq!(memory.max_rom, 8 * 1024 * 1024);
+    /// assert_eq!(memory.ram, Some(256 * 1024));
+    ///
+    /// let memory = BoardMemory::from_label("DMG-MC-DFCN-01").unwrap();
+    /// assert_eq!(memory.ram, Some(64 * 1024));
+    ///
+    /// assert_eq!(BoardMemory::from_label("DMG-AAA-03").unwrap().ram, Some(0));
+    /// assert_eq!(BoardMemory::from_label("DMG-A08-01"), None);
+    /// ```
+    pub fn from_label(label: &str) -> Option<BoardMemory> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| {
+            Regex::new(r#"^DMG-(M-|MC-)?[A-Z](?P<rom>[A-H])(?P<ram>[A-Z])[A-Z]?(\(K\))?(-|$)"#)
+                .unwrap()
+        });
+        let c = regex.captures(label)?;
+        let rom = c["rom"].as_bytes()[0] - b'A';
+        Some(BoardMemory {
+            max_rom: (256 * 1024) << rom,
+            ram: match &c["ram"] {
+                "A" => Some(0),
+                "C" => Some(64 * 1024),
+                "D" => Some(256 * 1024),
+                "E" => Some(1024 * 1024),
+                _ => None,
+            },
+        })
+    }
+}
+
 pub fn load_cfgs<P: AsRef<Path>>(path: P) -> Result<BTreeMap<String, GameConfig>, Error> {
     let file = File::open(path)?;
     let file = BufReader::new(file);
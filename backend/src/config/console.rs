@@ -0,0 +1,163 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use crate::{parser::PartFamily, Console};
+
+/// Location of a parsed label in console metadata, and the part families that may parse it
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LabelSlot {
+    /// JSON pointer to the label string, e.g. "/mainboard/u1/label"
+    pub pointer: &'static str,
+    pub families: &'static [PartFamily],
+}
+
+const fn slot(pointer: &'static str, families: &'static [PartFamily]) -> LabelSlot {
+    LabelSlot { pointer, families }
+}
+
+const LCD_SCREEN: [LabelSlot; 3] = [
+    slot("/screen/label", &[PartFamily::LcdScreen]),
+    slot("/screen/column_driver/label", &[PartFamily::LcdChip]),
+    slot("/screen/row_driver/label", &[PartFamily::LcdChip]),
+];
+
+const DMG_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::Gen1Soc]),
+    slot("/mainboard/u2/label", &[PartFamily::Ram]),
+    slot("/mainboard/u3/label", &[PartFamily::Ram]),
+    slot("/mainboard/u4/label", &[PartFamily::DmgAmp]),
+    slot("/mainboard/x1/label", &[PartFamily::Crystal4MiHz]),
+    slot("/mainboard/stamp", &[PartFamily::DmgStamp]),
+    slot("/lcd_board/chip/label", &[PartFamily::DmgReg]),
+    slot("/lcd_board/stamp", &[PartFamily::DmgStamp]),
+    slot("/lcd_board/screen/label", &[PartFamily::LcdScreen]),
+    slot(
+        "/lcd_board/screen/column_driver/label",
+        &[PartFamily::LcdChip],
+    ),
+    slot("/lcd_board/screen/row_driver/label", &[PartFamily::LcdChip]),
+];
+
+const SGB_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::Gen1Soc]),
+    slot("/mainboard/u2/label", &[PartFamily::Icd2]),
+    slot("/mainboard/u3/label", &[PartFamily::Ram]),
+    slot("/mainboard/u4/label", &[PartFamily::Ram]),
+    slot("/mainboard/u5/label", &[PartFamily::SgbRom]),
+    slot("/mainboard/u6/label", &[PartFamily::Cic]),
+];
+
+const MGB_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::Gen2Soc]),
+    slot("/mainboard/u2/label", &[PartFamily::Ram]),
+    slot("/mainboard/u3/label", &[PartFamily::MgbAmp]),
+    slot("/mainboard/u4/label", &[PartFamily::DmgReg]),
+    slot("/mainboard/x1/label", &[PartFamily::Crystal4MiHz]),
+    slot("/mainboard/stamp", &[PartFamily::DmgStamp]),
+    LCD_SCREEN[0],
+    LCD_SCREEN[1],
+    LCD_SCREEN[2],
+];
+
+const MGL_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::Gen2Soc]),
+    slot("/mainboard/u2/label", &[PartFamily::Ram]),
+    slot("/mainboard/u3/label", &[PartFamily::MgbAmp]),
+    slot("/mainboard/u4/label", &[PartFamily::DmgReg]),
+    slot("/mainboard/x1/label", &[PartFamily::Crystal4MiHz]),
+    slot("/mainboard/t1/label", &[PartFamily::MglTransformer]),
+    slot("/mainboard/stamp", &[PartFamily::CgbStamp]),
+    LCD_SCREEN[0],
+    LCD_SCREEN[1],
+    LCD_SCREEN[2],
+];
+
+const SGB2_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::Gen2Soc]),
+    slot("/mainboard/u2/label", &[PartFamily::Icd2]),
+    slot("/mainboard/u3/label", &[PartFamily::Ram]),
+    slot("/mainboard/u4/label", &[PartFamily::SgbRom]),
+    slot("/mainboard/u5/label", &[PartFamily::Cic]),
+    slot("/mainboard/coil1/label", &[PartFamily::Coil]),
+    slot("/mainboard/xtal1/label", &[PartFamily::Crystal20MiHz]),
+];
+
+const CGB_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::CgbSoc]),
+    slot("/mainboard/u2/label", &[PartFamily::Ram]),
+    slot("/mainboard/u3/label", &[PartFamily::MgbAmp]),
+    slot("/mainboard/u4/label", &[PartFamily::CgbReg]),
+    slot("/mainboard/x1/label", &[PartFamily::Crystal8MiHz]),
+    // early boards use the DMG-era stamp format
+    slot(
+        "/mainboard/stamp",
+        &[PartFamily::DmgStamp, PartFamily::CgbStamp],
+    ),
+];
+
+const AGB_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::AgbSocQfp128]),
+    slot("/mainboard/u2/label", &[PartFamily::SramTsop1_48]),
+    slot("/mainboard/u3/label", &[PartFamily::AgbReg]),
+    slot("/mainboard/u4/label", &[PartFamily::AgbPmic]),
+    slot("/mainboard/u6/label", &[PartFamily::AgbAmp]),
+    slot("/mainboard/x1/label", &[PartFamily::Crystal4MiHz]),
+    slot("/mainboard/stamp", &[PartFamily::CgbStamp]),
+];
+
+const AGS_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::AgbSocQfp156]),
+    slot("/mainboard/u2/label", &[PartFamily::SramTsop1_48]),
+    // later boards have a PMIC in place of the amplifier
+    slot(
+        "/mainboard/u3/label",
+        &[PartFamily::AgbAmp, PartFamily::AgsPmicNew],
+    ),
+    slot("/mainboard/u4/label", &[PartFamily::AgsPmicOld]),
+    slot("/mainboard/u5/label", &[PartFamily::AgsChargeController]),
+    slot("/mainboard/x1/label", &[PartFamily::Crystal4MiHz]),
+];
+
+const GBS_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u2/label", &[PartFamily::AgbSocQfp128]),
+    slot("/mainboard/u3/label", &[PartFamily::SramTsop1_48]),
+    slot("/mainboard/u4/label", &[PartFamily::GbsDol]),
+    slot("/mainboard/u5/label", &[PartFamily::GbsReg]),
+    slot("/mainboard/u6/label", &[PartFamily::GbsReg]),
+    slot("/mainboard/y1/label", &[PartFamily::Crystal32MiHz]),
+    slot("/mainboard/stamp", &[PartFamily::CgbStamp]),
+];
+
+const OXY_SLOTS: &[LabelSlot] = &[
+    slot("/mainboard/u1/label", &[PartFamily::AgbSocBga]),
+    slot("/mainboard/u2/label", &[PartFamily::OxyPmic]),
+    slot("/mainboard/u4/label", &[PartFamily::OxyU4]),
+    slot("/mainboard/u5/label", &[PartFamily::OxyU5]),
+];
+
+impl Console {
+    /// Parsed labels in the metadata of this console
+    ///
+    /// ```
+    /// use gbhwdb_backend::{parser::PartFamily, Console};
+    ///
+    /// let slot = Console::Dmg.label_slots()[0];
+    /// assert_eq!(slot.pointer, "/mainboard/u1/label");
+    /// assert_eq!(slot.families, [PartFamily::Gen1Soc]);
+    /// ```
+    pub fn label_slots(&self) -> &'static [LabelSlot] {
+        match self {
+            Console::Dmg => DMG_SLOTS,
+            Console::Sgb => SGB_SLOTS,
+            Console::Mgb => MGB_SLOTS,
+            Console::Mgl => MGL_SLOTS,
+            Console::Sgb2 => SGB2_SLOTS,
+            Console::Cgb => CGB_SLOTS,
+            Console::Agb => AGB_SLOTS,
+            Console::Ags => AGS_SLOTS,
+            Console::Gbs => GBS_SLOTS,
+            Console::Oxy => OXY_SLOTS,
+        }
+    }
+}
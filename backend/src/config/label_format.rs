@@ -0,0 +1,261 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{anyhow, bail, Error};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, fs::File, io::BufReader, path::Path, sync::OnceLock};
+
+use crate::parser::{
+    Country, DateCodeConvention, Identification, Manufacturer, PartDateCode, PartFamily,
+};
+
+/// Label format defined in a data file instead of a hand-written parser
+///
+/// The regex is compiled in the same whitespace-insensitive mode as hand-written parsers, so
+/// literal spaces must be written as `\ `. Named captures are interpreted as follows:
+///
+/// - `date`: date code, decoded with the `date_code` convention
+/// - `kind`: part kind, used if `kind` is not given
+/// - `manufacturer`: manufacturer name or alias, used if `manufacturer` is not given
+/// - `rom_code`: ROM code
+/// - `country`: country of origin
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct LabelFormat {
+    /// Unique name, e.g. "toshiba_tc74lvx04ft"
+    pub name: String,
+    /// Id of the part family this format belongs to, e.g. "hex_inverter"
+    pub family: String,
+    pub regex: String,
+    /// Part kind, which may refer to captures with `$name`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// Manufacturer id, name, or alias
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<String>,
+    /// Date code convention name, e.g. "YYWW"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub date_code: Option<String>,
+    /// Labels that must be accepted by this format
+    #[serde(default)]
+    pub examples: Vec<String>,
+}
+
+/// Label format that has been validated and compiled
+#[derive(Clone, Debug)]
+pub struct CompiledLabelFormat {
+    pub name: String,
+    /// Name as listed among the parser names of the family, e.g. "config:toshiba_tc74lvx04ft"
+    pub qualified_name: String,
+    pub family: PartFamily,
+    regex: Regex,
+    kind: Option<String>,
+    manufacturer: Option<Manufacturer>,
+    date_code: Option<DateCodeConvention>,
+    examples: Vec<String>,
+}
+
+impl CompiledLabelFormat {
+    /// Validates and compiles a label format
+    ///
+    /// ```
+    /// use gbhwdb_backend::config::label_format::{CompiledLabelFormat, LabelFormat};
+    /// use gbhwdb_backend::parser::{Manufacturer, PartFamily, Year};
+    ///
+    /// let format = CompiledLabelFormat::compile(LabelFormat {
+    ///     name: "toshiba_tc74lvx04ft".to_owned(),
+    ///     family: "hex_inverter".to_owned(),
+    ///     regex: r#"^LVX\ 04\ (?P<date>[0-9]\ [0-9]{2})$"#.to_owned(),
+    ///     kind: Some("TC74LVX04FT".to_owned()),
+    ///     manufacturer: Some("toshiba".to_owned()),
+    ///     date_code: Some("YWW".to_owned()),
+    ///     examples: vec!["LVX 04 8 12".to_owned()],
+    /// })
+    /// .unwrap();
+    /// let part = format.parse("LVX 04 9 45").unwrap();
+    /// assert_eq!(part.family, PartFamily::HexInverter);
+    /// assert_eq!(part.kind.as_deref(), Some("TC74LVX04FT"));
+    /// assert_eq!(part.manufacturer, Some(Manufacturer::Toshiba));
+    /// assert_eq!(part.date_code.year, Some(Year::Partial(9)));
+    /// assert!(format.parse("LVX 04").is_err());
+    /// ```
+    pub fn compile(format: LabelFormat) -> Result<CompiledLabelFormat, Error> {
+        let name = format.name;
+        let family = PartFamily::from_id(&format.family)
+            .ok_or_else(|| anyhow!("{name}: unknown part family {}", format.family))?;
+        let regex = RegexBuilder::new(&format.regex)
+            .ignore_whitespace(true)
+            .build()
+            .map_err(|err| anyhow!("{name}: invalid regex: {err}"))?;
+        let captures = regex.capture_names().flatten().collect::<HashSet<_>>();
+        for capture in &captures {
+            if !["date", "kind", "manufacturer", "rom_code", "country"].contains(capture) {
+                bail!("{name}: unsupported capture {capture}");
+            }
+        }
+        let manufacturer = format
+            .manufacturer
+            .map(|text| {
+                Manufacturer::from_alias(&text)
+                    .ok_or_else(|| anyhow!("{name}: unknown manufacturer {text}"))
+            })
+            .transpose()?;
+        let date_code = format
+            .date_code
+            .map(|text| {
+                DateCodeConvention::from_name(&text)
+                    .ok_or_else(|| anyhow!("{name}: unknown date code convention {text}"))
+            })
+            .transpose()?;
+        if date_code.is_some() != captures.contains("date") {
+            bail!("{name}: a date code convention requires a date capture and vice versa");
+        }
+        if format.kind.is_some() && captures.contains("kind") {
+            bail!("{name}: kind is defined both literally and as a capture");
+        }
+        if manufacturer.is_some() && captures.contains("manufacturer") {
+            bail!("{name}: manufacturer is defined both literally and as a capture");
+        }
+        let compiled = CompiledLabelFormat {
+            qualified_name: format!("config:{name}"),
+            name,
+            family,
+            regex,
+            kind: format.kind,
+            manufacturer,
+            date_code,
+            examples: format.examples,
+        };
+        for example in &compiled.examples {
+            compiled
+                .parse(example)
+                .map_err(|err| anyhow!("{}: example {example:?} failed: {err}", compiled.name))?;
+        }
+        Ok(compiled)
+    }
+    pub fn parse(&self, label: &str) -> Result<Identification, String> {
+        let c = self
+            .regex
+            .captures(label)
+            .ok_or_else(|| format!("Label does not match {}: {}", self.name, label))?;
+        let kind = match &self.kind {
+            Some(template) => {
+                let mut kind = String::new();
+                c.expand(template, &mut kind);
+                Some(kind)
+            }
+            None => c.name("kind").map(|m| m.as_str().to_owned()),
+        };
+        let manufacturer = match (self.manufacturer, c.name("manufacturer")) {
+            (Some(manufacturer), _) => Some(manufacturer),
+            (None, Some(m)) => Some(
+                Manufacturer::from_alias(m.as_str())
+                    .ok_or_else(|| format!("Unknown manufacturer: {}", m.as_str()))?,
+            ),
+            (None, None) => None,
+        };
+        let date_code = match (self.date_code, c.name("date")) {
+            (Some(convention), Some(m)) => convention.decode(m.as_str())?,
+            _ => PartDateCode::default(),
+        };
+        Ok(Identification {
+            family: self.family,
+            kind,
+            manufacturer,
+            rom_code: c.name("rom_code").map(|m| m.as_str().to_owned()),
+            date_code,
+            country: c
+                .name("country")
+                .and_then(|m| Country::from_alias(m.as_str())),
+        })
+    }
+    pub fn examples(&self) -> &[String] {
+        &self.examples
+    }
+}
+
+/// Collection of label formats loaded from a data file
+#[derive(Clone, Debug, Default)]
+pub struct LabelFormats {
+    formats: Vec<CompiledLabelFormat>,
+}
+
+impl LabelFormats {
+    pub fn compile(formats: Vec<LabelFormat>) -> Result<LabelFormats, Error> {
+        let mut names = HashSet::new();
+        let formats = formats
+            .into_iter()
+            .map(|format| {
+                if !names.insert(format.name.clone()) {
+                    bail!("Duplicate label format {}", format.name);
+                }
+                CompiledLabelFormat::compile(format)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(LabelFormats { formats })
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &CompiledLabelFormat> {
+        self.formats.iter()
+    }
+    pub fn for_family(&self, family: PartFamily) -> impl Iterator<Item = &CompiledLabelFormat> {
+        self.formats
+            .iter()
+            .filter(move |format| format.family == family)
+    }
+}
+
+static INSTALLED: OnceLock<LabelFormats> = OnceLock::new();
+
+impl LabelFormats {
+    /// Makes the formats available to [`PartFamily::identify`] and the other parser lookups
+    /// for the rest of the process
+    ///
+    /// Formats can only be installed once.
+    ///
+    /// ```
+    /// use gbhwdb_backend::config::label_format::load_label_formats;
+    /// use gbhwdb_backend::parser::PartFamily;
+    ///
+    /// let path = "../config/label-formats.json";
+    /// assert!(!PartFamily::Eeprom.parser_names().contains(&"config:lcs5"));
+    /// load_label_formats(path).unwrap().install().unwrap();
+    /// assert!(PartFamily::Eeprom.parser_names().contains(&"config:lcs5"));
+    /// assert!(load_label_formats(path).unwrap().install().is_err());
+    /// ```
+    pub fn install(self) -> Result<(), Error> {
+        INSTALLED
+            .set(self)
+            .map_err(|_| anyhow!("Label formats are already installed"))
+    }
+}
+
+/// Label formats installed with [`LabelFormats::install`], or no formats at all
+pub fn installed_label_formats() -> &'static LabelFormats {
+    static EMPTY: LabelFormats = LabelFormats {
+        formats: Vec::new(),
+    };
+    INSTALLED.get().unwrap_or(&EMPTY)
+}
+
+pub fn load_label_formats<P: AsRef<Path>>(path: P) -> Result<LabelFormats, Error> {
+    let file = File::open(path)?;
+    let file = BufReader::new(file);
+    let formats: Vec<LabelFormat> = serde_json::from_reader(file)?;
+    LabelFormats::compile(formats)
+}
+
+#[test]
+fn test_label_formats_config() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("../config/label-formats.json");
+    let formats = load_label_formats(&path).unwrap();
+    // formats duplicating a hand-written parser must agree with it, so the parser can be removed
+    for format in formats.iter() {
+        for example in format.examples() {
+            if let Ok(expected) = format.family.identify(example) {
+                assert_eq!(format.parse(example), Ok(expected), "{}", format.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::{collections::HashMap, sync::OnceLock};
+
+use crate::parser::Manufacturer;
+
+/// Known facts about a chip, keyed by the part kind produced by the parsers
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PartInfo {
+    /// Part kind, e.g. "BA6129A"
+    pub kind: &'static str,
+    /// What the chip does, e.g. "Supervisor & reset"
+    pub function: &'static str,
+    /// Package, e.g. "SOP-28", if known
+    pub package: Option<&'static str>,
+    /// Manufacturer, if all chips of this kind are made by the same one
+    pub manufacturer: Option<Manufacturer>,
+    /// Chips with the same pinout family are pin-compatible
+    pub pinout: Option<&'static str>,
+    /// Path of a local datasheet under site/static, e.g. "datasheets/ADXL202.pdf"
+    pub datasheet: Option<&'static str>,
+}
+
+impl PartInfo {
+    const fn new(kind: &'static str, function: &'static str) -> PartInfo {
+        PartInfo {
+            kind,
+            function,
+            package: None,
+            manufacturer: None,
+            pinout: None,
+            datasheet: None,
+        }
+    }
+    const fn package(self, package: &'static str) -> PartInfo {
+        PartInfo {
+            package: Some(package),
+            ..self
+        }
+    }
+    const fn manufacturer(self, manufacturer: Manufacturer) -> PartInfo {
+        PartInfo {
+            manufacturer: Some(manufacturer),
+            ..self
+        }
+    }
+    const fn pinout(self, pinout: &'static str) -> PartInfo {
+        PartInfo {
+            pinout: Some(pinout),
+            ..self
+        }
+    }
+    /// Looks up a part kind in the catalog
+    ///
+    /// ```
+    /// use gbhwdb_backend::{config::part_catalog::PartInfo, parser::Manufacturer};
+    ///
+    /// let info = PartInfo::from_kind("LH5164AN-10L").unwrap();
+    /// assert_eq!(info.function, "SRAM");
+    /// assert_eq!(info.package, Some("SOP-28"));
+    /// assert_eq!(info.manufacturer, Some(Manufacturer::Sharp));
+    ///
+    /// assert_eq!(PartInfo::from_kind("CPU AGB E").unwrap().package, Some("BGA"));
+    /// assert_eq!(PartInfo::from_kind("XYZ"), None);
+    /// ```
+    pub fn from_kind(kind: &str) -> Option<&'static PartInfo> {
+        static MAP: OnceLock<HashMap<&'static str, &'static PartInfo>> = OnceLock::new();
+        let map = MAP.get_or_init(|| CATALOG.iter().map(|info| (info.kind, info)).collect());
+        map.get(kind).copied()
+    }
+    /// All parts in the catalog
+    pub fn all() -> &'static [PartInfo] {
+        CATALOG
+    }
+}
+
+const SOC: &str = "SoC";
+const MAPPER: &str = "Mapper";
+const SRAM: &str = "SRAM";
+const MASK_ROM: &str = "Mask ROM";
+const SUPERVISOR_RESET: &str = "Supervisor & reset";
+const CRYSTAL: &str = "Crystal";
+const REGULATOR: &str = "Regulator";
+const AMPLIFIER: &str = "Audio amplifier";
+
+const SRAM_28: &str = "JEDEC SRAM 28-pin";
+const SRAM_48: &str = "JEDEC SRAM 48-pin ×16";
+
+static CATALOG: &[PartInfo] = &[
+    // SoCs
+    PartInfo::new("DMG-CPU", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("DMG-CPU A", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("DMG-CPU B", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("DMG-CPU C", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("DMG-CPU B (blob)", SOC)
+        .package("COB")
+        .manufacturer(Manufacturer::Sharp),
+    PartInfo::new("DMG-CPU C (blob)", SOC)
+        .package("COB")
+        .manufacturer(Manufacturer::Sharp),
+    PartInfo::new("SGB-CPU 01", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("CPU MGB", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("CPU SGB2", SOC)
+        .package("QFP-80")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("DMG-CPU"),
+    PartInfo::new("CPU CGB", SOC)
+        .package("QFP-128")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("CPU CGB"),
+    PartInfo::new("CPU CGB A", SOC)
+        .package("QFP-128")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("CPU CGB"),
+    PartInfo::new("CPU CGB B", SOC)
+        .package("QFP-128")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("CPU CGB"),
+    PartInfo::new("CPU CGB C", SOC)
+        .package("QFP-128")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("CPU CGB"),
+    PartInfo::new("CPU CGB D", SOC)
+        .package("QFP-128")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("CPU CGB"),
+    PartInfo::new("CPU CGB E", SOC)
+        .package("QFP-128")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout("CPU CGB"),
+    PartInfo::new("CPU AGB", SOC)
+        .package("QFP-128")
+        .pinout("CPU AGB (QFP-128)"),
+    PartInfo::new("CPU AGB A", SOC)
+        .package("QFP-128")
+        .pinout("CPU AGB (QFP-128)"),
+    PartInfo::new("CPU AGB A E", SOC)
+        .package("QFP-128")
+        .pinout("CPU AGB (QFP-128)"),
+    PartInfo::new("CPU AGB B", SOC)
+        .package("QFP-156")
+        .pinout("CPU AGB (QFP-156)"),
+    PartInfo::new("CPU AGB B E", SOC)
+        .package("QFP-156")
+        .pinout("CPU AGB (QFP-156)"),
+    PartInfo::new("CPU AGB E", SOC).package("BGA"),
+    // Mappers
+    PartInfo::new("MBC1", MAPPER).pinout("MBC1"),
+    PartInfo::new("MBC1A", MAPPER).pinout("MBC1"),
+    PartInfo::new("MBC1B", MAPPER).pinout("MBC1"),
+    PartInfo::new("MBC1B1", MAPPER).pinout("MBC1"),
+    PartInfo::new("MBC2", MAPPER).pinout("MBC2"),
+    PartInfo::new("MBC2A", MAPPER).pinout("MBC2"),
+    PartInfo::new("MBC3", MAPPER).pinout("MBC3"),
+    PartInfo::new("MBC3A", MAPPER).pinout("MBC3"),
+    PartInfo::new("MBC3B", MAPPER).pinout("MBC3"),
+    PartInfo::new("MBC30", MAPPER).pinout("MBC3"),
+    PartInfo::new("MBC5", MAPPER),
+    PartInfo::new("MBC6", MAPPER),
+    PartInfo::new("MBC7", MAPPER),
+    PartInfo::new("MMM01", MAPPER),
+    PartInfo::new("HuC-1", MAPPER).manufacturer(Manufacturer::Hudson),
+    PartInfo::new("HuC-1A", MAPPER).manufacturer(Manufacturer::Hudson),
+    PartInfo::new("HuC-3", MAPPER).manufacturer(Manufacturer::Hudson),
+    // SRAM
+    PartInfo::new("LH5164AN-10L", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout(SRAM_28),
+    PartInfo::new("LH5164LN-10", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout(SRAM_28),
+    PartInfo::new("LH5264N4", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout(SRAM_28),
+    PartInfo::new("LH52256CN-10LL", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Sharp)
+        .pinout(SRAM_28),
+    PartInfo::new("LH52256CVT", SRAM)
+        .package("TSOP-28")
+        .manufacturer(Manufacturer::Sharp),
+    PartInfo::new("HY6264ALLJ-10", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Hyundai)
+        .pinout(SRAM_28),
+    PartInfo::new("BR6265BF-10SL", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Rohm)
+        .pinout(SRAM_28),
+    PartInfo::new("XLJ6265BF-10SL", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Rohm)
+        .pinout(SRAM_28),
+    PartInfo::new("BR62256F-70LL", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Rohm)
+        .pinout(SRAM_28),
+    PartInfo::new("W2465S-70LL", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Winbond)
+        .pinout(SRAM_28),
+    PartInfo::new("GM76C256CLLFW70", SRAM)
+        .package("SOP-28")
+        .pinout(SRAM_28),
+    PartInfo::new("BS62LV256SC-70", SRAM)
+        .package("SOP-28")
+        .manufacturer(Manufacturer::Bsi)
+        .pinout(SRAM_28),
+    PartInfo::new("MB82D12160-10FN", SRAM)
+        .package("TSOP-I-48")
+        .manufacturer(Manufacturer::Fujitsu)
+        .pinout(SRAM_48),
+    PartInfo::new("μPD442012AGY-BB85X-MJH", SRAM)
+        .package("TSOP-I-48")
+        .manufacturer(Manufacturer::Nec)
+        .pinout(SRAM_48),
+    PartInfo::new("μPD442012AGY-BC85X-MJH", SRAM)
+        .package("TSOP-I-48")
+        .manufacturer(Manufacturer::Nec)
+        .pinout(SRAM_48),
+    PartInfo::new("M68AS128DL70N6", SRAM)
+        .package("TSOP-I-48")
+        .manufacturer(Manufacturer::StMicro)
+        .pinout(SRAM_48),
+    PartInfo::new("HY62LF16206A-LT12C", SRAM)
+        .package("TSOP-I-48")
+        .manufacturer(Manufacturer::Hynix)
+        .pinout(SRAM_48),
+    // Mask ROM
+    PartInfo::new("MSM538011E", MASK_ROM).manufacturer(Manufacturer::Oki),
+    PartInfo::new("MX23C1603-12A", MASK_ROM).manufacturer(Manufacturer::Macronix),
+    PartInfo::new("MX23C8005-12", MASK_ROM).manufacturer(Manufacturer::Macronix),
+    PartInfo::new("TC531001CF", MASK_ROM).manufacturer(Manufacturer::Toshiba),
+    // Supervisor & reset
+    PartInfo::new("MM1026A", SUPERVISOR_RESET).manufacturer(Manufacturer::Mitsumi),
+    PartInfo::new("MM1134A", SUPERVISOR_RESET).manufacturer(Manufacturer::Mitsumi),
+    PartInfo::new("BA6129", SUPERVISOR_RESET).manufacturer(Manufacturer::Rohm),
+    PartInfo::new("BA6129A", SUPERVISOR_RESET).manufacturer(Manufacturer::Rohm),
+    PartInfo::new("BA6735", SUPERVISOR_RESET).manufacturer(Manufacturer::Rohm),
+    PartInfo::new("M62021P", SUPERVISOR_RESET).manufacturer(Manufacturer::Mitsubishi),
+    // Crystals
+    PartInfo::new("32.768 kHz", CRYSTAL),
+    PartInfo::new("4.194304 MHz", CRYSTAL),
+    PartInfo::new("8.388608 MHz", CRYSTAL),
+    PartInfo::new("33.554432 MHz", CRYSTAL),
+    // Regulators and amplifiers
+    PartInfo::new("IR3E02", REGULATOR).manufacturer(Manufacturer::Sharp),
+    PartInfo::new("IR3E06N", REGULATOR).manufacturer(Manufacturer::Sharp),
+    PartInfo::new("IR3E09N", REGULATOR).manufacturer(Manufacturer::Sharp),
+    PartInfo::new("IR3R40", AMPLIFIER).manufacturer(Manufacturer::Sharp),
+    PartInfo::new("IR3R53N", AMPLIFIER).manufacturer(Manufacturer::Sharp),
+    PartInfo::new("IR3R56N", AMPLIFIER).manufacturer(Manufacturer::Sharp),
+    PartInfo::new("IR3R60N", AMPLIFIER).manufacturer(Manufacturer::Sharp),
+    // Other
+    PartInfo::new("ADXL202JQC", "Accelerometer")
+        .package("CERPAK-14")
+        .manufacturer(Manufacturer::Analog),
+    PartInfo::new("TC74LVX04FT", "Hex inverter")
+        .package("TSSOP-14")
+        .manufacturer(Manufacturer::Toshiba)
+        .pinout("74x04"),
+    PartInfo::new("LC56", "Serial EEPROM").pinout("93C56"),
+    PartInfo::new("F411A", "CIC").manufacturer(Manufacturer::Sharp),
+];
+
+#[test]
+fn test_unique_kinds() {
+    use std::collections::HashSet;
+    let mut kinds = HashSet::new();
+    for info in PartInfo::all() {
+        assert!(kinds.insert(info.kind), "{}", info.kind);
+    }
+}
@@ -0,0 +1,429 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::fmt;
+
+use crate::{
+    config::cartridge::{BoardLayout, BoardMemory, PartRole, PartRoleConfig},
+    input,
+    parser::{
+        self, chip_spec::format_capacity, normalize::normalize_with, ChipSpec, PartFamily, RomCode,
+        RomCodeMismatch,
+    },
+    Console,
+};
+
+/// Kind of a metadata.json document
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DocumentKind {
+    Console(Console),
+    Cartridge,
+}
+
+impl DocumentKind {
+    /// Parses a console code (e.g. "DMG") or "cartridge"
+    ///
+    /// ```
+    /// use gbhwdb_backend::{diagnostics::DocumentKind, Console};
+    ///
+    /// assert_eq!(DocumentKind::from_code("CGB"), Some(DocumentKind::Console(Console::Cgb)));
+    /// assert_eq!(DocumentKind::from_code("cartridge"), Some(DocumentKind::Cartridge));
+    /// assert_eq!(DocumentKind::from_code("NES"), None);
+    /// ```
+    pub fn from_code(code: &str) -> Option<DocumentKind> {
+        if code.eq_ignore_ascii_case("cartridge") {
+            return Some(DocumentKind::Cartridge);
+        }
+        Console::ALL
+            .into_iter()
+            .find(|console| console.code().eq_ignore_ascii_case(code))
+            .map(DocumentKind::Console)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// Problem found in a metadata document or a single label
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// JSON pointer to the problematic value, or an empty string for the whole document
+    pub pointer: String,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(pointer: &str, message: String) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            pointer: pointer.to_owned(),
+            message,
+        }
+    }
+    fn warning(pointer: &str, message: String) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Warning,
+            pointer: pointer.to_owned(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        if self.pointer.is_empty() {
+            write!(f, "{severity}: {}", self.message)
+        } else {
+            write!(f, "{severity}: {}: {}", self.pointer, self.message)
+        }
+    }
+}
+
+/// Parsed label in a metadata document, along with the part families that should parse it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DocumentLabel {
+    /// JSON pointer to the label, e.g. "/mainboard/u1/label"
+    pub pointer: String,
+    /// Empty if the part role has no parser
+    pub families: Vec<PartFamily>,
+    /// None if the part is present but has no label
+    pub label: Option<String>,
+}
+
+/// Finds all labels that should be parsed in a metadata document
+pub fn document_labels(kind: DocumentKind, json: &Value) -> Result<Vec<DocumentLabel>, String> {
+    let mut labels = Vec::new();
+    match kind {
+        DocumentKind::Console(console) => {
+            for slot in console.label_slots() {
+                if let Some(label) = find_label(json, slot.pointer) {
+                    labels.push(DocumentLabel {
+                        pointer: slot.pointer.to_owned(),
+                        families: slot.families.to_vec(),
+                        label,
+                    });
+                }
+            }
+        }
+        DocumentKind::Cartridge => {
+            let board_label = json
+                .pointer("/board/label")
+                .and_then(Value::as_str)
+                .ok_or_else(|| "Missing board label".to_owned())?;
+            let layout = BoardLayout::from_label(board_label)
+                .ok_or_else(|| format!("Unknown board {board_label}"))?;
+            for (designator, role) in PartRoleConfig::from(layout) {
+                let pointer = format!("/board/{}/label", designator.as_str().to_lowercase());
+                if let Some(label) = find_label(json, &pointer) {
+                    labels.push(DocumentLabel {
+                        pointer,
+                        families: PartFamily::for_cartridge_role(layout, role)
+                            .into_iter()
+                            .collect(),
+                        label,
+                    });
+                }
+            }
+        }
+    }
+    Ok(labels)
+}
+
+/// Returns Some(None) if the part exists without a label
+fn find_label(json: &Value, pointer: &str) -> Option<Option<String>> {
+    match json.pointer(pointer) {
+        Some(Value::String(label)) => Some(Some(label.clone())),
+        Some(_) => None,
+        None => pointer
+            .strip_suffix("/label")
+            .and_then(|part| json.pointer(part))
+            .filter(|part| part.is_object())
+            .map(|_| None),
+    }
+}
+
+/// Checks a single label against the part families that should parse it
+///
+/// ```
+/// use gbhwdb_backend::{diagnostics::{check_label, Severity}, parser::PartFamily};
+///
+/// let diagnostics = check_label("/board/u2/label", &[PartFamily::Mapper], "MBC5 LZ9GB31 AL23 A");
+/// assert!(diagnostics.is_empty());
+///
+/// let diagnostics = check_label("/board/u2/label", &[PartFamily::Mapper], "MBC5  LZ9GB31 AL23 A");
+/// assert_eq!(diagnostics[0].severity, Severity::Warning);
+///
+/// let diagnostics = check_label("/board/u2/label", &[PartFamily::Ram], "MBC5 LZ9GB31 AL23 A");
+/// assert_eq!(diagnostics[0].severity, Severity::Error);
+/// assert!(diagnostics[0].message.contains("looks like Mapper"));
+/// ```
+pub fn check_label(pointer: &str, families: &[PartFamily], label: &str) -> Vec<Diagnostic> {
+    if families.iter().any(|family| family.identify(label).is_ok()) {
+        return Vec::new();
+    }
+    let normalized = families
+        .iter()
+        .find_map(|family| normalize_with(label, |label| family.identify(label)).ok());
+    if let Some((_, normalized)) = normalized {
+        let substitutions = normalized
+            .substitutions
+            .iter()
+            .map(|substitution| substitution.to_string())
+            .collect::<Vec<_>>();
+        return vec![Diagnostic::warning(
+            pointer,
+            format!(
+                "Label should be written as {:?} ({})",
+                normalized.text,
+                substitutions.join(", ")
+            ),
+        )];
+    }
+    let names = families
+        .iter()
+        .map(|family| family.name())
+        .collect::<Vec<_>>();
+    let mut message = format!("Unrecognized {} label {:?}", names.join(" / "), label);
+    if let Some(candidate) = parser::identify(label).first() {
+        message.push_str(&format!(" (looks like {})", candidate.family.name()));
+    }
+    vec![Diagnostic::error(pointer, message)]
+}
+
+/// Validates a metadata.json document and all its labels
+///
+/// ```
+/// use gbhwdb_backend::diagnostics::{check_metadata, DocumentKind};
+///
+/// let json = r#"{
+///     "code": "CGB-AFIP-0", "slug": "test-1", "contributor": "test", "index": 1, "shell": {},
+///     "board": {"label": "DMG-A08-01", "u2": {"label": "MBC5 LZ9GB31 AL23 A"}}
+/// }"#;
+/// assert!(check_metadata(DocumentKind::Cartridge, json).is_empty());
+///
+/// let json = json.replace("MBC5", "MBC 5");
+/// let diagnostics = check_metadata(DocumentKind::Cartridge, &json);
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].pointer, "/board/u2/label");
+///
+/// let diagnostics = check_metadata(DocumentKind::Cartridge, "{}");
+/// assert_eq!(diagnostics[0].pointer, "");
+/// ```
+pub fn check_metadata(kind: DocumentKind, json: &str) -> Vec<Diagnostic> {
+    let value = match serde_json::from_str::<Value>(json) {
+        Ok(value) => value,
+        Err(err) => return vec![Diagnostic::error("", format!("Invalid JSON: {err}"))],
+    };
+    let result = match kind {
+        DocumentKind::Console(Console::Dmg) => check_schema::<input::dmg::DmgConsole>(&value),
+        DocumentKind::Console(Console::Sgb) => check_schema::<input::sgb::SgbConsole>(&value),
+        DocumentKind::Console(Console::Mgb) => check_schema::<input::mgb::MgbConsole>(&value),
+        DocumentKind::Console(Console::Mgl) => check_schema::<input::mgl::MglConsole>(&value),
+        DocumentKind::Console(Console::Sgb2) => check_schema::<input::sgb2::Sgb2Console>(&value),
+        DocumentKind::Console(Console::Cgb) => check_schema::<input::cgb::CgbConsole>(&value),
+        DocumentKind::Console(Console::Agb) => check_schema::<input::agb::AgbConsole>(&value),
+        DocumentKind::Console(Console::Ags) => check_schema::<input::ags::AgsConsole>(&value),
+        DocumentKind::Console(Console::Gbs) => check_schema::<input::gbs::GbsConsole>(&value),
+        DocumentKind::Console(Console::Oxy) => check_schema::<input::oxy::OxyConsole>(&value),
+        DocumentKind::Cartridge => check_schema::<input::cartridge::Cartridge>(&value),
+    };
+    let mut diagnostics = Vec::new();
+    if let Err(err) = result {
+        diagnostics.push(Diagnostic::error("", err));
+    }
+    match document_labels(kind, &value) {
+        Ok(labels) => {
+            for entry in labels {
+                if let Some(label) = &entry.label {
+                    if !entry.families.is_empty() {
+                        diagnostics.extend(check_label(&entry.pointer, &entry.families, label));
+                    }
+                }
+            }
+        }
+        Err(err) => diagnostics.push(Diagnostic::error("", err)),
+    }
+    if kind == DocumentKind::Cartridge {
+        diagnostics.extend(check_board_memory(&value));
+        diagnostics.extend(check_rom_code(&value));
+    }
+    diagnostics
+}
+
+/// Compares decoded ROM and RAM capacities against the sizes encoded in the board label
+///
+/// ```
+/// use gbhwdb_backend::diagnostics::{check_metadata, DocumentKind, Severity};
+///
+/// let json = r#"{
+///     "code": "DMG-APSJ-0", "slug": "test-1", "contributor": "test", "index": 1, "shell": {},
+///     "board": {"label": "DMG-DECN-02", "u3": {"label": "HY6264A LLJ-10 9540B KOREA"}}
+/// }"#;
+/// assert!(check_metadata(DocumentKind::Cartridge, json).is_empty());
+///
+/// let json = json.replace("HY6264A LLJ-10 9540B KOREA", "LH52256CN-10LL SHARP JAPAN 0016 5 SO");
+/// let diagnostics = check_metadata(DocumentKind::Cartridge, &json);
+/// assert_eq!(diagnostics[0].severity, Severity::Warning);
+/// assert_eq!(diagnostics[0].pointer, "/board/u3/label");
+/// ```
+fn check_board_memory(json: &Value) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let Some(board_label) = json.pointer("/board/label").and_then(Value::as_str) else {
+        return diagnostics;
+    };
+    let (Some(layout), Some(memory)) = (
+        BoardLayout::from_label(board_label),
+        BoardMemory::from_label(board_label),
+    ) else {
+        return diagnostics;
+    };
+    for (designator, role) in PartRoleConfig::from(layout) {
+        let pointer = format!("/board/{}/label", designator.as_str().to_lowercase());
+        let Some(label) = json.pointer(&pointer).and_then(Value::as_str) else {
+            continue;
+        };
+        let capacity = PartFamily::for_cartridge_role(layout, role)
+            .and_then(|family| family.identify(label).ok())
+            .and_then(|part| part.kind)
+            .and_then(|kind| ChipSpec::from_part_number(&kind))
+            .and_then(|spec| spec.capacity);
+        let Some(capacity) = capacity else {
+            continue;
+        };
+        match role {
+            PartRole::Rom if capacity > memory.max_rom => {
+                diagnostics.push(Diagnostic::warning(
+                    &pointer,
+                    format!(
+                        "{} ROM is larger than the {} supported by board {board_label}",
+                        format_capacity(capacity),
+                        format_capacity(memory.max_rom)
+                    ),
+                ));
+            }
+            PartRole::Ram => match memory.ram {
+                Some(ram) if ram != capacity => {
+                    diagnostics.push(Diagnostic::warning(
+                        &pointer,
+                        format!(
+                            "{} RAM doesn't match board {board_label}, which expects {}",
+                            format_capacity(capacity),
+                            format_capacity(ram)
+                        ),
+                    ));
+                }
+                _ => (),
+            },
+            _ => (),
+        }
+    }
+    diagnostics
+}
+
+/// Compares the ROM code printed on the ROM chip against the cartridge and shell codes
+///
+/// ```
+/// use gbhwdb_backend::diagnostics::{check_metadata, DocumentKind, Severity};
+///
+/// let json = r#"{
+///     "code": "DMG-YTE-0", "slug": "test-1", "contributor": "test", "index": 1,
+///     "shell": {"code": "DMG-YT-AUS"},
+///     "board": {"label": "DMG-BEAN-02", "u1": {"label": "DMG-YTE-0 E1 N-4001EJGW-J16 9604K7007"}}
+/// }"#;
+/// assert!(check_metadata(DocumentKind::Cartridge, json).is_empty());
+///
+/// let diagnostics = check_metadata(DocumentKind::Cartridge, &json.replace("DMG-YTE-0 E1", "DMG-YTE-1 E1"));
+/// assert_eq!(diagnostics[0].severity, Severity::Warning);
+/// assert!(diagnostics[0].message.contains("revision 1"));
+///
+/// let diagnostics = check_metadata(DocumentKind::Cartridge, &json.replace("DMG-YT-AUS", "DMG-YTJ-JPN"));
+/// assert_eq!(diagnostics[0].pointer, "/shell/code");
+/// ```
+fn check_rom_code(json: &Value) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let (Some(code), Some(board_label)) = (
+        json.pointer("/code").and_then(Value::as_str),
+        json.pointer("/board/label").and_then(Value::as_str),
+    ) else {
+        return diagnostics;
+    };
+    let Some(layout) = BoardLayout::from_label(board_label) else {
+        return diagnostics;
+    };
+    let rom = PartRoleConfig::from(layout)
+        .into_iter()
+        .filter(|(_, role)| *role == PartRole::Rom)
+        .find_map(|(designator, role)| {
+            let pointer = format!("/board/{}/label", designator.as_str().to_lowercase());
+            let label = json.pointer(&pointer).and_then(Value::as_str)?;
+            let rom_code = PartFamily::for_cartridge_role(layout, role)
+                .and_then(|family| family.identify(label).ok())
+                .and_then(|part| part.rom_code)
+                .and_then(|rom_code| rom_code.parse::<RomCode>().ok())?;
+            Some((pointer, rom_code))
+        });
+    let Some((pointer, rom_code)) = rom else {
+        return diagnostics;
+    };
+    if let Ok(expected) = code.parse::<RomCode>() {
+        let message = match rom_code.mismatch(&expected) {
+            Some(RomCodeMismatch::Game) => Some(format!(
+                "ROM code {rom_code} doesn't match cartridge code {expected}"
+            )),
+            Some(RomCodeMismatch::Region) => Some(format!(
+                "ROM code {rom_code} is for region {} but cartridge code {expected} is for region {}",
+                rom_code.region, expected.region
+            )),
+            Some(RomCodeMismatch::Revision) => Some(format!(
+                "ROM code {rom_code} is revision {} but cartridge code {expected} is revision {}",
+                rom_code.revision, expected.revision
+            )),
+            None => None,
+        };
+        if let Some(message) = message {
+            diagnostics.push(Diagnostic::warning(&pointer, message));
+        }
+    }
+    // shell codes have the game code with or without the region letter, e.g. DMG-YT-AUS,
+    // DMG-AP2J-JPN, or DIS-CGB-AW8A-USA
+    let shell_game_code = json
+        .pointer("/shell/code")
+        .and_then(Value::as_str)
+        .and_then(|shell_code| {
+            shell_code
+                .split('-')
+                .skip_while(|part| *part != rom_code.platform.code())
+                .nth(1)
+        });
+    if let Some(shell_game_code) = shell_game_code {
+        if !rom_code.full_game_code().starts_with(shell_game_code) {
+            let message = match shell_game_code.strip_prefix(rom_code.game_code.as_str()) {
+                Some(region) if region.len() == 1 => format!(
+                    "Shell code is for region {region} but ROM code {rom_code} is for region {}",
+                    rom_code.region
+                ),
+                _ => format!("Shell code doesn't match ROM code {rom_code}"),
+            };
+            diagnostics.push(Diagnostic::warning("/shell/code", message));
+        }
+    }
+    diagnostics
+}
+
+fn check_schema<T: DeserializeOwned>(value: &Value) -> Result<(), String> {
+    T::deserialize(value)
+        .map(|_| ())
+        .map_err(|err| format!("Invalid metadata: {err}"))
+}
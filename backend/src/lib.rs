@@ -1,12 +1,15 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
 use std::fmt;
 
 pub mod config;
+pub mod diagnostics;
 pub mod input;
 pub mod parser;
+pub mod prediction;
+pub mod serial;
 pub mod sha256;
 pub mod time;
 
@@ -99,10 +102,10 @@ impl fmt::Display for ParseError {
 #[macro_use]
 pub(crate) mod macros {
     macro_rules! single_parser {
-        ($t:ty, $re:literal, $f:expr $(,)?) => {{
+        ($t:ty, $name:literal, $re:literal, $f:expr $(,)?) => {{
             static PARSER: std::sync::OnceLock<crate::parser::SingleParser<$t>> =
                 std::sync::OnceLock::new();
-            PARSER.get_or_init(|| crate::parser::SingleParser::compile($re, $f))
+            PARSER.get_or_init(|| crate::parser::SingleParser::compile($name, $re, $f))
         }};
     }
     pub(crate) use single_parser;
@@ -1,10 +1,9 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
 use log::warn;
 use regex::{Captures, Regex, RegexBuilder, RegexSet, RegexSetBuilder};
-use std::str::FromStr;
 
 use crate::time::{Month, Week};
 
@@ -18,8 +17,11 @@ pub use self::{
     cgb_reg::CgbReg,
     cgb_soc::CgbSoc,
     cgb_stamp::CgbStamp,
+    chip_spec::{ChipSpec, Package},
     cic::Cic,
     coil::Coil,
+    country::Country,
+    date_code::{DateCodeConvention, PartDateCode},
     dmg_amp::DmgAmp,
     dmg_reg::DmgReg,
     dmg_stamp::DmgStamp,
@@ -31,9 +33,11 @@ pub use self::{
     gen2_soc::{Gen2Soc, Gen2SocKind},
     hex_inverter::HexInverter,
     icd2::Icd2,
+    identify::{identify, Identification, PartFamily, PartSummary},
     lcd_chip::LcdChip,
     lcd_screen::LcdScreen,
     line_decoder::LineDecoder,
+    manufacturer::Manufacturer,
     mapper::{Huc1Version, Mapper, MapperType, Mbc1Version, Mbc2Version, Mbc3Version},
     mask_rom::MaskRom,
     mgb_amp::MgbAmp,
@@ -42,6 +46,7 @@ pub use self::{
     oxy_u4::OxyU4,
     oxy_u5::OxyU5,
     ram::Ram,
+    rom_code::{RomCode, RomCodeMismatch},
     rtc::Rtc,
     sgb_rom::SgbRom,
     supervisor_reset::SupervisorReset,
@@ -61,13 +66,17 @@ pub mod ags_pmic_old;
 pub mod cgb_reg;
 pub mod cgb_soc;
 pub mod cgb_stamp;
+pub mod chip_spec;
 pub mod cic;
 pub mod coil;
+pub mod corpus;
+pub mod country;
 pub mod crystal_20mihz;
 pub mod crystal_32kihz;
 pub mod crystal_32mihz;
 pub mod crystal_4mihz;
 pub mod crystal_8mihz;
+pub mod date_code;
 pub mod dmg_amp;
 pub mod dmg_reg;
 pub mod dmg_stamp;
@@ -79,17 +88,21 @@ pub mod gen1_soc;
 pub mod gen2_soc;
 pub mod hex_inverter;
 pub mod icd2;
+pub mod identify;
 pub mod lcd_chip;
 pub mod lcd_screen;
 pub mod line_decoder;
+pub mod manufacturer;
 pub mod mapper;
 pub mod mask_rom;
 pub mod mgb_amp;
 pub mod mgl_transformer;
+pub mod normalize;
 pub mod oxy_pmic;
 pub mod oxy_u4;
 pub mod oxy_u5;
 pub mod ram;
+pub mod rom_code;
 pub mod rtc;
 pub mod sgb_rom;
 pub mod sram_tsop1_48;
@@ -102,6 +115,7 @@ pub struct ChipYearWeek {
     pub manufacturer: Option<Manufacturer>,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -111,6 +125,7 @@ pub struct Crystal {
     pub year: Option<Year>,
     pub month: Option<Month>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 impl Crystal {
@@ -129,148 +144,26 @@ impl Crystal {
     }
 }
 
-fn kds_month(text: &str) -> Result<Month, String> {
-    match text {
-        "A" => Ok(Month::January),
-        "B" => Ok(Month::February),
-        "C" => Ok(Month::March),
-        "D" => Ok(Month::April),
-        "E" => Ok(Month::May),
-        "F" => Ok(Month::June),
-        "G" => Ok(Month::July),
-        "H" => Ok(Month::August),
-        // I is intentionally skipped
-        "J" => Ok(Month::September),
-        "K" => Ok(Month::October),
-        "L" => Ok(Month::November),
-        "M" => Ok(Month::December),
-        _ => Err(format!("Invalid 1-letter month: {}", text)),
-    }
-}
-
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub enum Manufacturer {
-    Amic,
-    Analog,
-    AtT,
-    Bsi,
-    Crosslink,
-    Fujitsu,
-    Hudson,
-    Hynix,
-    Hyundai,
-    Kds,
-    Lgs,
-    LsiLogic,
-    Kinseki,
-    Macronix,
-    Mitsubishi,
-    Mitsumi,
-    MoselVitelic,
-    Motorola,
-    Nec,
-    Oki,
-    Panasonic,
-    Rohm,
-    Samsung,
-    Sanyo,
-    Sharp,
-    Smsc,
-    StMicro,
-    Tdk,
-    TexasInstruments,
-    Toshiba,
-    Victronix,
-    Winbond,
-}
-
-impl Manufacturer {
-    pub const fn name(&self) -> &'static str {
-        match self {
-            Manufacturer::Amic => "AMIC Technology",
-            Manufacturer::Analog => "Analog Devices",
-            Manufacturer::AtT => "AT&T Technologies",
-            Manufacturer::Bsi => "BSI",
-            Manufacturer::Crosslink => "Crosslink Semiconductor",
-            Manufacturer::Fujitsu => "Fujitsu",
-            Manufacturer::Hudson => "Hudson",
-            Manufacturer::Hynix => "Hynix",
-            Manufacturer::Hyundai => "Hyundai",
-            Manufacturer::Kds => "Daishinku",
-            Manufacturer::Kinseki => "Kinseki",
-            Manufacturer::Lgs => "Lucky GoldStar",
-            Manufacturer::LsiLogic => "LSI Logic",
-            Manufacturer::Macronix => "Macronix",
-            Manufacturer::Mitsubishi => "Mitsubishi",
-            Manufacturer::Mitsumi => "Mitsumi",
-            Manufacturer::MoselVitelic => "Mosel-Vitelic",
-            Manufacturer::Motorola => "Motorola",
-            Manufacturer::Nec => "NEC",
-            Manufacturer::Oki => "OKI",
-            Manufacturer::Panasonic => "Panasonic",
-            Manufacturer::Rohm => "ROHM",
-            Manufacturer::Samsung => "Samsung",
-            Manufacturer::Sanyo => "Sanyo",
-            Manufacturer::Sharp => "Sharp",
-            Manufacturer::Smsc => "Standard Microsystems Corporation",
-            Manufacturer::StMicro => "STMicroelectronics",
-            Manufacturer::Tdk => "TDK",
-            Manufacturer::TexasInstruments => "Texas Instruments",
-            Manufacturer::Toshiba => "Toshiba",
-            Manufacturer::Victronix => "Victronix",
-            Manufacturer::Winbond => "Winbond",
-        }
-    }
-}
-
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum Year {
     Full(u16),
     Partial(u8),
 }
 
-pub fn year1(text: &str) -> Result<Year, String> {
-    match u8::from_str(text) {
-        Ok(value) => Ok(Year::Partial(value)),
-        _ => Err(format!("Invalid 1-digit year: {}", text)),
-    }
-}
-
-pub fn year2(text: &str) -> Result<Year, String> {
-    if text == "AL" {
-        return Ok(Year::Full(2000));
-    }
-    if text == "AA" {
-        return Ok(Year::Full(2001));
-    }
-    match u16::from_str(text) {
-        Ok(value @ 0..=87) => Ok(Year::Full(value + 2000)),
-        Ok(value @ 88..=99) => Ok(Year::Full(value + 1900)),
-        _ => Err(format!("Invalid 2-digit year: {}", text)),
-    }
-}
-
-pub fn week2(text: &str) -> Result<Week, String> {
-    u8::from_str(text)
-        .ok()
-        .and_then(|v| Week::try_from(v).ok())
-        .ok_or_else(|| format!("Invalid 2-digit week: {}", text))
-}
-
-pub fn month2(text: &str) -> Result<Month, String> {
-    u8::from_str(text)
-        .ok()
-        .and_then(|v| Month::try_from(v).ok())
-        .ok_or_else(|| format!("Invalid 2-digit month: {}", text))
-}
-
 pub trait LabelParser<T> {
     fn parse(&self, label: &str) -> Result<T, String>;
     fn parsers(&self) -> Vec<&SingleParser<T>>;
 }
 
+/// Country of origin from the `country` capture of a parser regex
+fn capture_country(c: &Captures) -> Option<Country> {
+    c.name("country")
+        .and_then(|country| Country::from_alias(country.as_str()))
+}
+
 #[derive(Clone)]
 pub struct SingleParser<T> {
+    name: &'static str,
     regex: Regex,
     f: fn(Captures) -> Result<T, String>,
 }
@@ -289,12 +182,30 @@ impl<T> LabelParser<T> for SingleParser<T> {
 }
 
 impl<T> SingleParser<T> {
-    pub fn compile(regex: &str, f: fn(Captures) -> Result<T, String>) -> SingleParser<T> {
+    pub fn compile(
+        name: &'static str,
+        regex: &str,
+        f: fn(Captures) -> Result<T, String>,
+    ) -> SingleParser<T> {
         let regex = RegexBuilder::new(regex)
             .ignore_whitespace(true)
             .build()
             .expect("Failed to compile regex");
-        SingleParser { regex, f }
+        SingleParser { name, regex, f }
+    }
+    /// Stable name of the parser, e.g. "ram::sharp_lh5160". The label corpus refers to parsers by
+    /// this name, so it must not change when the parser is moved or renamed
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::{self, LabelParser};
+    /// let parser = parser::mapper::sharp_mbc5().parsers()[0];
+    /// assert_eq!(parser.name(), "mapper::sharp_mbc5");
+    /// ```
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    pub fn regex(&self) -> &str {
+        self.regex.as_str()
     }
 }
 
@@ -305,6 +216,7 @@ pub struct StaticRam {
     pub manufacturer: Option<Manufacturer>,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 #[derive(Clone)]
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type Accelerometer = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type Accelerometer = ChipYearWeek;
 pub fn analog_adxl202jqc() -> &'static impl LabelParser<ChipYearWeek> {
     single_parser!(
         ChipYearWeek,
-        r#"^[0-9]{10}\ ([0-9]{2})([0-9]{2})\ ADXL202JQC$"#,
+        "accelerometer::analog_adxl202jqc",
+        r#"^[0-9]{10}\ ([0-9]{4})\ ADXL202JQC$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(ChipYearWeek {
                 kind: "ADXL202JQC".to_owned(),
                 manufacturer: Some(Manufacturer::Analog),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 pub type AgbAmp = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type AgbAmp = ChipYearWeek;
 pub fn sharp_ir3r60n() -> &'static impl LabelParser<AgbAmp> {
     single_parser!(
         AgbAmp,
-        r#"^AMP\ AGB\ IR3R60N\ ([A0-9]{2})([0-9]{2})\ [A-Za-z]$"#,
+        "agb_amp::sharp_ir3r60n",
+        r#"^AMP\ AGB\ IR3R60N\ ([A0-9]{2}[0-9]{2})\ [A-Za-z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(AgbAmp {
                 kind: "IR3R60N".to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -33,13 +36,16 @@ pub fn sharp_ir3r60n() -> &'static impl LabelParser<AgbAmp> {
 pub fn rohm_bh7835afs() -> &'static impl LabelParser<AgbAmp> {
     single_parser!(
         AgbAmp,
-        r#"^BH7835AFS\ ([0-9])([0-9]{2})\ [[:alnum:]][0-9]{2}$"#,
+        "agb_amp::rohm_bh7835afs",
+        r#"^BH7835AFS\ ([0-9]{3})\ [[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(AgbAmp {
                 kind: "BH7835AFS".to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 pub type AgbPmic = ChipYearWeek;
@@ -12,14 +12,21 @@ pub type AgbPmic = ChipYearWeek;
 /// assert!(parser::agb_pmic::mitsumi_mm1514x().parse("105 514X").is_ok());
 /// ```
 pub fn mitsumi_mm1514x() -> &'static impl LabelParser<AgbPmic> {
-    single_parser!(AgbPmic, r#"^([1-9])([0-5][0-9])\ 514X$"#, move |c| {
-        Ok(AgbPmic {
-            kind: "MM1514X".to_owned(),
-            manufacturer: Some(Manufacturer::Mitsumi),
-            year: Some(year1(&c[1])?),
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        AgbPmic,
+        "agb_pmic::mitsumi_mm1514x",
+        r#"^([1-9][0-5][0-9])\ 514X$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
+            Ok(AgbPmic {
+                kind: "MM1514X".to_owned(),
+                manufacturer: Some(Manufacturer::Mitsumi),
+                year: date.year,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -27,14 +34,21 @@ pub fn mitsumi_mm1514x() -> &'static impl LabelParser<AgbPmic> {
 /// assert!(parser::agb_pmic::mitsumi_mm1514x_2().parse("081 514X").is_ok());
 /// ```
 pub fn mitsumi_mm1514x_2() -> &'static impl LabelParser<AgbPmic> {
-    single_parser!(AgbPmic, r#"^(0)[0-9]{2}\ 514X$"#, move |c| {
-        Ok(AgbPmic {
-            kind: "MM1514X".to_owned(),
-            manufacturer: Some(Manufacturer::Mitsumi),
-            year: Some(year1(&c[1])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        AgbPmic,
+        "agb_pmic::mitsumi_mm1514x_2",
+        r#"^(0)[0-9]{2}\ 514X$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
+            Ok(AgbPmic {
+                kind: "MM1514X".to_owned(),
+                manufacturer: Some(Manufacturer::Mitsumi),
+                year: date.year,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -45,13 +59,16 @@ pub fn mitsumi_mm1514x_2() -> &'static impl LabelParser<AgbPmic> {
 pub fn unknown() -> &'static impl LabelParser<AgbPmic> {
     single_parser!(
         AgbPmic,
+        "agb_pmic::unknown",
         r#"^S6960\ E-U([0-9])[A-Z]\ C[0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(AgbPmic {
                 kind: "S6960".to_owned(),
                 manufacturer: None,
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
@@ -65,13 +82,16 @@ pub fn unknown() -> &'static impl LabelParser<AgbPmic> {
 pub fn unknown2() -> &'static impl LabelParser<AgbPmic> {
     single_parser!(
         AgbPmic,
+        "agb_pmic::unknown2",
         r#"^(9750[AB])\ ([0-9])[[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[2])?;
             Ok(AgbPmic {
                 kind: c[1].to_owned(),
                 manufacturer: None,
-                year: Some(year1(&c[2])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
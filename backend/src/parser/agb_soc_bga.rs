@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{capture_country, ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::single_parser;
 
 pub type AgbSoc = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type AgbSoc = ChipYearWeek;
 pub fn agb_soc_bga() -> &'static impl LabelParser<AgbSoc> {
     single_parser!(
         AgbSoc,
-        r#"^([0-9]{2})([0-9]{2})\ 2m\ (CPU\ AGB\ E)\ Ⓜ\ ©\ 2004\ Nintendo\ JAPAN\ ARM$"#,
+        "agb_soc_bga::agb_soc_bga",
+        r#"^([0-9]{4})\ 2m\ (CPU\ AGB\ E)\ Ⓜ\ ©\ 2004\ Nintendo\ (?P<country>JAPAN)\ ARM$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(AgbSoc {
-                kind: c[3].to_owned(),
+                kind: c[2].to_owned(),
                 manufacturer: None,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
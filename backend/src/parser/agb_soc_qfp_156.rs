@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{capture_country, ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::single_parser;
 
 pub type AgbSoc = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type AgbSoc = ChipYearWeek;
 pub fn agb_soc_qfp_156() -> &'static impl LabelParser<AgbSoc> {
     single_parser!(
         AgbSoc,
-        r#"^(CPU\ AGB\ B(\ E)?)\ Ⓜ\ ©\ 2002\ Nintendo\ JAPAN\ ARM\ ([0-9]{2})([0-9]{2})\ [a-zA-Z]{1,2}$"#,
+        "agb_soc_qfp_156::agb_soc_qfp_156",
+        r#"^(CPU\ AGB\ B(\ E)?)\ Ⓜ\ ©\ 2002\ Nintendo\ (?P<country>JAPAN)\ ARM\ ([0-9]{4})\ [a-zA-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(AgbSoc {
                 kind: c[1].to_owned(),
                 manufacturer: None,
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 pub type AgsChargeController = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type AgsChargeController = ChipYearWeek;
 pub fn mitsumi_mm1581a() -> &'static impl LabelParser<AgsChargeController> {
     single_parser!(
         AgsChargeController,
-        r#"^([0-9])([0-9]{2})\ 1581A$"#,
+        "ags_charge_ctrl::mitsumi_mm1581a",
+        r#"^([0-9]{3})\ 1581A$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(AgsChargeController {
                 kind: "MM1581A".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -33,13 +36,16 @@ pub fn mitsumi_mm1581a() -> &'static impl LabelParser<AgsChargeController> {
 pub fn unknown() -> &'static impl LabelParser<AgsChargeController> {
     single_parser!(
         AgsChargeController,
-        r#"^2253B\ ([0-9])([0-9]{2})[0-9]$"#,
+        "ags_charge_ctrl::unknown",
+        r#"^2253B\ ([0-9]{3})[0-9]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(AgsChargeController {
                 kind: "2253B".to_owned(),
                 manufacturer: None,
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, AgbAmp, ChipYearWeek, LabelParser, Manufacturer};
+use super::{capture_country, AgbAmp, ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type AgsPmicNew = ChipYearWeek;
@@ -15,13 +15,16 @@ pub fn mitsumi_pm() -> &'static impl LabelParser<AgsPmicNew> {
     // FIXME: Not really an amplifier
     single_parser!(
         AgbAmp,
-        r#"^MITSUMI\ JAPAN\ ([0-9])([0-9]{2})[A-Z]\ (PM\ B[0-9])$"#,
+        "ags_pmic_new::mitsumi_pm",
+        r#"^MITSUMI\ (?P<country>JAPAN)\ ([0-9]{3})[A-Z]\ (PM\ B[0-9])$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(AgbAmp {
                 kind: c[3].to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
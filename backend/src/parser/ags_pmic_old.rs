@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{year1, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::{multi_parser, single_parser};
 
 pub type AgsPmicOld = ChipYearWeek;
@@ -14,6 +14,7 @@ pub type AgsPmicOld = ChipYearWeek;
 pub fn unknown() -> &'static impl LabelParser<AgsPmicOld> {
     single_parser!(
         AgsPmicOld,
+        "ags_pmic_old::unknown",
         r#"^S6403\ [[:alnum:]]{5}\ [0-9]{4}$"#,
         move |_| {
             Ok(AgsPmicOld {
@@ -21,6 +22,7 @@ pub fn unknown() -> &'static impl LabelParser<AgsPmicOld> {
                 manufacturer: None,
                 year: None,
                 week: None,
+                country: None,
             })
         }
     )
@@ -33,13 +35,16 @@ pub fn unknown() -> &'static impl LabelParser<AgsPmicOld> {
 pub fn unknown2() -> &'static impl LabelParser<AgsPmicOld> {
     single_parser!(
         AgsPmicOld,
+        "ags_pmic_old::unknown2",
         r#"^(9753)\ ([0-9])[[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[2])?;
             Ok(AgsPmicOld {
                 kind: c[1].to_owned(),
                 manufacturer: None,
-                year: Some(year1(&c[2])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::{macros::single_parser, parser::Manufacturer};
 
 pub type CgbReg = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type CgbReg = ChipYearWeek;
 pub fn sharp_ir3e06n() -> &'static impl LabelParser<CgbReg> {
     single_parser!(
         CgbReg,
-        r#"^CGB-REG\ IR3E06N\ ([0-9]{2})([0-9]{2})\ [A-Z]{1,2}$"#,
+        "cgb_reg::sharp_ir3e06n",
+        r#"^CGB-REG\ IR3E06N\ ([0-9]{4})\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(CgbReg {
                 kind: "IR3E06N".to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{capture_country, ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::{macros::single_parser, parser::Manufacturer};
 
 pub type CgbSoc = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type CgbSoc = ChipYearWeek;
 pub fn cpu_cgb() -> &'static impl LabelParser<CgbSoc> {
     single_parser!(
         CgbSoc,
-        r#"^(CPU\ CGB(\ [A-E])?)\ Ⓜ\ ©\ (1998|2000)\ Nintendo\ JAPAN\ ([0-9]{2})([0-9]{2})\ [A-Z]{1,2}$"#,
+        "cgb_soc::cpu_cgb",
+        r#"^(CPU\ CGB(\ [A-E])?)\ Ⓜ\ ©\ (1998|2000)\ Nintendo\ (?P<country>JAPAN)\ ([0-9]{4})\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[5])?;
             Ok(CgbSoc {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
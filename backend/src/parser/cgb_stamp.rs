@@ -2,13 +2,14 @@
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, LabelParser, Year};
+use super::{Country, DateCodeConvention, LabelParser, Year};
 use crate::{macros::single_parser, time::Week};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct CgbStamp {
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -18,11 +19,14 @@ pub struct CgbStamp {
 pub fn cgb_stamp() -> &'static impl LabelParser<CgbStamp> {
     single_parser!(
         CgbStamp,
-        r#"^([0-9]{2})([0-9])[-\ .X]?[0-9]{2,4}Y?$"#,
+        "cgb_stamp::cgb_stamp",
+        r#"^([0-9]{3})[-\ .X]?[0-9]{2,4}Y?$"#,
         move |c| {
+            let date = DateCodeConvention::WeekShortYear.decode(&c[1])?;
             Ok(CgbStamp {
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[1])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -0,0 +1,274 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use regex::{Captures, Regex, RegexBuilder};
+use std::{fmt, sync::OnceLock};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Package {
+    Sop,
+    Tsop,
+    Tsop1,
+}
+
+impl Package {
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Package::Sop => "SOP",
+            Package::Tsop => "TSOP",
+            Package::Tsop1 => "TSOP-I",
+        }
+    }
+}
+
+impl fmt::Display for Package {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Memory chip organization decoded from a part number
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ChipSpec {
+    /// Capacity in bits
+    pub capacity: Option<u32>,
+    /// Data bus width in bits, or None for serial and configurable chips
+    pub bus_width: Option<u8>,
+    /// Access time in nanoseconds
+    pub speed_ns: Option<u16>,
+    pub package: Option<Package>,
+}
+
+const KBIT: u32 = 1024;
+const MBIT: u32 = 1024 * 1024;
+
+/// Formats a capacity in bits, e.g. "256 kbit" or "16 Mbit"
+pub fn format_capacity(bits: u32) -> String {
+    if bits >= MBIT && bits % MBIT == 0 {
+        format!("{} Mbit", bits / MBIT)
+    } else {
+        format!("{} kbit", bits / KBIT)
+    }
+}
+
+type Decoder = fn(&Captures) -> ChipSpec;
+
+fn decoders() -> &'static [(Regex, Decoder)] {
+    static DECODERS: OnceLock<Vec<(Regex, Decoder)>> = OnceLock::new();
+    fn compile(regex: &str, f: Decoder) -> (Regex, Decoder) {
+        let regex = RegexBuilder::new(regex)
+            .ignore_whitespace(true)
+            .build()
+            .unwrap();
+        (regex, f)
+    }
+    DECODERS.get_or_init(|| {
+        vec![
+            // Sharp SRAM: the letters after the part number give the package (N = SOP, T = TSOP), e.g.
+            // "AN" or "LN" for SOP-28
+            compile(
+                r#"^LH5(?P<part>160|164|168|264|2A64|268|2256|2B256|2CV256|1D256)[A-C]?(?P<package>[A-Z0-9]*)(-(?P<speed>[0-9]{2}))?"#,
+                |c| ChipSpec {
+                    capacity: Some(match &c["part"] {
+                        "160" => 16 * KBIT,
+                        "164" | "168" | "264" | "2A64" | "268" => 64 * KBIT,
+                        _ => 256 * KBIT,
+                    }),
+                    bus_width: Some(8),
+                    speed_ns: speed(c),
+                    package: c.name("package").and_then(|package| {
+                        let package = package.as_str();
+                        if package.contains('T') {
+                            Some(Package::Tsop)
+                        } else if package.contains('N') {
+                            Some(Package::Sop)
+                        } else {
+                            None
+                        }
+                    }),
+                },
+            ),
+            compile(
+                r#"^(HY6264|BR6265|XLJ6265|W2465|LC3564|VN4464)[A-Z]*-(?P<speed>[0-9]{2})"#,
+                |c| sram_x8(64 * KBIT, c),
+            ),
+            compile(
+                r#"^(BR62256|BS62LV256|W24257|W24258|LC35256)[A-Z]*-(?P<speed>[0-9]{2})"#,
+                |c| sram_x8(256 * KBIT, c),
+            ),
+            compile(r#"^GM76C256[A-Z]*(?P<speed>[0-9]{2})$"#, |c| {
+                sram_x8(256 * KBIT, c)
+            }),
+            compile(r#"^HY62WT081[A-Z]*(?P<speed>[0-9]{2})"#, |c| {
+                sram_x8(256 * KBIT, c)
+            }),
+            compile(r#"^HY628100[A-Z]*-(?P<speed>[0-9]{2})"#, |c| {
+                sram_x8(MBIT, c)
+            }),
+            // 128K x 16 SRAM used on GBA mainboards
+            compile(
+                r#"^(LP62S16128|BS616LV201[89]|M68AS128|HY62LF16206|MB82D12160|TC55V200|μPD442012)[A-Z]*[-]?[A-Z]*(?P<speed>[0-9]{2})?"#,
+                |c| ChipSpec {
+                    capacity: Some(2 * MBIT),
+                    bus_width: Some(16),
+                    speed_ns: speed(c),
+                    package: Some(Package::Tsop1),
+                },
+            ),
+            // Sharp mask ROM with the capacity in Mbit as a single digit, e.g. LH534M
+            compile(r#"^LH(53|5S|MN)(?P<size>[12478])[A-Z]$"#, |c| {
+                let size = match &c["size"] {
+                    "7" => 16,
+                    size => size.parse().unwrap_or_default(),
+                };
+                rom_x8(size * MBIT)
+            }),
+            compile(r#"^LH53(?P<part>259|514|517|2100|0800A?)$"#, |c| {
+                rom_x8(match &c["part"] {
+                    "259" => 256 * KBIT,
+                    "514" | "517" => 512 * KBIT,
+                    "2100" => 2 * MBIT,
+                    _ => MBIT,
+                })
+            }),
+            // Nintendo and NEC mask ROM, e.g. N-4001EJGW, N-16019WG5 or 23C1001EGW
+            compile(
+                r#"^(N-|UPD)?(23C)?(?P<size>[0-9]{1,2})0[0-9]{2}[A-Z][A-Z0-9]*$"#,
+                |c| rom_x8(c["size"].parse::<u32>().unwrap_or_default() * MBIT),
+            ),
+            compile(r#"^(MSM|M)53(?P<size>[0-9])011E?$"#, |c| {
+                rom_x8(c["size"].parse::<u32>().unwrap_or_default() * MBIT)
+            }),
+            compile(r#"^TC53(?P<size>[124])[0-9]{3}[A-Z]*$"#, |c| {
+                rom_x8(c["size"].parse::<u32>().unwrap_or_default() * MBIT)
+            }),
+            compile(r#"^KM23C(?P<size>4|8|16)[0-9]{3}[A-Z]*$"#, |c| ChipSpec {
+                capacity: Some(c["size"].parse::<u32>().unwrap_or_default() * MBIT),
+                ..ChipSpec::default()
+            }),
+            // Macronix mask ROM, which can be configured as x8 or x16
+            compile(
+                r#"^MX23C(?P<size>4|8|16|32)0[0-9]{1,2}-(?P<speed>[0-9]{2})"#,
+                |c| ChipSpec {
+                    capacity: Some(c["size"].parse::<u32>().unwrap_or_default() * MBIT),
+                    speed_ns: speed(c),
+                    ..ChipSpec::default()
+                },
+            ),
+            compile(
+                r#"^MX29F(?P<size>[0-9]{3})[TB]C-(?P<speed>[0-9]{2})"#,
+                |c| ChipSpec {
+                    capacity: Some(c["size"].parse::<u32>().unwrap_or_default() * MBIT),
+                    bus_width: Some(8),
+                    speed_ns: speed(c),
+                    package: Some(Package::Tsop),
+                },
+            ),
+            // 93LC56 / 93LCS56 serial EEPROM
+            compile(r#"^(LC56|LCS5)$"#, |_| ChipSpec {
+                capacity: Some(2 * KBIT),
+                ..ChipSpec::default()
+            }),
+        ]
+    })
+}
+
+fn sram_x8(capacity: u32, c: &Captures) -> ChipSpec {
+    ChipSpec {
+        capacity: Some(capacity),
+        bus_width: Some(8),
+        speed_ns: speed(c),
+        package: None,
+    }
+}
+
+fn rom_x8(capacity: u32) -> ChipSpec {
+    ChipSpec {
+        capacity: Some(capacity),
+        bus_width: Some(8),
+        ..ChipSpec::default()
+    }
+}
+
+/// Decodes a speed grade, e.g. "70" = 70 ns, "10" = 100 ns
+fn speed(c: &Captures) -> Option<u16> {
+    let speed = c.name("speed")?.as_str().parse::<u16>().ok()?;
+    Some(if speed <= 20 { speed * 10 } else { speed })
+}
+
+impl ChipSpec {
+    /// Decodes a RAM, mask ROM, flash, or EEPROM part number
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::{ChipSpec, Package};
+    ///
+    /// let spec = ChipSpec::from_part_number("LH52256CN-10LL").unwrap();
+    /// assert_eq!(spec.capacity, Some(256 * 1024));
+    /// assert_eq!(spec.bus_width, Some(8));
+    /// assert_eq!(spec.speed_ns, Some(100));
+    /// assert_eq!(spec.package, Some(Package::Sop));
+    ///
+    /// let spec = ChipSpec::from_part_number("LH534M").unwrap();
+    /// assert_eq!(spec.capacity, Some(4 * 1024 * 1024));
+    ///
+    /// let spec = ChipSpec::from_part_number("MX23C1603-12A").unwrap();
+    /// assert_eq!(spec.capacity, Some(16 * 1024 * 1024));
+    /// assert_eq!(spec.speed_ns, Some(120));
+    /// assert_eq!(spec.to_string(), "16 Mbit, 120 ns");
+    ///
+    /// assert_eq!(ChipSpec::from_part_number("CPU CGB E"), None);
+    /// ```
+    pub fn from_part_number(kind: &str) -> Option<ChipSpec> {
+        decoders()
+            .iter()
+            .find_map(|(regex, f)| regex.captures(kind).map(|c| f(&c)))
+    }
+}
+
+impl fmt::Display for ChipSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = Vec::new();
+        match (self.capacity, self.bus_width) {
+            (Some(capacity), Some(width)) => {
+                parts.push(format!("{} ×{}", format_capacity(capacity), width))
+            }
+            (Some(capacity), None) => parts.push(format_capacity(capacity)),
+            (None, Some(width)) => parts.push(format!("×{}", width)),
+            (None, None) => (),
+        }
+        if let Some(speed) = self.speed_ns {
+            parts.push(format!("{} ns", speed));
+        }
+        if let Some(package) = self.package {
+            parts.push(package.to_string());
+        }
+        f.write_str(&parts.join(", "))
+    }
+}
+
+#[test]
+fn test_decode_corpus_kinds() {
+    let spec = |kind| ChipSpec::from_part_number(kind).unwrap();
+    assert_eq!(spec("LH5164AN-10L").capacity, Some(64 * KBIT));
+    assert_eq!(spec("LH5160N-10L").capacity, Some(16 * KBIT));
+    assert_eq!(spec("LH52256CVT").package, Some(Package::Tsop));
+    assert_eq!(spec("LH5164LN-10").package, Some(Package::Sop));
+    assert_eq!(spec("LH5164AN-10L").package, Some(Package::Sop));
+    assert_eq!(spec("GM76C256CLLFW70").speed_ns, Some(70));
+    assert_eq!(spec("HY628100BLLG-70").capacity, Some(MBIT));
+    assert_eq!(spec("μPD442012AGY-BB85X-MJH").speed_ns, Some(85));
+    assert_eq!(spec("μPD442012AGY-BB85X-MJH").bus_width, Some(16));
+    assert_eq!(spec("LH537M").capacity, Some(16 * MBIT));
+    assert_eq!(spec("LH53259").capacity, Some(256 * KBIT));
+    assert_eq!(spec("LH530800A").capacity, Some(MBIT));
+    assert_eq!(spec("N-16019WG5").capacity, Some(16 * MBIT));
+    assert_eq!(spec("N-4001EJGW").capacity, Some(4 * MBIT));
+    assert_eq!(spec("23C1001EAGW").capacity, Some(MBIT));
+    assert_eq!(spec("MSM538011E").capacity, Some(8 * MBIT));
+    assert_eq!(spec("TC531001CF").capacity, Some(MBIT));
+    assert_eq!(spec("KM23C16120DT").capacity, Some(16 * MBIT));
+    assert_eq!(spec("MX23C4002-20").speed_ns, Some(200));
+    assert_eq!(spec("MX29F008TC-14").capacity, Some(8 * MBIT));
+    assert_eq!(spec("LC56").capacity, Some(2 * KBIT));
+}
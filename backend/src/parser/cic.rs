@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::{macros::single_parser, parser::Manufacturer};
 
 pub type Cic = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type Cic = ChipYearWeek;
 pub fn sharp_cic() -> &'static impl LabelParser<Cic> {
     single_parser!(
         Cic,
-        r#"^(F411A|F411B|F413A|F413B)\ ©\ (1990|1992)\ Nintendo\ ([0-9]{2})([0-9]{2})\ [A-Za-z]?$"#,
+        "cic::sharp_cic",
+        r#"^(F411A|F411B|F413A|F413B)\ ©\ (1990|1992)\ Nintendo\ ([0-9]{4})\ [A-Za-z]?$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Cic {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -2,13 +2,14 @@
 //
 // SPDX-License-Identifier: MIT
 
-use super::{LabelParser, Manufacturer};
+use super::{Country, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Coil {
     pub kind: String,
     pub manufacturer: Option<Manufacturer>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -16,10 +17,11 @@ pub struct Coil {
 /// assert!(parser::coil::tdk().parse("TDK ZJY-M4A N").is_ok());
 /// ```
 pub fn tdk() -> &'static impl LabelParser<Coil> {
-    single_parser!(Coil, r#"^TDK\ (ZJY-M4A)\ [A-Z]$"#, move |c| {
+    single_parser!(Coil, "coil::tdk", r#"^TDK\ (ZJY-M4A)\ [A-Z]$"#, move |c| {
         Ok(Coil {
             kind: c[1].to_owned(),
             manufacturer: Some(Manufacturer::Tdk),
+            country: None,
         })
     })
 }
@@ -29,12 +31,18 @@ pub fn tdk() -> &'static impl LabelParser<Coil> {
 /// assert!(parser::coil::tdk2().parse("TDK ZJY-M4PA n").is_ok());
 /// ```
 pub fn tdk2() -> &'static impl LabelParser<Coil> {
-    single_parser!(Coil, r#"^TDK\ (ZJY-M4PA)\ [a-z]$"#, move |c| {
-        Ok(Coil {
-            kind: c[1].to_owned(),
-            manufacturer: Some(Manufacturer::Tdk),
-        })
-    })
+    single_parser!(
+        Coil,
+        "coil::tdk2",
+        r#"^TDK\ (ZJY-M4PA)\ [a-z]$"#,
+        move |c| {
+            Ok(Coil {
+                kind: c[1].to_owned(),
+                manufacturer: Some(Manufacturer::Tdk),
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn coil() -> &'static impl LabelParser<Coil> {
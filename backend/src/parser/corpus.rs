@@ -0,0 +1,160 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use serde::{Deserialize, Serialize};
+
+use super::{normalize::normalize_with, PartFamily, Year};
+
+/// Path of the regression corpus, relative to the backend crate
+pub const CORPUS_PATH: &str = "fixtures/label-corpus.json";
+
+/// Label from submission data, along with the result it is expected to parse to
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize)]
+pub struct CorpusEntry {
+    pub label: String,
+    /// Family ids, in the order they are tried
+    pub families: Vec<String>,
+    #[serde(flatten)]
+    pub expected: Classification,
+}
+
+impl CorpusEntry {
+    pub fn new(label: &str, families: &[PartFamily]) -> CorpusEntry {
+        CorpusEntry {
+            label: label.to_owned(),
+            families: families
+                .iter()
+                .map(|family| family.id().to_owned())
+                .collect(),
+            expected: classify(label, families),
+        }
+    }
+    /// Classifies the label again with the current parsers
+    pub fn reclassify(&self) -> Result<Classification, String> {
+        let families = self
+            .families
+            .iter()
+            .map(|id| PartFamily::from_id(id).ok_or_else(|| format!("Unknown part family {id}")))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(classify(&self.label, &families))
+    }
+}
+
+/// Parse result of a label in a form that is stable across refactorings
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize)]
+#[serde(default)]
+pub struct Classification {
+    /// The individual parser that accepted the label, or None if no parser did
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parser: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manufacturer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rom_code: Option<String>,
+    /// Full year, or a single digit for partial years
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub week: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    /// Label as parsed, if it only parsed after normalization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub normalized: Option<String>,
+}
+
+/// Classifies a label with the first part family that accepts it
+///
+/// Labels are normalized the same way as in the site build, so character confusions that are
+/// resolved during the build show up in the corpus.
+///
+/// ```
+/// use gbhwdb_backend::parser::{corpus::classify, PartFamily};
+///
+/// let result = classify("MBC5 LZ9GB31 AL23 A", &[PartFamily::Mapper]);
+/// assert_eq!(result.parser.as_deref(), Some("mapper::sharp_mbc5"));
+/// assert_eq!(result.manufacturer.as_deref(), Some("sharp"));
+/// assert_eq!(result.year.as_deref(), Some("2000"));
+/// assert_eq!(result.week, Some(23));
+///
+/// assert_eq!(result.normalized, None);
+///
+/// assert_eq!(classify("MBC5 LZ9GB31 AL23 A", &[PartFamily::Ram]).parser, None);
+///
+/// let result = classify("DMG-CPU B 94O4 D", &[PartFamily::Gen1Soc]);
+/// assert_eq!(result.normalized.as_deref(), Some("DMG-CPU B 9404 D"));
+/// ```
+pub fn classify(label: &str, families: &[PartFamily]) -> Classification {
+    families
+        .iter()
+        .find_map(|family| {
+            let (part, normalized) = normalize_with(label, |label| family.identify(label)).ok()?;
+            Some(Classification {
+                parser: family
+                    .matching_parsers(&normalized.text)
+                    .first()
+                    .map(|name| name.to_string()),
+                kind: part.kind,
+                manufacturer: part.manufacturer.map(|m| m.id().to_owned()),
+                rom_code: part.rom_code,
+                year: part.date_code.year.map(|year| match year {
+                    Year::Full(year) => year.to_string(),
+                    Year::Partial(year) => year.to_string(),
+                }),
+                month: part.date_code.month.map(u8::from),
+                week: part.date_code.week.map(u8::from),
+                country: part.country.map(|country| country.id().to_owned()),
+                normalized: normalized.is_changed().then_some(normalized.text),
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Parses the corpus from its JSON representation
+pub fn parse_corpus(json: &str) -> Result<Vec<CorpusEntry>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Serializes the corpus in the canonical form used for the checked-in fixture
+pub fn format_corpus(entries: &[CorpusEntry]) -> Result<String, serde_json::Error> {
+    let mut entries = entries.to_vec();
+    entries.sort();
+    entries.dedup();
+    let mut json = serde_json::to_string_pretty(&entries)?;
+    json.push('\n');
+    Ok(json)
+}
+
+#[test]
+fn test_label_corpus() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(CORPUS_PATH);
+    let json = std::fs::read_to_string(&path).expect("Failed to read label corpus");
+    let entries = parse_corpus(&json).expect("Failed to parse label corpus");
+    let changed = entries
+        .iter()
+        .filter_map(|entry| {
+            let actual = entry.reclassify().unwrap();
+            (actual != entry.expected).then(|| {
+                format!(
+                    "{:?} [{}]\n  expected: {:?}\n    actual: {:?}",
+                    entry.label,
+                    entry.families.join(", "),
+                    entry.expected,
+                    actual
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        changed.is_empty(),
+        "{} labels in {} are classified differently. If this is intentional, run `just bless-corpus`.\n{}",
+        changed.len(),
+        path.display(),
+        changed.join("\n")
+    );
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+/// Country of origin (usually the assembly country) printed on a chip label
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Country {
+    China,
+    Indonesia,
+    Japan,
+    Korea,
+    Malaysia,
+    Philippines,
+    Singapore,
+    Taiwan,
+    Thailand,
+}
+
+impl Country {
+    pub const ALL: [Country; 9] = [
+        Country::China,
+        Country::Indonesia,
+        Country::Japan,
+        Country::Korea,
+        Country::Malaysia,
+        Country::Philippines,
+        Country::Singapore,
+        Country::Taiwan,
+        Country::Thailand,
+    ];
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Country::China => "china",
+            Country::Indonesia => "indonesia",
+            Country::Japan => "japan",
+            Country::Korea => "korea",
+            Country::Malaysia => "malaysia",
+            Country::Philippines => "philippines",
+            Country::Singapore => "singapore",
+            Country::Taiwan => "taiwan",
+            Country::Thailand => "thailand",
+        }
+    }
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Country::China => "China",
+            Country::Indonesia => "Indonesia",
+            Country::Japan => "Japan",
+            Country::Korea => "Korea",
+            Country::Malaysia => "Malaysia",
+            Country::Philippines => "Philippines",
+            Country::Singapore => "Singapore",
+            Country::Taiwan => "Taiwan",
+            Country::Thailand => "Thailand",
+        }
+    }
+    /// Words used for the country on labels
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Country::China => &["CHINA"],
+            Country::Indonesia => &["INDONESIA"],
+            Country::Japan => &["JAPAN"],
+            Country::Korea => &["KOREA"],
+            Country::Malaysia => &["MALAYSIA"],
+            Country::Philippines => &["PHILIPPINES"],
+            Country::Singapore => &["SINGAPORE"],
+            Country::Taiwan => &["TAIWAN", "TWN"],
+            Country::Thailand => &["THAILAND"],
+        }
+    }
+    /// Looks up a country by a word used for it on labels
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::Country;
+    ///
+    /// assert_eq!(Country::from_alias("TWN"), Some(Country::Taiwan));
+    /// assert_eq!(Country::from_alias("Japan"), None);
+    /// ```
+    pub fn from_alias(text: &str) -> Option<Country> {
+        Country::ALL
+            .into_iter()
+            .find(|country| country.aliases().contains(&text))
+    }
+}
+
+impl fmt::Display for Country {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{kds_month, year1, Crystal, LabelParser, Manufacturer};
+use super::{Crystal, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 const FREQUENCY: u32 = 32_768;
@@ -12,15 +12,22 @@ const FREQUENCY: u32 = 32_768;
 /// assert!(parser::crystal_32kihz::kds_short().parse("KDS1H").is_ok());
 /// ```
 pub fn kds_short() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^KDS([0-9])([A-Z])$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: Some(Manufacturer::Kds),
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: Some(kds_month(&c[2])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_32kihz::kds_short",
+        r#"^KDS([0-9][A-Z])$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearKdsMonth.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: Some(Manufacturer::Kds),
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -30,15 +37,22 @@ pub fn kds_short() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_32kihz::unknown().parse("32K0Z").is_ok());
 /// ```
 pub fn unknown() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^32K([0-9])[[:alnum:]]$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: None,
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: None,
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_32kihz::unknown",
+        r#"^32K([0-9])[[:alnum:]]$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: None,
+                frequency: FREQUENCY,
+                year: date.year,
+                month: None,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn crystal_32kihz() -> &'static impl LabelParser<Crystal> {
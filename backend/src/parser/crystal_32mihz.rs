@@ -2,7 +2,7 @@
 //
 // SPDX-License-Identifier: MIT
 
-use super::{kds_month, year1, Crystal, LabelParser, Manufacturer};
+use super::{Crystal, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 const FREQUENCY: u32 = 33_554_432;
@@ -12,15 +12,22 @@ const FREQUENCY: u32 = 33_554_432;
 /// assert!(parser::crystal_32mihz::kinseki_kss30().parse("33WKSS6DT").is_ok());
 /// ```
 pub fn kinseki_kss30() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^33WKSS([0-9])([A-Z])T$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: Some(Manufacturer::Kinseki),
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: Some(kds_month(&c[2])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_32mihz::kinseki_kss30",
+        r#"^33WKSS([0-9][A-Z])T$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearKdsMonth.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: Some(Manufacturer::Kinseki),
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 pub fn crystal_32mihz() -> &'static impl LabelParser<Crystal> {
     kinseki_kss30()
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{kds_month, week2, year1, year2, Crystal, LabelParser, Manufacturer};
+use super::{Crystal, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 const FREQUENCY: u32 = 4_194_304;
@@ -16,14 +16,17 @@ const FREQUENCY: u32 = 4_194_304;
 pub fn kds_4194() -> &'static impl LabelParser<Crystal> {
     single_parser!(
         Crystal,
-        r#"^KDSI?\ ?([0-9]{2})([0-9]{2})\ 4\.194$"#,
+        "crystal_4mihz::kds_4194",
+        r#"^KDSI?\ ?([0-9]{4})\ 4\.194$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Crystal {
                 manufacturer: Some(Manufacturer::Kds),
                 frequency: FREQUENCY,
-                year: Some(year2(&c[1])?),
-                month: None,
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -34,15 +37,22 @@ pub fn kds_4194() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::kds_4194_short().parse("KDS 6F 4.194").is_ok());
 /// ```
 pub fn kds_4194_short() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^KDS\ ([0-9])([A-Z])\ 4\.194$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: Some(Manufacturer::Kds),
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: Some(kds_month(&c[2])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::kds_4194_short",
+        r#"^KDS\ ([0-9][A-Z])\ 4\.194$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearKdsMonth.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: Some(Manufacturer::Kds),
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -51,15 +61,22 @@ pub fn kds_4194_short() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::kds_d419().parse("D419J3I").is_ok());
 /// ```
 pub fn kds_d419() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^D419([A-Z])([0-9])[A-Z]?$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: Some(Manufacturer::Kds),
-            frequency: FREQUENCY,
-            year: Some(year1(&c[2])?),
-            month: Some(kds_month(&c[1])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::kds_d419",
+        r#"^D419([A-Z][0-9])[A-Z]?$"#,
+        move |c| {
+            let date = DateCodeConvention::KdsMonthShortYear.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: Some(Manufacturer::Kds),
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -68,15 +85,22 @@ pub fn kds_d419() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::kinseki_4194().parse("4194 KSS1A").is_ok());
 /// ```
 pub fn kinseki_4194() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^4194\ KSS\ ?([0-9])([A-Z])[A-Z]?$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: Some(Manufacturer::Kinseki),
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: Some(kds_month(&c[2])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::kinseki_4194",
+        r#"^4194\ KSS\ ?([0-9][A-Z])[A-Z]?$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearKdsMonth.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: Some(Manufacturer::Kinseki),
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -84,15 +108,22 @@ pub fn kinseki_4194() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::unknown2().parse("4.19C59").is_ok());
 /// ```
 pub fn unknown2() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^4\.19C([0-9])[[:alnum:]]$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: None,
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: None,
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::unknown2",
+        r#"^4\.19C([0-9])[[:alnum:]]$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: None,
+                frequency: FREQUENCY,
+                year: date.year,
+                month: None,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -100,15 +131,22 @@ pub fn unknown2() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::unknown_41943().parse("4.1943 9752").is_ok());
 /// ```
 pub fn unknown_41943() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^4\.1943\ ([0-9]{2})([0-9]{2})$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: None,
-            frequency: FREQUENCY,
-            year: Some(year2(&c[1])?),
-            month: None,
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::unknown_41943",
+        r#"^4\.1943\ ([0-9]{4})$"#,
+        move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: None,
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -116,15 +154,22 @@ pub fn unknown_41943() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::unknown_41943_2().parse("4.1943 RVR 841").is_ok());
 /// ```
 pub fn unknown_41943_2() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^4\.1943\ RVR\ ([0-9])([0-9]{2})$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: None,
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: None,
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::unknown_41943_2",
+        r#"^4\.1943\ RVR\ ([0-9]{3})$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: None,
+                frequency: FREQUENCY,
+                year: date.year,
+                month: date.month,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -132,15 +177,22 @@ pub fn unknown_41943_2() -> &'static impl LabelParser<Crystal> {
 /// assert!(parser::crystal_4mihz::unknown2().parse("4.19C59").is_ok());
 /// ```
 pub fn unknown() -> &'static impl LabelParser<Crystal> {
-    single_parser!(Crystal, r#"^4\.19C([0-9])[[:alnum:]]$"#, move |c| {
-        Ok(Crystal {
-            manufacturer: None,
-            frequency: FREQUENCY,
-            year: Some(year1(&c[1])?),
-            month: None,
-            week: None,
-        })
-    })
+    single_parser!(
+        Crystal,
+        "crystal_4mihz::unknown",
+        r#"^4\.19C([0-9])[[:alnum:]]$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
+            Ok(Crystal {
+                manufacturer: None,
+                frequency: FREQUENCY,
+                year: date.year,
+                month: None,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn crystal_4mihz() -> &'static impl LabelParser<Crystal> {
@@ -0,0 +1,260 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use super::Year;
+use crate::time::{Month, Week};
+use std::str::FromStr;
+
+/// Date code decoded from a label, before any year hint has been applied
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct PartDateCode {
+    pub year: Option<Year>,
+    pub month: Option<Month>,
+    pub week: Option<Week>,
+}
+
+/// Date code convention used by a manufacturer or a board stamp
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum DateCodeConvention {
+    /// 1-digit year only, e.g. "8"
+    ShortYear,
+    /// 2-digit year + 2-digit week, e.g. "9824"
+    YearWeek,
+    /// 1-digit year + 2-digit week, e.g. "824"
+    ShortYearWeek,
+    /// 1-digit year + 1-letter KDS month, e.g. "6F"
+    ShortYearKdsMonth,
+    /// 1-letter KDS month + 1-digit year, e.g. "A2"
+    KdsMonthShortYear,
+    /// 2-digit year + 2-digit month, e.g. "9003"
+    YearMonth,
+    /// 1-digit year + 2-digit month, e.g. "903" (DMG-era board stamps)
+    ShortYearMonth,
+    /// 2-digit week + 1-digit year, e.g. "218" (CGB-era board stamps)
+    WeekShortYear,
+}
+
+impl DateCodeConvention {
+    pub const ALL: [DateCodeConvention; 8] = [
+        DateCodeConvention::ShortYear,
+        DateCodeConvention::YearWeek,
+        DateCodeConvention::ShortYearWeek,
+        DateCodeConvention::ShortYearKdsMonth,
+        DateCodeConvention::KdsMonthShortYear,
+        DateCodeConvention::YearMonth,
+        DateCodeConvention::ShortYearMonth,
+        DateCodeConvention::WeekShortYear,
+    ];
+    pub const fn name(&self) -> &'static str {
+        match self {
+            DateCodeConvention::ShortYear => "Y",
+            DateCodeConvention::YearWeek => "YYWW",
+            DateCodeConvention::ShortYearWeek => "YWW",
+            DateCodeConvention::ShortYearKdsMonth => "YM",
+            DateCodeConvention::KdsMonthShortYear => "MY",
+            DateCodeConvention::YearMonth => "YYMM",
+            DateCodeConvention::ShortYearMonth => "YMM",
+            DateCodeConvention::WeekShortYear => "WWY",
+        }
+    }
+    /// Looks up a convention by its name, e.g. "YYWW"
+    pub fn from_name(name: &str) -> Option<DateCodeConvention> {
+        DateCodeConvention::ALL
+            .into_iter()
+            .find(|convention| convention.name() == name)
+    }
+    /// Regex fragment matching a date code in this convention
+    pub const fn pattern(&self) -> &'static str {
+        match self {
+            DateCodeConvention::ShortYear => "[0-9]",
+            DateCodeConvention::YearWeek => "(?:[0-9]{2}|AL|AA)[0-9]{2}",
+            DateCodeConvention::ShortYearWeek => "[0-9]{3}",
+            DateCodeConvention::ShortYearKdsMonth => "[0-9][A-HJ-M]",
+            DateCodeConvention::KdsMonthShortYear => "[A-HJ-M][0-9]",
+            DateCodeConvention::YearMonth => "[0-9]{4}",
+            DateCodeConvention::ShortYearMonth => "[0-9]{3}",
+            DateCodeConvention::WeekShortYear => "[0-9]{3}",
+        }
+    }
+    /// Decodes a date code written in this convention. Whitespace inside the code is ignored, so
+    /// "8 24" is decoded like "824"
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::{DateCodeConvention, Year};
+    /// use gbhwdb_backend::time::{Month, Week};
+    ///
+    /// let code = DateCodeConvention::YearWeek.decode("9824").unwrap();
+    /// assert_eq!(code.year, Some(Year::Full(1998)));
+    /// assert_eq!(code.week, Week::try_from(24).ok());
+    ///
+    /// let code = DateCodeConvention::ShortYear.decode("8").unwrap();
+    /// assert_eq!(code.year, Some(Year::Partial(8)));
+    /// assert_eq!(code.week, None);
+    ///
+    /// let code = DateCodeConvention::ShortYearWeek.decode("8 24").unwrap();
+    /// assert_eq!(code.year, Some(Year::Partial(8)));
+    /// assert_eq!(code.week, Week::try_from(24).ok());
+    ///
+    /// let code = DateCodeConvention::ShortYearKdsMonth.decode("6J").unwrap();
+    /// assert_eq!(code.year, Some(Year::Partial(6)));
+    /// assert_eq!(code.month, Some(Month::September));
+    ///
+    /// let code = DateCodeConvention::KdsMonthShortYear.decode("A2").unwrap();
+    /// assert_eq!(code.year, Some(Year::Partial(2)));
+    /// assert_eq!(code.month, Some(Month::January));
+    ///
+    /// let code = DateCodeConvention::YearMonth.decode("9003").unwrap();
+    /// assert_eq!(code.year, Some(Year::Full(1990)));
+    /// assert_eq!(code.month, Some(Month::March));
+    ///
+    /// let code = DateCodeConvention::ShortYearMonth.decode("903").unwrap();
+    /// assert_eq!(code.year, Some(Year::Partial(9)));
+    /// assert_eq!(code.month, Some(Month::March));
+    ///
+    /// let code = DateCodeConvention::WeekShortYear.decode("218").unwrap();
+    /// assert_eq!(code.year, Some(Year::Partial(8)));
+    /// assert_eq!(code.week, Week::try_from(21).ok());
+    /// ```
+    pub fn decode(&self, text: &str) -> Result<PartDateCode, String> {
+        let text = &text.split_whitespace().collect::<String>();
+        let (head, tail) = match self {
+            DateCodeConvention::ShortYear => split_exact(text, 1, 1),
+            DateCodeConvention::YearWeek | DateCodeConvention::YearMonth => split_exact(text, 2, 4),
+            DateCodeConvention::ShortYearWeek | DateCodeConvention::ShortYearMonth => {
+                split_exact(text, 1, 3)
+            }
+            DateCodeConvention::ShortYearKdsMonth | DateCodeConvention::KdsMonthShortYear => {
+                split_exact(text, 1, 2)
+            }
+            DateCodeConvention::WeekShortYear => split_exact(text, 2, 3),
+        }
+        .ok_or_else(|| format!("Invalid {} date code: {}", self.name(), text))?;
+        match self {
+            DateCodeConvention::ShortYear => Ok(PartDateCode {
+                year: Some(year1(head)?),
+                ..PartDateCode::default()
+            }),
+            DateCodeConvention::YearWeek | DateCodeConvention::ShortYearWeek => {
+                let year = match self {
+                    DateCodeConvention::YearWeek => year2(head)?,
+                    _ => year1(head)?,
+                };
+                Ok(PartDateCode {
+                    year: Some(year),
+                    week: Some(week2(tail)?),
+                    ..PartDateCode::default()
+                })
+            }
+            DateCodeConvention::ShortYearKdsMonth => Ok(PartDateCode {
+                year: Some(year1(head)?),
+                month: Some(kds_month(tail)?),
+                ..PartDateCode::default()
+            }),
+            DateCodeConvention::KdsMonthShortYear => Ok(PartDateCode {
+                year: Some(year1(tail)?),
+                month: Some(kds_month(head)?),
+                ..PartDateCode::default()
+            }),
+            DateCodeConvention::YearMonth => Ok(PartDateCode {
+                year: Some(year2(head)?),
+                month: Some(month2(tail)?),
+                ..PartDateCode::default()
+            }),
+            DateCodeConvention::ShortYearMonth => Ok(PartDateCode {
+                year: Some(year1(head)?),
+                month: Some(month2(tail)?),
+                ..PartDateCode::default()
+            }),
+            DateCodeConvention::WeekShortYear => Ok(PartDateCode {
+                year: Some(year1(tail)?),
+                week: Some(week2(head)?),
+                ..PartDateCode::default()
+            }),
+        }
+    }
+}
+
+fn year1(text: &str) -> Result<Year, String> {
+    match u8::from_str(text) {
+        Ok(value) => Ok(Year::Partial(value)),
+        _ => Err(format!("Invalid 1-digit year: {}", text)),
+    }
+}
+
+fn year2(text: &str) -> Result<Year, String> {
+    if text == "AL" {
+        return Ok(Year::Full(2000));
+    }
+    if text == "AA" {
+        return Ok(Year::Full(2001));
+    }
+    match u16::from_str(text) {
+        Ok(value @ 0..=87) => Ok(Year::Full(value + 2000)),
+        Ok(value @ 88..=99) => Ok(Year::Full(value + 1900)),
+        _ => Err(format!("Invalid 2-digit year: {}", text)),
+    }
+}
+
+fn week2(text: &str) -> Result<Week, String> {
+    u8::from_str(text)
+        .ok()
+        .and_then(|v| Week::try_from(v).ok())
+        .ok_or_else(|| format!("Invalid 2-digit week: {}", text))
+}
+
+fn month2(text: &str) -> Result<Month, String> {
+    u8::from_str(text)
+        .ok()
+        .and_then(|v| Month::try_from(v).ok())
+        .ok_or_else(|| format!("Invalid 2-digit month: {}", text))
+}
+
+fn split_exact(text: &str, at: usize, len: usize) -> Option<(&str, &str)> {
+    if text.len() == len && text.is_char_boundary(at) {
+        Some(text.split_at(at))
+    } else {
+        None
+    }
+}
+
+/// Decodes a 1-letter month used by KDS, Kinseki and others
+pub fn kds_month(text: &str) -> Result<Month, String> {
+    match text {
+        "A" => Ok(Month::January),
+        "B" => Ok(Month::February),
+        "C" => Ok(Month::March),
+        "D" => Ok(Month::April),
+        "E" => Ok(Month::May),
+        "F" => Ok(Month::June),
+        "G" => Ok(Month::July),
+        "H" => Ok(Month::August),
+        // I is intentionally skipped
+        "J" => Ok(Month::September),
+        "K" => Ok(Month::October),
+        "L" => Ok(Month::November),
+        "M" => Ok(Month::December),
+        _ => Err(format!("Invalid 1-letter month: {}", text)),
+    }
+}
+
+#[test]
+fn test_decode_invalid() {
+    assert!(DateCodeConvention::ShortYear.decode("A").is_err());
+    assert!(DateCodeConvention::YearWeek.decode("982").is_err());
+    assert!(DateCodeConvention::YearWeek.decode("9899").is_err());
+    assert!(DateCodeConvention::ShortYearWeek.decode("8241").is_err());
+    assert!(DateCodeConvention::ShortYearKdsMonth.decode("6I").is_err());
+    assert!(DateCodeConvention::KdsMonthShortYear.decode("2A").is_err());
+    assert!(DateCodeConvention::YearMonth.decode("9013").is_err());
+    assert!(DateCodeConvention::ShortYearMonth.decode("913").is_err());
+    assert!(DateCodeConvention::WeekShortYear.decode("5A8").is_err());
+}
+
+#[test]
+fn test_decode_year_week_letter_years() {
+    let code = DateCodeConvention::YearWeek.decode("AL12").unwrap();
+    assert_eq!(code.year, Some(Year::Full(2000)));
+    let code = DateCodeConvention::YearWeek.decode("AA12").unwrap();
+    assert_eq!(code.year, Some(Year::Full(2001)));
+}
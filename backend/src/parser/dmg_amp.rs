@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::{macros::single_parser, parser::Manufacturer};
 
 pub type DmgAmp = ChipYearWeek;
@@ -15,13 +15,16 @@ pub type DmgAmp = ChipYearWeek;
 pub fn sharp_ir3r40() -> &'static impl LabelParser<DmgAmp> {
     single_parser!(
         DmgAmp,
-        r#"^DMG-AMP\ IR3R40\ ([0-9]{2})([0-9]{2})\ [A-Z]{1,2}$"#,
+        "dmg_amp::sharp_ir3r40",
+        r#"^DMG-AMP\ IR3R40\ ([0-9]{4})\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(DmgAmp {
                 kind: "IR3R40".to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::{macros::single_parser, parser::Manufacturer};
 
 pub type DmgReg = ChipYearWeek;
@@ -16,13 +16,16 @@ pub type DmgReg = ChipYearWeek;
 pub fn sharp_ir3e02() -> &'static impl LabelParser<DmgReg> {
     single_parser!(
         DmgReg,
-        r#"^DMG-REG\ IR3E02\ ([0-9]{2})([0-9]{2})\ [a-zA-Z]{1,2}$"#,
+        "dmg_reg::sharp_ir3e02",
+        r#"^DMG-REG\ IR3E02\ ([0-9]{4})\ [a-zA-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(DmgReg {
                 kind: "IR3E02".to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -2,13 +2,14 @@
 //
 // SPDX-License-Identifier: MIT
 
-use super::{month2, year1, LabelParser, Year};
+use super::{Country, DateCodeConvention, LabelParser, Year};
 use crate::{macros::single_parser, time::Month};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DmgStamp {
     pub year: Option<Year>,
     pub month: Option<Month>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -21,11 +22,14 @@ pub struct DmgStamp {
 pub fn dmg_stamp() -> &'static impl LabelParser<DmgStamp> {
     single_parser!(
         DmgStamp,
-        r#"^([0-9])([0-9]{2})[-\ .][0-9-]{2,4}Y?$"#,
+        "dmg_stamp::dmg_stamp",
+        r#"^([0-9]{3})[-\ .][0-9-]{2,4}Y?$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearMonth.decode(&c[1])?;
             Ok(DmgStamp {
-                year: Some(year1(&c[1])?),
-                month: Some(month2(&c[2])?),
+                year: date.year,
+                month: date.month,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::{multi_parser, single_parser};
 
 pub type Eeprom = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type Eeprom = ChipYearWeek;
 pub fn lcs5() -> &'static impl LabelParser<Eeprom> {
     single_parser!(
         Eeprom,
-        r#"^LCS5\ ([0-9])([0-9]{2})(\ [0-9]{2})?$"#,
+        "eeprom::lcs5",
+        r#"^LCS5\ ([0-9]{3})(\ [0-9]{2})?$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Eeprom {
                 kind: "LCS5".to_owned(),
                 manufacturer: None,
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -31,14 +34,20 @@ pub fn lcs5() -> &'static impl LabelParser<Eeprom> {
 /// assert!(parser::eeprom::lc56().parse("LC56 W617 08").is_ok());
 /// ```
 pub fn lc56() -> &'static impl LabelParser<Eeprom> {
-    single_parser!(Eeprom, r#"^LC56\ [A-Z][0-9]{3}\ [0-9]{2}$"#, move |_| {
-        Ok(Eeprom {
-            kind: "LC56".to_owned(),
-            manufacturer: None,
-            year: None,
-            week: None,
-        })
-    })
+    single_parser!(
+        Eeprom,
+        "eeprom::lc56",
+        r#"^LC56\ [A-Z][0-9]{3}\ [0-9]{2}$"#,
+        move |_| {
+            Ok(Eeprom {
+                kind: "LC56".to_owned(),
+                manufacturer: None,
+                year: None,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn eeprom() -> &'static impl LabelParser<Eeprom> {
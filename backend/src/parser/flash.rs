@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser, Manufacturer};
+use super::{capture_country, ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type Flash = ChipYearWeek;
@@ -16,13 +16,16 @@ pub type Flash = ChipYearWeek;
 pub fn macronix_mx29f008() -> &'static impl LabelParser<Flash> {
     single_parser!(
         Flash,
-        r#"^[A-Z]([0-9]{2})([0-9]{2})[0-9]{2}\ (29F008[A-Z]{2}-[0-9]{2})\ [0-9]{5}\ TAIWAN$"#,
+        "flash::macronix_mx29f008",
+        r#"^[A-Z]([0-9]{4})[0-9]{2}\ (29F008[A-Z]{2}-[0-9]{2})\ [0-9]{5}\ (?P<country>TAIWAN)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Flash {
-                kind: format!("MX{}", &c[3]),
+                kind: format!("MX{}", &c[2]),
                 manufacturer: Some(Manufacturer::Macronix),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::single_parser;
 
 pub type GbsDol = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type GbsDol = ChipYearWeek;
 pub fn unknown() -> &'static impl LabelParser<GbsDol> {
     single_parser!(
         GbsDol,
-        r#"^Nintendo\ GBS-DOL\ 011\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "gbs_dol::unknown",
+        r#"^Nintendo\ GBS-DOL\ 011\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(GbsDol {
                 kind: "GBS-DOL".to_owned(),
                 manufacturer: None,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
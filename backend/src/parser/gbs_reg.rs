@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type GbsReg = ChipYearWeek;
@@ -12,14 +12,21 @@ pub type GbsReg = ChipYearWeek;
 /// assert!(parser::gbs_reg::mitsumi_mm1592f().parse("548 592F").is_ok());
 /// ```
 pub fn mitsumi_mm1592f() -> &'static impl LabelParser<GbsReg> {
-    single_parser!(GbsReg, r#"^([0-9])([0-9]{2})\ 592F$"#, move |c| {
-        Ok(GbsReg {
-            kind: "MM1592F".to_owned(),
-            manufacturer: Some(Manufacturer::Mitsumi),
-            year: Some(year1(&c[1])?),
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        GbsReg,
+        "gbs_reg::mitsumi_mm1592f",
+        r#"^([0-9]{3})\ 592F$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
+            Ok(GbsReg {
+                kind: "MM1592F".to_owned(),
+                manufacturer: Some(Manufacturer::Mitsumi),
+                year: date.year,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn gbs_reg() -> &'static impl LabelParser<GbsReg> {
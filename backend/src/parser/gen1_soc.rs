@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, LabelParser, Year};
+use super::{capture_country, Country, DateCodeConvention, LabelParser, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -19,11 +19,26 @@ pub enum Gen1SocKind {
     Sgb,
 }
 
+impl Gen1SocKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Gen1SocKind::Dmg0 => "DMG-CPU",
+            Gen1SocKind::DmgA => "DMG-CPU A",
+            Gen1SocKind::DmgB => "DMG-CPU B",
+            Gen1SocKind::DmgC => "DMG-CPU C",
+            Gen1SocKind::DmgBlobB => "DMG-CPU B (blob)",
+            Gen1SocKind::DmgBlobC => "DMG-CPU C (blob)",
+            Gen1SocKind::Sgb => "SGB-CPU 01",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Gen1Soc {
     pub kind: Gen1SocKind,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -33,12 +48,15 @@ pub struct Gen1Soc {
 pub fn dmg_cpu_lr35902() -> &'static impl LabelParser<Gen1Soc> {
     single_parser!(
         Gen1Soc,
-        r#"^DMG-CPU\ LR35902\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "gen1_soc::dmg_cpu_lr35902",
+        r#"^DMG-CPU\ LR35902\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Gen1Soc {
                 kind: Gen1SocKind::Dmg0,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -54,8 +72,10 @@ pub fn dmg_cpu_lr35902() -> &'static impl LabelParser<Gen1Soc> {
 pub fn dmg_cpu() -> &'static impl LabelParser<Gen1Soc> {
     single_parser!(
         Gen1Soc,
-        r#"^DMG-CPU(\ [ABC])?\ ©\ 1989\ Nintendo\ JAPAN\ ([0-9]{2})([0-9]{2})\ [A-Z]{1,2}$"#,
+        "gen1_soc::dmg_cpu",
+        r#"^DMG-CPU(\ [ABC])?\ ©\ 1989\ Nintendo\ (?P<country>JAPAN)\ ([0-9]{4})\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Gen1Soc {
                 kind: (match c.get(1).map(|m| m.as_str()) {
                     Some(" A") => Ok(Gen1SocKind::DmgA),
@@ -64,8 +84,9 @@ pub fn dmg_cpu() -> &'static impl LabelParser<Gen1Soc> {
                     Some(text) => Err(format!("Invalid DMG-CPU part name: {}", text)),
                     _ => Ok(Gen1SocKind::Dmg0),
                 })?,
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -74,8 +95,10 @@ pub fn dmg_cpu() -> &'static impl LabelParser<Gen1Soc> {
 pub fn dmg_cpu_deprecated() -> &'static impl LabelParser<Gen1Soc> {
     single_parser!(
         Gen1Soc,
-        r#"^DMG-CPU(\ [A-B])?\ ([0-9]{2})([0-9]{2})\ [A-Z]{1,2}$"#,
+        "gen1_soc::dmg_cpu_deprecated",
+        r#"^DMG-CPU(\ [A-B])?\ ([0-9]{4})\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Gen1Soc {
                 kind: (match c.get(1).map(|m| m.as_str()) {
                     Some(" A") => Ok(Gen1SocKind::DmgA),
@@ -83,8 +106,9 @@ pub fn dmg_cpu_deprecated() -> &'static impl LabelParser<Gen1Soc> {
                     Some(text) => Err(format!("Invalid DMG-CPU part name: {}", text)),
                     _ => Ok(Gen1SocKind::Dmg0),
                 })?,
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -96,7 +120,7 @@ pub fn dmg_cpu_deprecated() -> &'static impl LabelParser<Gen1Soc> {
 /// assert!(parser::gen1_soc::dmg_cpu_blob().parse("C").is_ok());
 /// ```
 pub fn dmg_cpu_blob() -> &'static impl LabelParser<Gen1Soc> {
-    single_parser!(Gen1Soc, r#"^[BC]$"#, move |c| {
+    single_parser!(Gen1Soc, "gen1_soc::dmg_cpu_blob", r#"^[BC]$"#, move |c| {
         Ok(Gen1Soc {
             kind: (match &c[0] {
                 "B" => Ok(Gen1SocKind::DmgBlobB),
@@ -105,6 +129,7 @@ pub fn dmg_cpu_blob() -> &'static impl LabelParser<Gen1Soc> {
             })?,
             year: None,
             week: None,
+            country: None,
         })
     })
 }
@@ -116,12 +141,15 @@ pub fn dmg_cpu_blob() -> &'static impl LabelParser<Gen1Soc> {
 pub fn sgb_cpu() -> &'static impl LabelParser<Gen1Soc> {
     single_parser!(
         Gen1Soc,
-        r#"^SGB-CPU\ 01\ ©\ 1994\ Nintendo\ Ⓜ\ 1989\ Nintendo\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]$"#,
+        "gen1_soc::sgb_cpu",
+        r#"^SGB-CPU\ 01\ ©\ 1994\ Nintendo\ Ⓜ\ 1989\ Nintendo\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Gen1Soc {
                 kind: Gen1SocKind::Sgb,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, LabelParser, Year};
+use super::{capture_country, Country, DateCodeConvention, LabelParser, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -14,11 +14,21 @@ pub enum Gen2SocKind {
     Sgb2,
 }
 
+impl Gen2SocKind {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Gen2SocKind::Mgb => "CPU MGB",
+            Gen2SocKind::Sgb2 => "CPU SGB2",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Gen2Soc {
     pub kind: Gen2SocKind,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -29,12 +39,15 @@ pub struct Gen2Soc {
 pub fn cpu_mgb() -> &'static impl LabelParser<Gen2Soc> {
     single_parser!(
         Gen2Soc,
-        r#"^CPU\ MGB\ Ⓜ\ ©\ 1996\ Nintendo\ JAPAN\ ([0-9]{2})([0-9]{2})\ [A-Z]{1,2}$"#,
+        "gen2_soc::cpu_mgb",
+        r#"^CPU\ MGB\ Ⓜ\ ©\ 1996\ Nintendo\ (?P<country>JAPAN)\ ([0-9]{4})\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Gen2Soc {
                 kind: Gen2SocKind::Mgb,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -47,12 +60,15 @@ pub fn cpu_mgb() -> &'static impl LabelParser<Gen2Soc> {
 pub fn cpu_sgb2() -> &'static impl LabelParser<Gen2Soc> {
     single_parser!(
         Gen2Soc,
-        r#"^CPU\ SGB2\ Ⓜ\ 1996\ Nintendo\ ©\ 1997\ Nintendo\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ ?[A-Z]$"#,
+        "gen2_soc::cpu_sgb2",
+        r#"^CPU\ SGB2\ Ⓜ\ 1996\ Nintendo\ ©\ 1997\ Nintendo\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ ?[A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Gen2Soc {
                 kind: Gen2SocKind::Sgb2,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
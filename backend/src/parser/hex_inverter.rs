@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type HexInverter = ChipYearWeek;
@@ -12,14 +12,21 @@ pub type HexInverter = ChipYearWeek;
 /// assert!(parser::hex_inverter::toshiba_tc74lvx04ft().parse("LVX 04 8 45").is_ok());
 /// ```
 pub fn toshiba_tc74lvx04ft() -> &'static impl LabelParser<HexInverter> {
-    single_parser!(HexInverter, r#"^LVX\ 04\ ([0-9])\ ([0-9]{2})$"#, move |c| {
-        Ok(HexInverter {
-            kind: "TC74LVX04FT".to_owned(),
-            manufacturer: Some(Manufacturer::Toshiba),
-            year: Some(year1(&c[1])?),
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        HexInverter,
+        "hex_inverter::toshiba_tc74lvx04ft",
+        r#"^LVX\ 04\ ([0-9]\ [0-9]{2})$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
+            Ok(HexInverter {
+                kind: "TC74LVX04FT".to_owned(),
+                manufacturer: Some(Manufacturer::Toshiba),
+                year: date.year,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn hex_inverter() -> &'static impl LabelParser<HexInverter> {
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::{multi_parser, single_parser};
 
 pub type Icd2 = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type Icd2 = ChipYearWeek;
 pub fn unknown() -> &'static impl LabelParser<Icd2> {
     single_parser!(
         Icd2,
-        r#"^Nintendo\ (ICD2-[NR])\ ([0-9])([0-9]{2})\ [0-9]{3}$"#,
+        "icd2::unknown",
+        r#"^Nintendo\ (ICD2-[NR])\ ([0-9]{3})\ [0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(Icd2 {
                 kind: c[1].to_owned(),
                 manufacturer: None,
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -33,13 +36,16 @@ pub fn unknown() -> &'static impl LabelParser<Icd2> {
 pub fn unknown2() -> &'static impl LabelParser<Icd2> {
     single_parser!(
         Icd2,
-        r#"^Nintendo\ (ICD2-[NR])\ ([0-9]{2})\ ?([0-9]{2})[A-Z]{2}[0-9]{3}\ (D93115|D93128)$"#,
+        "icd2::unknown2",
+        r#"^Nintendo\ (ICD2-[NR])\ ([0-9]{2}\ ?[0-9]{2})[A-Z]{2}[0-9]{3}\ (D93115|D93128)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Icd2 {
                 kind: c[1].to_owned(),
                 manufacturer: None,
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
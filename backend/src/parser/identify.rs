@@ -0,0 +1,782 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::{cmp::Reverse, marker::PhantomData};
+
+use super::*;
+use crate::config::{
+    cartridge::{BoardLayout, PartRole},
+    label_format::installed_label_formats,
+};
+
+/// Family of parts recognized by one of the top-level parsers
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum PartFamily {
+    Accelerometer,
+    AgbAmp,
+    AgbPmic,
+    AgbReg,
+    AgbSocBga,
+    AgbSocQfp128,
+    AgbSocQfp156,
+    AgsChargeController,
+    AgsPmicNew,
+    AgsPmicOld,
+    CgbReg,
+    CgbSoc,
+    CgbStamp,
+    Cic,
+    Coil,
+    Crystal20MiHz,
+    Crystal32KiHz,
+    Crystal32MiHz,
+    Crystal4MiHz,
+    Crystal8MiHz,
+    DmgAmp,
+    DmgReg,
+    DmgStamp,
+    Eeprom,
+    Flash,
+    GbsDol,
+    GbsReg,
+    Gen1Soc,
+    Gen2Soc,
+    HexInverter,
+    Icd2,
+    LcdChip,
+    LcdScreen,
+    LineDecoder,
+    Mapper,
+    MaskRom,
+    MgbAmp,
+    MglTransformer,
+    OxyPmic,
+    OxyU4,
+    OxyU5,
+    Ram,
+    Rtc,
+    SgbRom,
+    SramTsop1_48,
+    SupervisorReset,
+    Tama,
+}
+
+impl PartFamily {
+    pub const ALL: [PartFamily; 47] = [
+        PartFamily::Accelerometer,
+        PartFamily::AgbAmp,
+        PartFamily::AgbPmic,
+        PartFamily::AgbReg,
+        PartFamily::AgbSocBga,
+        PartFamily::AgbSocQfp128,
+        PartFamily::AgbSocQfp156,
+        PartFamily::AgsChargeController,
+        PartFamily::AgsPmicNew,
+        PartFamily::AgsPmicOld,
+        PartFamily::CgbReg,
+        PartFamily::CgbSoc,
+        PartFamily::CgbStamp,
+        PartFamily::Cic,
+        PartFamily::Coil,
+        PartFamily::Crystal20MiHz,
+        PartFamily::Crystal32KiHz,
+        PartFamily::Crystal32MiHz,
+        PartFamily::Crystal4MiHz,
+        PartFamily::Crystal8MiHz,
+        PartFamily::DmgAmp,
+        PartFamily::DmgReg,
+        PartFamily::DmgStamp,
+        PartFamily::Eeprom,
+        PartFamily::Flash,
+        PartFamily::GbsDol,
+        PartFamily::GbsReg,
+        PartFamily::Gen1Soc,
+        PartFamily::Gen2Soc,
+        PartFamily::HexInverter,
+        PartFamily::Icd2,
+        PartFamily::LcdChip,
+        PartFamily::LcdScreen,
+        PartFamily::LineDecoder,
+        PartFamily::Mapper,
+        PartFamily::MaskRom,
+        PartFamily::MgbAmp,
+        PartFamily::MglTransformer,
+        PartFamily::OxyPmic,
+        PartFamily::OxyU4,
+        PartFamily::OxyU5,
+        PartFamily::Ram,
+        PartFamily::Rtc,
+        PartFamily::SgbRom,
+        PartFamily::SramTsop1_48,
+        PartFamily::SupervisorReset,
+        PartFamily::Tama,
+    ];
+    /// Name of the parser module handling this family, also used as the family of label formats
+    pub const fn id(&self) -> &'static str {
+        match self {
+            PartFamily::Accelerometer => "accelerometer",
+            PartFamily::AgbAmp => "agb_amp",
+            PartFamily::AgbPmic => "agb_pmic",
+            PartFamily::AgbReg => "agb_reg",
+            PartFamily::AgbSocBga => "agb_soc_bga",
+            PartFamily::AgbSocQfp128 => "agb_soc_qfp_128",
+            PartFamily::AgbSocQfp156 => "agb_soc_qfp_156",
+            PartFamily::AgsChargeController => "ags_charge_ctrl",
+            PartFamily::AgsPmicNew => "ags_pmic_new",
+            PartFamily::AgsPmicOld => "ags_pmic_old",
+            PartFamily::CgbReg => "cgb_reg",
+            PartFamily::CgbSoc => "cgb_soc",
+            PartFamily::CgbStamp => "cgb_stamp",
+            PartFamily::Cic => "cic",
+            PartFamily::Coil => "coil",
+            PartFamily::Crystal20MiHz => "crystal_20mihz",
+            PartFamily::Crystal32KiHz => "crystal_32kihz",
+            PartFamily::Crystal32MiHz => "crystal_32mihz",
+            PartFamily::Crystal4MiHz => "crystal_4mihz",
+            PartFamily::Crystal8MiHz => "crystal_8mihz",
+            PartFamily::DmgAmp => "dmg_amp",
+            PartFamily::DmgReg => "dmg_reg",
+            PartFamily::DmgStamp => "dmg_stamp",
+            PartFamily::Eeprom => "eeprom",
+            PartFamily::Flash => "flash",
+            PartFamily::GbsDol => "gbs_dol",
+            PartFamily::GbsReg => "gbs_reg",
+            PartFamily::Gen1Soc => "gen1_soc",
+            PartFamily::Gen2Soc => "gen2_soc",
+            PartFamily::HexInverter => "hex_inverter",
+            PartFamily::Icd2 => "icd2",
+            PartFamily::LcdChip => "lcd_chip",
+            PartFamily::LcdScreen => "lcd_screen",
+            PartFamily::LineDecoder => "line_decoder",
+            PartFamily::Mapper => "mapper",
+            PartFamily::MaskRom => "mask_rom",
+            PartFamily::MgbAmp => "mgb_amp",
+            PartFamily::MglTransformer => "mgl_transformer",
+            PartFamily::OxyPmic => "oxy_pmic",
+            PartFamily::OxyU4 => "oxy_u4",
+            PartFamily::OxyU5 => "oxy_u5",
+            PartFamily::Ram => "ram",
+            PartFamily::Rtc => "rtc",
+            PartFamily::SgbRom => "sgb_rom",
+            PartFamily::SramTsop1_48 => "sram_tsop1_48",
+            PartFamily::SupervisorReset => "supervisor_reset",
+            PartFamily::Tama => "tama",
+        }
+    }
+    /// Looks up a family by its id
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::PartFamily;
+    ///
+    /// assert_eq!(PartFamily::from_id("crystal_4mihz"), Some(PartFamily::Crystal4MiHz));
+    /// assert_eq!(PartFamily::from_id("crystal"), None);
+    /// ```
+    pub fn from_id(id: &str) -> Option<PartFamily> {
+        PartFamily::ALL.into_iter().find(|family| family.id() == id)
+    }
+    pub const fn name(&self) -> &'static str {
+        match self {
+            PartFamily::Accelerometer => "Accelerometer",
+            PartFamily::AgbAmp => "AGB amplifier",
+            PartFamily::AgbPmic => "AGB PMIC",
+            PartFamily::AgbReg => "AGB regulator",
+            PartFamily::AgbSocBga => "AGB CPU (BGA)",
+            PartFamily::AgbSocQfp128 => "AGB CPU (QFP-128)",
+            PartFamily::AgbSocQfp156 => "AGB CPU (QFP-156)",
+            PartFamily::AgsChargeController => "AGS charge controller",
+            PartFamily::AgsPmicNew => "AGS PMIC (new)",
+            PartFamily::AgsPmicOld => "AGS PMIC (old)",
+            PartFamily::CgbReg => "CGB regulator",
+            PartFamily::CgbSoc => "CGB CPU",
+            PartFamily::CgbStamp => "CGB board stamp",
+            PartFamily::Cic => "CIC",
+            PartFamily::Coil => "Coil",
+            PartFamily::Crystal20MiHz => "Crystal (20 MiHz)",
+            PartFamily::Crystal32KiHz => "Crystal (32 KiHz)",
+            PartFamily::Crystal32MiHz => "Crystal (32 MiHz)",
+            PartFamily::Crystal4MiHz => "Crystal (4 MiHz)",
+            PartFamily::Crystal8MiHz => "Crystal (8 MiHz)",
+            PartFamily::DmgAmp => "DMG amplifier",
+            PartFamily::DmgReg => "DMG regulator",
+            PartFamily::DmgStamp => "DMG board stamp",
+            PartFamily::Eeprom => "EEPROM",
+            PartFamily::Flash => "Flash",
+            PartFamily::GbsDol => "GBS DOL",
+            PartFamily::GbsReg => "GBS regulator",
+            PartFamily::Gen1Soc => "DMG/SGB CPU",
+            PartFamily::Gen2Soc => "MGB/SGB2 CPU",
+            PartFamily::HexInverter => "Hex inverter",
+            PartFamily::Icd2 => "ICD2",
+            PartFamily::LcdChip => "LCD chip",
+            PartFamily::LcdScreen => "LCD screen",
+            PartFamily::LineDecoder => "Line decoder",
+            PartFamily::Mapper => "Mapper",
+            PartFamily::MaskRom => "Mask ROM",
+            PartFamily::MgbAmp => "MGB amplifier",
+            PartFamily::MglTransformer => "MGL transformer",
+            PartFamily::OxyPmic => "OXY PMIC",
+            PartFamily::OxyU4 => "OXY U4",
+            PartFamily::OxyU5 => "OXY U5",
+            PartFamily::Ram => "RAM",
+            PartFamily::Rtc => "RTC",
+            PartFamily::SgbRom => "SGB ROM",
+            PartFamily::SramTsop1_48 => "SRAM (TSOP-I-48)",
+            PartFamily::SupervisorReset => "Supervisor & Reset",
+            PartFamily::Tama => "TAMA",
+        }
+    }
+    /// Cartridge role whose parser covers this family, if any
+    pub const fn cartridge_role(&self) -> Option<PartRole> {
+        match self {
+            PartFamily::Accelerometer => Some(PartRole::Accelerometer),
+            PartFamily::Crystal32KiHz => Some(PartRole::Crystal),
+            PartFamily::Eeprom => Some(PartRole::Eeprom),
+            PartFamily::Flash => Some(PartRole::Flash),
+            PartFamily::HexInverter => Some(PartRole::HexInverter),
+            PartFamily::LineDecoder => Some(PartRole::LineDecoder),
+            PartFamily::Mapper => Some(PartRole::Mapper),
+            PartFamily::MaskRom => Some(PartRole::Rom),
+            PartFamily::Ram => Some(PartRole::Ram),
+            PartFamily::Rtc => Some(PartRole::Rtc),
+            PartFamily::SupervisorReset => Some(PartRole::SupervisorReset),
+            PartFamily::Tama => Some(PartRole::Mcu),
+            _ => None,
+        }
+    }
+    /// Family whose parser handles a cartridge part in the given role
+    pub fn for_cartridge_role(layout: BoardLayout, role: PartRole) -> Option<PartFamily> {
+        match role {
+            PartRole::Rom | PartRole::Mapper if layout == BoardLayout::Tama => {
+                Some(PartFamily::Tama)
+            }
+            PartRole::Rom => Some(PartFamily::MaskRom),
+            PartRole::Mapper => Some(PartFamily::Mapper),
+            PartRole::Ram => Some(PartFamily::Ram),
+            PartRole::SupervisorReset => Some(PartFamily::SupervisorReset),
+            PartRole::Crystal => Some(PartFamily::Crystal32KiHz),
+            PartRole::Flash => Some(PartFamily::Flash),
+            PartRole::Eeprom => Some(PartFamily::Eeprom),
+            PartRole::Accelerometer => Some(PartFamily::Accelerometer),
+            PartRole::LineDecoder => Some(PartFamily::LineDecoder),
+            PartRole::HexInverter => Some(PartFamily::HexInverter),
+            PartRole::Rtc => Some(PartFamily::Rtc),
+            PartRole::Mcu => Some(PartFamily::Tama),
+            PartRole::Unknown => None,
+        }
+    }
+    /// Parses a label with the top-level parser of this family, falling back to the installed
+    /// label formats of the family
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::{identify::PartFamily, Manufacturer};
+    ///
+    /// let part = PartFamily::Mapper.identify("MBC5 LZ9GB31 AL23 A").unwrap();
+    /// assert_eq!(part.kind.as_deref(), Some("MBC5"));
+    /// assert_eq!(part.manufacturer, Some(Manufacturer::Sharp));
+    /// assert!(PartFamily::Ram.identify("MBC5 LZ9GB31 AL23 A").is_err());
+    /// ```
+    pub fn identify(&self, label: &str) -> Result<Identification, String> {
+        self.parser().summarize(*self, label).or_else(|err| {
+            installed_label_formats()
+                .for_family(*self)
+                .find_map(|format| format.parse(label).ok())
+                .ok_or(err)
+        })
+    }
+    /// Names of all individual parsers and installed label formats in this family
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::PartFamily;
+    /// assert!(PartFamily::Mapper.parser_names().contains(&"mapper::sharp_mbc5"));
+    /// ```
+    pub fn parser_names(&self) -> Vec<&'static str> {
+        let formats = installed_label_formats().for_family(*self);
+        self.parser()
+            .parser_names()
+            .into_iter()
+            .chain(formats.map(|format| format.qualified_name.as_str()))
+            .collect()
+    }
+    /// Names of the individual parsers and installed label formats in this family that accept
+    /// the label, in the order they are tried
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::PartFamily;
+    /// let names = PartFamily::Mapper.matching_parsers("MBC5 LZ9GB31 AL23 A");
+    /// assert_eq!(names, ["mapper::sharp_mbc5"]);
+    /// ```
+    pub fn matching_parsers(&self, label: &str) -> Vec<&'static str> {
+        let formats = installed_label_formats()
+            .for_family(*self)
+            .filter(|format| format.parse(label).is_ok());
+        self.parser()
+            .matching_parsers(label)
+            .into_iter()
+            .chain(formats.map(|format| format.qualified_name.as_str()))
+            .collect()
+    }
+    fn parser(&self) -> Box<dyn FamilyParser> {
+        match self {
+            PartFamily::Accelerometer => erase(accelerometer::accelerometer()),
+            PartFamily::AgbAmp => erase(agb_amp::agb_amp()),
+            PartFamily::AgbPmic => erase(agb_pmic::agb_pmic()),
+            PartFamily::AgbReg => erase(agb_reg::agb_reg()),
+            PartFamily::AgbSocBga => erase(agb_soc_bga::agb_soc_bga()),
+            PartFamily::AgbSocQfp128 => erase(agb_soc_qfp_128::agb_soc_qfp_128()),
+            PartFamily::AgbSocQfp156 => erase(agb_soc_qfp_156::agb_soc_qfp_156()),
+            PartFamily::AgsChargeController => erase(ags_charge_ctrl::ags_charge_ctrl()),
+            PartFamily::AgsPmicNew => erase(ags_pmic_new::ags_pmic_new()),
+            PartFamily::AgsPmicOld => erase(ags_pmic_old::ags_pmic_old()),
+            PartFamily::CgbReg => erase(cgb_reg::cgb_reg()),
+            PartFamily::CgbSoc => erase(cgb_soc::cgb_soc()),
+            PartFamily::CgbStamp => erase(cgb_stamp::cgb_stamp()),
+            PartFamily::Cic => erase(cic::cic()),
+            PartFamily::Coil => erase(coil::coil()),
+            PartFamily::Crystal20MiHz => erase(crystal_20mihz::crystal_20mihz()),
+            PartFamily::Crystal32KiHz => erase(crystal_32kihz::crystal_32kihz()),
+            PartFamily::Crystal32MiHz => erase(crystal_32mihz::crystal_32mihz()),
+            PartFamily::Crystal4MiHz => erase(crystal_4mihz::crystal_4mihz()),
+            PartFamily::Crystal8MiHz => erase(crystal_8mihz::crystal_8mihz()),
+            PartFamily::DmgAmp => erase(dmg_amp::dmg_amp()),
+            PartFamily::DmgReg => erase(dmg_reg::dmg_reg()),
+            PartFamily::DmgStamp => erase(dmg_stamp::dmg_stamp()),
+            PartFamily::Eeprom => erase(eeprom::eeprom()),
+            PartFamily::Flash => erase(flash::flash()),
+            PartFamily::GbsDol => erase(gbs_dol::gbs_dol()),
+            PartFamily::GbsReg => erase(gbs_reg::gbs_reg()),
+            PartFamily::Gen1Soc => erase(gen1_soc::gen1_soc()),
+            PartFamily::Gen2Soc => erase(gen2_soc::gen2_soc()),
+            PartFamily::HexInverter => erase(hex_inverter::hex_inverter()),
+            PartFamily::Icd2 => erase(icd2::icd2()),
+            PartFamily::LcdChip => erase(lcd_chip::lcd_chip()),
+            PartFamily::LcdScreen => erase(lcd_screen::lcd_screen()),
+            PartFamily::LineDecoder => erase(line_decoder::line_decoder()),
+            PartFamily::Mapper => erase(mapper::mapper()),
+            PartFamily::MaskRom => erase(mask_rom::mask_rom()),
+            PartFamily::MgbAmp => erase(mgb_amp::mgb_amp()),
+            PartFamily::MglTransformer => erase(mgl_transformer::mgl_transformer()),
+            PartFamily::OxyPmic => erase(oxy_pmic::oxy_pmic()),
+            PartFamily::OxyU4 => erase(oxy_u4::oxy_u4()),
+            PartFamily::OxyU5 => erase(oxy_u5::oxy_u5()),
+            PartFamily::Ram => erase(ram::ram()),
+            PartFamily::Rtc => erase(rtc::rtc()),
+            PartFamily::SgbRom => erase(sgb_rom::sgb_rom()),
+            PartFamily::SramTsop1_48 => erase(sram_tsop1_48::sram_tsop1_48()),
+            PartFamily::SupervisorReset => erase(supervisor_reset::supervisor_reset()),
+            PartFamily::Tama => erase(tama::tama()),
+        }
+    }
+}
+
+/// Candidate identification of a label, independent of the parser result type
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Identification {
+    pub family: PartFamily,
+    pub kind: Option<String>,
+    pub manufacturer: Option<Manufacturer>,
+    pub rom_code: Option<String>,
+    pub date_code: PartDateCode,
+    pub country: Option<Country>,
+}
+
+impl Identification {
+    /// Rough measure of how much information the label yielded.
+    ///
+    /// Used to rank candidates: a parser that recognizes the part kind and manufacturer is
+    /// a better guess than a loose parser that only picks up a date code.
+    pub fn score(&self) -> u32 {
+        let mut score = 0;
+        if self.kind.is_some() {
+            score += 4;
+        }
+        if self.manufacturer.is_some() {
+            score += 2;
+        }
+        if self.rom_code.is_some() {
+            score += 2;
+        }
+        if self.date_code.year.is_some() {
+            score += 1;
+        }
+        if self.date_code.month.is_some() || self.date_code.week.is_some() {
+            score += 1;
+        }
+        score
+    }
+}
+
+/// Role-independent view of a parser result
+pub trait PartSummary {
+    fn kind(&self) -> Option<String> {
+        None
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        None
+    }
+    fn rom_code(&self) -> Option<String> {
+        None
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode::default()
+    }
+    fn country(&self) -> Option<Country>;
+}
+
+/// Family parser with the result type erased
+trait FamilyParser {
+    fn summarize(&self, family: PartFamily, label: &str) -> Result<Identification, String>;
+    fn parser_names(&self) -> Vec<&'static str>;
+    fn matching_parsers(&self, label: &str) -> Vec<&'static str>;
+}
+
+struct Erased<T: 'static, F: 'static>(&'static F, PhantomData<fn() -> T>);
+
+fn erase<T: PartSummary + 'static, F: LabelParser<T>>(f: &'static F) -> Box<dyn FamilyParser> {
+    Box::new(Erased(f, PhantomData))
+}
+
+impl<T: PartSummary, F: LabelParser<T>> FamilyParser for Erased<T, F> {
+    fn summarize(&self, family: PartFamily, label: &str) -> Result<Identification, String> {
+        let part = self.0.parse(label)?;
+        Ok(Identification {
+            family,
+            kind: part.kind(),
+            manufacturer: part.manufacturer(),
+            rom_code: part.rom_code(),
+            date_code: part.date_code(),
+            country: part.country(),
+        })
+    }
+    fn parser_names(&self) -> Vec<&'static str> {
+        self.0
+            .parsers()
+            .iter()
+            .map(|parser| parser.name())
+            .collect()
+    }
+    fn matching_parsers(&self, label: &str) -> Vec<&'static str> {
+        self.0
+            .parsers()
+            .iter()
+            .filter(|parser| parser.parse(label).is_ok())
+            .map(|parser| parser.name())
+            .collect()
+    }
+}
+
+/// Identifies a label by trying every part family.
+///
+/// Returns all matching candidates, best first. Candidates with equal scores keep the order
+/// of `PartFamily::ALL`.
+///
+/// ```
+/// use gbhwdb_backend::parser::{identify, Manufacturer, PartFamily, Year};
+///
+/// let candidates = identify("DMG MBC1B Nintendo S 9107 5 A");
+/// let best = &candidates[0];
+/// assert_eq!(best.family, PartFamily::Mapper);
+/// assert_eq!(best.kind.as_deref(), Some("MBC1B"));
+/// assert_eq!(best.manufacturer, Some(Manufacturer::Sharp));
+/// assert_eq!(best.date_code.year, Some(Year::Full(1991)));
+///
+/// assert!(identify("not a chip").is_empty());
+/// ```
+pub fn identify(label: &str) -> Vec<Identification> {
+    let mut candidates = PartFamily::ALL
+        .iter()
+        .filter_map(|family| family.identify(label).ok())
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|candidate| Reverse(candidate.score()));
+    candidates
+}
+
+impl PartSummary for ChipYearWeek {
+    fn kind(&self) -> Option<String> {
+        Some(self.kind.clone())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Crystal {
+    fn kind(&self) -> Option<String> {
+        Some(self.format_frequency())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            month: self.month,
+            week: self.week,
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for StaticRam {
+    fn kind(&self) -> Option<String> {
+        self.part.clone()
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Gen1Soc {
+    fn kind(&self) -> Option<String> {
+        Some(self.kind.display_name().to_owned())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        Some(Manufacturer::Sharp)
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Gen2Soc {
+    fn kind(&self) -> Option<String> {
+        Some(self.kind.display_name().to_owned())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        Some(Manufacturer::Sharp)
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Coil {
+    fn kind(&self) -> Option<String> {
+        Some(self.kind.clone())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Transformer {
+    fn kind(&self) -> Option<String> {
+        Some(self.kind.clone())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for MaskRom {
+    fn kind(&self) -> Option<String> {
+        self.chip_type.clone()
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn rom_code(&self) -> Option<String> {
+        Some(self.rom_code.clone())
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for SgbRom {
+    fn kind(&self) -> Option<String> {
+        self.chip_type.clone()
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn rom_code(&self) -> Option<String> {
+        Some(self.rom_code.clone())
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Mapper {
+    fn kind(&self) -> Option<String> {
+        Some(self.mbc_type.display_name().to_owned())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for SupervisorReset {
+    fn kind(&self) -> Option<String> {
+        Some(self.chip_type.clone())
+    }
+    fn manufacturer(&self) -> Option<Manufacturer> {
+        self.manufacturer
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for Tama {
+    fn kind(&self) -> Option<String> {
+        Some(self.tama_type.display_name().to_owned())
+    }
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for LcdChip {
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            month: self.month,
+            week: self.week,
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for LcdScreen {
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            month: self.month,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for DmgStamp {
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            month: self.month,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+impl PartSummary for CgbStamp {
+    fn date_code(&self) -> PartDateCode {
+        PartDateCode {
+            year: self.year,
+            week: self.week,
+            ..PartDateCode::default()
+        }
+    }
+    fn country(&self) -> Option<Country> {
+        self.country
+    }
+}
+
+#[test]
+fn test_ids_match_families() {
+    use std::collections::HashSet;
+    let ids = PartFamily::ALL
+        .iter()
+        .map(|family| family.id())
+        .collect::<HashSet<_>>();
+    assert_eq!(ids.len(), PartFamily::ALL.len());
+}
+
+#[test]
+fn test_wrong_designator_is_visible() {
+    // A mapper label entered as a mask ROM should still identify as a mapper
+    assert!(PartFamily::MaskRom.identify("MBC5 LZ9GB31 AL23 A").is_err());
+    let candidates = identify("MBC5 LZ9GB31 AL23 A");
+    assert_eq!(
+        candidates[0].family.cartridge_role(),
+        Some(PartRole::Mapper)
+    );
+}
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{month2, week2, year1, LabelParser, Year};
+use super::{Country, DateCodeConvention, LabelParser, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::{Month, Week},
@@ -13,6 +13,7 @@ pub struct LcdChip {
     pub year: Option<Year>,
     pub month: Option<Month>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -20,13 +21,20 @@ pub struct LcdChip {
 /// assert!(parser::lcd_chip::lcd_chip_old().parse("110").is_ok());
 /// ```
 pub fn lcd_chip_old() -> &'static impl LabelParser<LcdChip> {
-    single_parser!(LcdChip, r#"^([0-9])([0-9]{2})$"#, move |c| {
-        Ok(LcdChip {
-            year: Some(year1(&c[1])?),
-            month: Some(month2(&c[2])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        LcdChip,
+        "lcd_chip::lcd_chip_old",
+        r#"^([0-9]{3})$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearMonth.decode(&c[1])?;
+            Ok(LcdChip {
+                year: date.year,
+                month: date.month,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -34,13 +42,20 @@ pub fn lcd_chip_old() -> &'static impl LabelParser<LcdChip> {
 /// assert!(parser::lcd_chip::lcd_chip_new().parse("5341").is_ok());
 /// ```
 pub fn lcd_chip_new() -> &'static impl LabelParser<LcdChip> {
-    single_parser!(LcdChip, r#"^([0-9])([0-9]{2})[0-9]$"#, move |c| {
-        Ok(LcdChip {
-            year: Some(year1(&c[1])?),
-            month: None,
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        LcdChip,
+        "lcd_chip::lcd_chip_new",
+        r#"^([0-9]{3})[0-9]$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
+            Ok(LcdChip {
+                year: date.year,
+                month: None,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn lcd_chip() -> &'static impl LabelParser<LcdChip> {
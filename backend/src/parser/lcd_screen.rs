@@ -1,11 +1,10 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{month2, year1, LabelParser, Year};
+use super::{Country, DateCodeConvention, LabelParser, Year};
 use crate::{
     macros::{multi_parser, single_parser},
-    parser::year2,
     time::Month,
 };
 
@@ -13,6 +12,7 @@ use crate::{
 pub struct LcdScreen {
     pub year: Option<Year>,
     pub month: Option<Month>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -20,12 +20,19 @@ pub struct LcdScreen {
 /// assert!(parser::lcd_screen::lcd_screen1().parse("S890220").is_ok());
 /// ```
 pub fn lcd_screen1() -> &'static impl LabelParser<LcdScreen> {
-    single_parser!(LcdScreen, r#"^.*([0-9]{2})([0-9]{2})[0-9]{2}$"#, move |c| {
-        Ok(LcdScreen {
-            year: Some(year2(&c[1])?),
-            month: Some(month2(&c[2])?),
-        })
-    })
+    single_parser!(
+        LcdScreen,
+        "lcd_screen::lcd_screen1",
+        r#"^.*([0-9]{4})[0-9]{2}$"#,
+        move |c| {
+            let date = DateCodeConvention::YearMonth.decode(&c[1])?;
+            Ok(LcdScreen {
+                year: date.year,
+                month: date.month,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ```
@@ -35,11 +42,14 @@ pub fn lcd_screen1() -> &'static impl LabelParser<LcdScreen> {
 pub fn lcd_screen2() -> &'static impl LabelParser<LcdScreen> {
     single_parser!(
         LcdScreen,
-        r#"^(.*[^0-9])?([0-9])([0-9]{2})[0-9]{2}$"#,
+        "lcd_screen::lcd_screen2",
+        r#"^(.*[^0-9])?([0-9]{3})[0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearMonth.decode(&c[2])?;
             Ok(LcdScreen {
-                year: Some(year1(&c[2])?),
-                month: Some(month2(&c[3])?),
+                year: date.year,
+                month: date.month,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{year1, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type LineDecoder = ChipYearWeek;
@@ -14,18 +14,25 @@ pub type LineDecoder = ChipYearWeek;
 /// assert!(parser::line_decoder::toshiba_tc7w139f().parse("7W139 0J").is_ok());
 /// ```
 pub fn toshiba_tc7w139f() -> &'static impl LabelParser<LineDecoder> {
-    single_parser!(LineDecoder, r#"^(7W139F?)\ ([0-9])[A-Z]$"#, move |c| {
-        Ok(LineDecoder {
-            kind: (match &c[1] {
-                "7W139" => Ok("TC7W139FU".to_owned()),
-                "7W139F" => Ok("TC7W139F".to_owned()),
-                text => Err(format!("Invalid Toshiba TC7W139F part name: {}", text)),
-            })?,
-            manufacturer: Some(Manufacturer::Toshiba),
-            year: Some(year1(&c[2])?),
-            week: None,
-        })
-    })
+    single_parser!(
+        LineDecoder,
+        "line_decoder::toshiba_tc7w139f",
+        r#"^(7W139F?)\ ([0-9])[A-Z]$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[2])?;
+            Ok(LineDecoder {
+                kind: (match &c[1] {
+                    "7W139" => Ok("TC7W139FU".to_owned()),
+                    "7W139F" => Ok("TC7W139F".to_owned()),
+                    text => Err(format!("Invalid Toshiba TC7W139F part name: {}", text)),
+                })?,
+                manufacturer: Some(Manufacturer::Toshiba),
+                year: date.year,
+                week: None,
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn line_decoder() -> &'static impl LabelParser<LineDecoder> {
@@ -0,0 +1,330 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+use super::DateCodeConvention;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Manufacturer {
+    Amic,
+    Analog,
+    AtT,
+    Bsi,
+    Crosslink,
+    Fujitsu,
+    Hudson,
+    Hynix,
+    Hyundai,
+    Kds,
+    Lgs,
+    LsiLogic,
+    Kinseki,
+    Macronix,
+    Mitsubishi,
+    Mitsumi,
+    MoselVitelic,
+    Motorola,
+    Nec,
+    Oki,
+    Panasonic,
+    Rohm,
+    Samsung,
+    Sanyo,
+    Sharp,
+    Smsc,
+    StMicro,
+    Tdk,
+    TexasInstruments,
+    Toshiba,
+    Victronix,
+    Winbond,
+}
+
+impl Manufacturer {
+    pub const ALL: [Manufacturer; 32] = [
+        Manufacturer::Amic,
+        Manufacturer::Analog,
+        Manufacturer::AtT,
+        Manufacturer::Bsi,
+        Manufacturer::Crosslink,
+        Manufacturer::Fujitsu,
+        Manufacturer::Hudson,
+        Manufacturer::Hynix,
+        Manufacturer::Hyundai,
+        Manufacturer::Kds,
+        Manufacturer::Lgs,
+        Manufacturer::LsiLogic,
+        Manufacturer::Kinseki,
+        Manufacturer::Macronix,
+        Manufacturer::Mitsubishi,
+        Manufacturer::Mitsumi,
+        Manufacturer::MoselVitelic,
+        Manufacturer::Motorola,
+        Manufacturer::Nec,
+        Manufacturer::Oki,
+        Manufacturer::Panasonic,
+        Manufacturer::Rohm,
+        Manufacturer::Samsung,
+        Manufacturer::Sanyo,
+        Manufacturer::Sharp,
+        Manufacturer::Smsc,
+        Manufacturer::StMicro,
+        Manufacturer::Tdk,
+        Manufacturer::TexasInstruments,
+        Manufacturer::Toshiba,
+        Manufacturer::Victronix,
+        Manufacturer::Winbond,
+    ];
+    pub const fn id(&self) -> &'static str {
+        match self {
+            Manufacturer::Amic => "amic",
+            Manufacturer::Analog => "analog",
+            Manufacturer::AtT => "att",
+            Manufacturer::Bsi => "bsi",
+            Manufacturer::Crosslink => "crosslink",
+            Manufacturer::Fujitsu => "fujitsu",
+            Manufacturer::Hudson => "hudson",
+            Manufacturer::Hynix => "hynix",
+            Manufacturer::Hyundai => "hyundai",
+            Manufacturer::Kds => "kds",
+            Manufacturer::Kinseki => "kinseki",
+            Manufacturer::Lgs => "lgs",
+            Manufacturer::LsiLogic => "lsi-logic",
+            Manufacturer::Macronix => "macronix",
+            Manufacturer::Mitsubishi => "mitsubishi",
+            Manufacturer::Mitsumi => "mitsumi",
+            Manufacturer::MoselVitelic => "mosel-vitelic",
+            Manufacturer::Motorola => "motorola",
+            Manufacturer::Nec => "nec",
+            Manufacturer::Oki => "oki",
+            Manufacturer::Panasonic => "panasonic",
+            Manufacturer::Rohm => "rohm",
+            Manufacturer::Samsung => "samsung",
+            Manufacturer::Sanyo => "sanyo",
+            Manufacturer::Sharp => "sharp",
+            Manufacturer::Smsc => "smsc",
+            Manufacturer::StMicro => "st-micro",
+            Manufacturer::Tdk => "tdk",
+            Manufacturer::TexasInstruments => "texas-instruments",
+            Manufacturer::Toshiba => "toshiba",
+            Manufacturer::Victronix => "victronix",
+            Manufacturer::Winbond => "winbond",
+        }
+    }
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Manufacturer::Amic => "AMIC Technology",
+            Manufacturer::Analog => "Analog Devices",
+            Manufacturer::AtT => "AT&T Technologies",
+            Manufacturer::Bsi => "BSI",
+            Manufacturer::Crosslink => "Crosslink Semiconductor",
+            Manufacturer::Fujitsu => "Fujitsu",
+            Manufacturer::Hudson => "Hudson",
+            Manufacturer::Hynix => "Hynix",
+            Manufacturer::Hyundai => "Hyundai",
+            Manufacturer::Kds => "Daishinku",
+            Manufacturer::Kinseki => "Kinseki",
+            Manufacturer::Lgs => "Lucky GoldStar",
+            Manufacturer::LsiLogic => "LSI Logic",
+            Manufacturer::Macronix => "Macronix",
+            Manufacturer::Mitsubishi => "Mitsubishi",
+            Manufacturer::Mitsumi => "Mitsumi",
+            Manufacturer::MoselVitelic => "Mosel-Vitelic",
+            Manufacturer::Motorola => "Motorola",
+            Manufacturer::Nec => "NEC",
+            Manufacturer::Oki => "OKI",
+            Manufacturer::Panasonic => "Panasonic",
+            Manufacturer::Rohm => "ROHM",
+            Manufacturer::Samsung => "Samsung",
+            Manufacturer::Sanyo => "Sanyo",
+            Manufacturer::Sharp => "Sharp",
+            Manufacturer::Smsc => "Standard Microsystems Corporation",
+            Manufacturer::StMicro => "STMicroelectronics",
+            Manufacturer::Tdk => "TDK",
+            Manufacturer::TexasInstruments => "Texas Instruments",
+            Manufacturer::Toshiba => "Toshiba",
+            Manufacturer::Victronix => "Victronix",
+            Manufacturer::Winbond => "Winbond",
+        }
+    }
+    /// Manufacturer names as printed on chip labels
+    pub const fn aliases(&self) -> &'static [&'static str] {
+        match self {
+            Manufacturer::Amic => &["AMIC"],
+            Manufacturer::Analog => &[],
+            Manufacturer::AtT => &["AT&T", "Ⓜ AT&T"],
+            Manufacturer::Bsi => &["BSI"],
+            Manufacturer::Crosslink => &["Xlink"],
+            Manufacturer::Fujitsu => &["FUJITSU"],
+            Manufacturer::Hudson => &["HUDSON"],
+            Manufacturer::Hynix => &["Hynix", "hynix"],
+            Manufacturer::Hyundai => &["HYUNDAI"],
+            Manufacturer::Kds => &["KDS", "KDSI"],
+            Manufacturer::Kinseki => &["KSS"],
+            Manufacturer::Lgs => &["LGS"],
+            Manufacturer::LsiLogic => &["LSI LOGIC"],
+            Manufacturer::Macronix => &[],
+            Manufacturer::Mitsubishi => &[],
+            Manufacturer::Mitsumi => &["MITSUMI"],
+            Manufacturer::MoselVitelic => &["MOSEL-VITELIC"],
+            Manufacturer::Motorola => &[],
+            Manufacturer::Nec => &["NEC"],
+            Manufacturer::Oki => &["OKI"],
+            Manufacturer::Panasonic => &[],
+            Manufacturer::Rohm => &["ROHM"],
+            Manufacturer::Samsung => &["SEC"],
+            Manufacturer::Sanyo => &["SANYO"],
+            Manufacturer::Sharp => &["SHARP"],
+            Manufacturer::Smsc => &["STANDARD MICRO"],
+            Manufacturer::StMicro => &[],
+            Manufacturer::Tdk => &["TDK"],
+            Manufacturer::TexasInstruments => &[],
+            Manufacturer::Toshiba => &["TOSHIBA"],
+            Manufacturer::Victronix => &["Victronix"],
+            Manufacturer::Winbond => &["Winbond"],
+        }
+    }
+    /// Country where the manufacturer is headquartered
+    pub const fn country(&self) -> Option<&'static str> {
+        match self {
+            Manufacturer::Amic => Some("Taiwan"),
+            Manufacturer::Analog => Some("United States"),
+            Manufacturer::AtT => Some("United States"),
+            Manufacturer::Bsi => Some("Taiwan"),
+            Manufacturer::Crosslink => None,
+            Manufacturer::Fujitsu => Some("Japan"),
+            Manufacturer::Hudson => Some("Japan"),
+            Manufacturer::Hynix => Some("South Korea"),
+            Manufacturer::Hyundai => Some("South Korea"),
+            Manufacturer::Kds => Some("Japan"),
+            Manufacturer::Kinseki => Some("Japan"),
+            Manufacturer::Lgs => Some("South Korea"),
+            Manufacturer::LsiLogic => Some("United States"),
+            Manufacturer::Macronix => Some("Taiwan"),
+            Manufacturer::Mitsubishi => Some("Japan"),
+            Manufacturer::Mitsumi => Some("Japan"),
+            Manufacturer::MoselVitelic => Some("Taiwan"),
+            Manufacturer::Motorola => Some("United States"),
+            Manufacturer::Nec => Some("Japan"),
+            Manufacturer::Oki => Some("Japan"),
+            Manufacturer::Panasonic => Some("Japan"),
+            Manufacturer::Rohm => Some("Japan"),
+            Manufacturer::Samsung => Some("South Korea"),
+            Manufacturer::Sanyo => Some("Japan"),
+            Manufacturer::Sharp => Some("Japan"),
+            Manufacturer::Smsc => Some("United States"),
+            Manufacturer::StMicro => Some("Switzerland"),
+            Manufacturer::Tdk => Some("Japan"),
+            Manufacturer::TexasInstruments => Some("United States"),
+            Manufacturer::Toshiba => Some("Japan"),
+            Manufacturer::Victronix => None,
+            Manufacturer::Winbond => Some("Taiwan"),
+        }
+    }
+    /// Text of the logo printed on chips, if the manufacturer uses a logo instead of its name
+    pub const fn logo(&self) -> Option<&'static str> {
+        match self {
+            Manufacturer::Kinseki => Some("KSS"),
+            Manufacturer::Panasonic => Some("P"),
+            Manufacturer::Samsung => Some("SEC"),
+            Manufacturer::Sharp => Some("S"),
+            Manufacturer::Toshiba => Some("T"),
+            _ => None,
+        }
+    }
+    /// Date code conventions seen on chips made by this manufacturer
+    ///
+    /// Empty if no label parser decodes a date code of this manufacturer yet.
+    pub const fn date_code_conventions(&self) -> &'static [DateCodeConvention] {
+        use DateCodeConvention::*;
+        match self {
+            Manufacturer::Amic
+            | Manufacturer::Analog
+            | Manufacturer::AtT
+            | Manufacturer::Bsi
+            | Manufacturer::Fujitsu
+            | Manufacturer::Hudson
+            | Manufacturer::Hynix
+            | Manufacturer::Hyundai
+            | Manufacturer::Lgs
+            | Manufacturer::Macronix
+            | Manufacturer::Motorola
+            | Manufacturer::Nec
+            | Manufacturer::Smsc
+            | Manufacturer::Victronix => &[YearWeek],
+            Manufacturer::Kds => &[YearWeek, ShortYearKdsMonth, KdsMonthShortYear],
+            Manufacturer::Kinseki => &[ShortYearKdsMonth],
+            Manufacturer::Crosslink
+            | Manufacturer::LsiLogic
+            | Manufacturer::MoselVitelic
+            | Manufacturer::Oki
+            | Manufacturer::StMicro
+            | Manufacturer::Winbond => &[ShortYearWeek],
+            Manufacturer::Mitsumi | Manufacturer::Panasonic | Manufacturer::Rohm => {
+                &[ShortYearWeek, ShortYear]
+            }
+            Manufacturer::Sharp => &[YearWeek, ShortYearWeek],
+            Manufacturer::Toshiba => &[YearWeek, ShortYearWeek, ShortYear],
+            Manufacturer::Mitsubishi | Manufacturer::Sanyo | Manufacturer::TexasInstruments => {
+                &[ShortYear]
+            }
+            Manufacturer::Samsung | Manufacturer::Tdk => &[],
+        }
+    }
+    /// Finds a manufacturer by a name printed on a chip label
+    ///
+    /// Logos are not matched, because a single-letter logo such as "S" could be any stray letter.
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::Manufacturer;
+    /// assert_eq!(Manufacturer::from_alias("LSI LOGIC"), Some(Manufacturer::LsiLogic));
+    /// assert_eq!(Manufacturer::from_alias("KDSI"), Some(Manufacturer::Kds));
+    /// assert_eq!(Manufacturer::from_alias("Ⓜ AT&T"), Some(Manufacturer::AtT));
+    /// assert_eq!(Manufacturer::from_alias("Sharp"), Some(Manufacturer::Sharp));
+    /// assert_eq!(Manufacturer::from_alias("S"), None);
+    /// assert_eq!(Manufacturer::from_alias("Nintendo"), None);
+    /// ```
+    pub fn from_alias(text: &str) -> Option<Manufacturer> {
+        let text = text.trim();
+        Manufacturer::ALL.into_iter().find(|manufacturer| {
+            manufacturer.aliases().contains(&text)
+                || manufacturer.name().eq_ignore_ascii_case(text)
+                || manufacturer.id() == text
+        })
+    }
+    /// Finds a manufacturer by the logo printed on a chip label
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::Manufacturer;
+    /// assert_eq!(Manufacturer::from_logo("S"), Some(Manufacturer::Sharp));
+    /// assert_eq!(Manufacturer::from_logo("SHARP"), None);
+    /// ```
+    pub fn from_logo(text: &str) -> Option<Manufacturer> {
+        let text = text.trim();
+        Manufacturer::ALL
+            .into_iter()
+            .find(|manufacturer| manufacturer.logo() == Some(text))
+    }
+}
+
+impl fmt::Display for Manufacturer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[test]
+fn test_unique_ids_and_aliases() {
+    use std::collections::HashSet;
+    let mut ids = HashSet::new();
+    let mut aliases = HashSet::new();
+    for manufacturer in Manufacturer::ALL {
+        assert!(ids.insert(manufacturer.id()), "{manufacturer:?}");
+        for alias in manufacturer.aliases() {
+            assert!(aliases.insert(*alias), "{alias}");
+            assert_eq!(Manufacturer::from_alias(alias), Some(manufacturer));
+        }
+    }
+}
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, LabelParser, Manufacturer, Year};
+use super::{Country, DateCodeConvention, LabelParser, Manufacturer, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -79,6 +79,7 @@ pub struct Mapper {
     pub manufacturer: Option<Manufacturer>,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// Sharp MBC1A
@@ -90,13 +91,16 @@ pub struct Mapper {
 pub fn sharp_mbc1a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^DMG\ MBC1A\ Nintendo\ S\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{1,2}$"#,
+        "mapper::sharp_mbc1a",
+        r#"^DMG\ MBC1A\ Nintendo\ S\ ([0-9]{4})\ [0-9]\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::A),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -111,13 +115,16 @@ pub fn sharp_mbc1a() -> &'static impl LabelParser<Mapper> {
 pub fn sharp_mbc1b() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^DMG\ MBC1B\ Nintendo\ S\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{1,2}$"#,
+        "mapper::sharp_mbc1b",
+        r#"^DMG\ MBC1B\ Nintendo\ S\ ([0-9]{4})\ [0-9]\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -132,13 +139,16 @@ pub fn sharp_mbc1b() -> &'static impl LabelParser<Mapper> {
 pub fn sharp_mbc1b1() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^DMG\ MBC1B1\ Nintendo\ S\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{1,2}$"#,
+        "mapper::sharp_mbc1b1",
+        r#"^DMG\ MBC1B1\ Nintendo\ S\ ([0-9]{4})\ [0-9]\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B1),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -153,13 +163,16 @@ pub fn sharp_mbc1b1() -> &'static impl LabelParser<Mapper> {
 pub fn sharp_mbc2a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^DMG\ MBC2A\ Nintendo\ S\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{1,2}$"#,
+        "mapper::sharp_mbc2a",
+        r#"^DMG\ MBC2A\ Nintendo\ S\ ([0-9]{4})\ [0-9]\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc2(Mbc2Version::A),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -174,13 +187,16 @@ pub fn sharp_mbc2a() -> &'static impl LabelParser<Mapper> {
 pub fn sharp_mbc3() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC3\ LR385364\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mapper::sharp_mbc3",
+        r#"^MBC3\ LR385364\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::Original),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -195,13 +211,16 @@ pub fn sharp_mbc3() -> &'static impl LabelParser<Mapper> {
 pub fn sharp_mbc3a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC3\ A\ LR38536B\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mapper::sharp_mbc3a",
+        r#"^MBC3\ A\ LR38536B\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::A),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -216,13 +235,16 @@ pub fn sharp_mbc3a() -> &'static impl LabelParser<Mapper> {
 pub fn sharp_mbc5() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC5\ LZ9GB31\ ([[:alnum:]]{2})([0-9]{2})\ [A-Z]$"#,
+        "mapper::sharp_mbc5",
+        r#"^MBC5\ LZ9GB31\ ([[:alnum:]]{2}[0-9]{2})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc5,
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -237,13 +259,16 @@ pub fn sharp_mbc5() -> &'static impl LabelParser<Mapper> {
 pub fn nec_mbc1b() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^Nintendo\ DMG\ MBC1B\ N\ ([0-9]{2})([0-9]{2})BA[0-9]{3}$"#,
+        "mapper::nec_mbc1b",
+        r#"^Nintendo\ DMG\ MBC1B\ N\ ([0-9]{4})BA[0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B),
                 manufacturer: Some(Manufacturer::Nec),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -258,13 +283,16 @@ pub fn nec_mbc1b() -> &'static impl LabelParser<Mapper> {
 pub fn nec_mbc2a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^Nintendo\ DMG\ MBC2A\ N\ ([0-9]{2})([0-9]{2})CA[0-9]{3}$"#,
+        "mapper::nec_mbc2a",
+        r#"^Nintendo\ DMG\ MBC2A\ N\ ([0-9]{4})CA[0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc2(Mbc2Version::A),
                 manufacturer: Some(Manufacturer::Nec),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -279,13 +307,16 @@ pub fn nec_mbc2a() -> &'static impl LabelParser<Mapper> {
 pub fn nec_like_mbc6() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^Nintendo\ MBC6\ ([0-9]{2})([0-9]{2})XP0[0-9]{2}$"#,
+        "mapper::nec_like_mbc6",
+        r#"^Nintendo\ MBC6\ ([0-9]{4})XP0[0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc6,
                 manufacturer: None,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -300,13 +331,16 @@ pub fn nec_like_mbc6() -> &'static impl LabelParser<Mapper> {
 pub fn panasonic_mbc1b() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
+        "mapper::panasonic_mbc1b",
         r#"^DMG\ MBC1-B\ Nintendo\ P\ ([0-9])'[[:alnum:]][0-9]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B),
                 manufacturer: Some(Manufacturer::Panasonic),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         },
     )
@@ -321,13 +355,16 @@ pub fn panasonic_mbc1b() -> &'static impl LabelParser<Mapper> {
 pub fn panasonic_mbc2a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
+        "mapper::panasonic_mbc2a",
         r#"^DMG\ MBC2-A\ Nintendo\ P\ ([0-9])'[[:alnum:]][0-9]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc2(Mbc2Version::A),
                 manufacturer: Some(Manufacturer::Panasonic),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         },
     )
@@ -342,13 +379,16 @@ pub fn panasonic_mbc2a() -> &'static impl LabelParser<Mapper> {
 pub fn panasonic_mbc3a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC3\ A\ P-2\ ([0-9])([0-9]{2})U[0-9][A-Z]$"#,
+        "mapper::panasonic_mbc3a",
+        r#"^MBC3\ A\ P-2\ ([0-9]{3})U[0-9][A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::A),
                 manufacturer: Some(Manufacturer::Panasonic),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -363,13 +403,16 @@ pub fn panasonic_mbc3a() -> &'static impl LabelParser<Mapper> {
 pub fn panasonic_mbc3b() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC3\ B\ P-2\ ([0-9])([0-9]{2})U[0-9][A-Z]$"#,
+        "mapper::panasonic_mbc3b",
+        r#"^MBC3\ B\ P-2\ ([0-9]{3})U[0-9][A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::B),
                 manufacturer: Some(Manufacturer::Panasonic),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -384,13 +427,16 @@ pub fn panasonic_mbc3b() -> &'static impl LabelParser<Mapper> {
 pub fn panasonic_mbc30() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC30\ P\ ([0-9])([0-9]{2})[[:alnum:]][0-9][A-Z]$"#,
+        "mapper::panasonic_mbc30",
+        r#"^MBC30\ P\ ([0-9]{3})[[:alnum:]][0-9][A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc30,
                 manufacturer: Some(Manufacturer::Panasonic),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -405,13 +451,16 @@ pub fn panasonic_mbc30() -> &'static impl LabelParser<Mapper> {
 pub fn panasonic_mbc5() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC5\ P(-[0-9])?\ ([0-9])([0-9]{2})U[0-9][A-Z]$"#,
+        "mapper::panasonic_mbc5",
+        r#"^MBC5\ P(-[0-9])?\ ([0-9]{3})U[0-9][A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc5,
                 manufacturer: Some(Manufacturer::Panasonic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -426,13 +475,16 @@ pub fn panasonic_mbc5() -> &'static impl LabelParser<Mapper> {
 pub fn rohm_mbc3() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC3\ BU3631K\ ([0-9])([0-9]{2})\ [0-9]{3}$"#,
+        "mapper::rohm_mbc3",
+        r#"^MBC3\ BU3631K\ ([0-9]{3})\ [0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::Original),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -447,13 +499,16 @@ pub fn rohm_mbc3() -> &'static impl LabelParser<Mapper> {
 pub fn rohm_mbc3a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC-3\ A\ BU3632K\ ([0-9])([0-9]{2})\ [[:alnum:]]{3}$"#,
+        "mapper::rohm_mbc3a",
+        r#"^MBC-3\ A\ BU3632K\ ([0-9]{3})\ [[:alnum:]]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::A),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -468,13 +523,16 @@ pub fn rohm_mbc3a() -> &'static impl LabelParser<Mapper> {
 pub fn rohm_mbc3b() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC-3\ B\ BU3634K\ ([0-9])([0-9]{2})\ H[0-9]{2}$"#,
+        "mapper::rohm_mbc3b",
+        r#"^MBC-3\ B\ BU3634K\ ([0-9]{3})\ H[0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc3(Mbc3Version::B),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -489,13 +547,16 @@ pub fn rohm_mbc3b() -> &'static impl LabelParser<Mapper> {
 pub fn rohm_mbc30() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC-30\ BU3633AK\ ([0-9])([0-9]{2})\ [0-9]{3}$"#,
+        "mapper::rohm_mbc30",
+        r#"^MBC-30\ BU3633AK\ ([0-9]{3})\ [0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc30,
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -511,13 +572,16 @@ pub fn rohm_mbc30() -> &'static impl LabelParser<Mapper> {
 pub fn rohm_mbc5() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC-?5\ BU3650K\ ([0-9])([0-9]{2})\ [[:alnum:]][0-9]{2}$"#,
+        "mapper::rohm_mbc5",
+        r#"^MBC-?5\ BU3650K\ ([0-9]{3})\ [[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc5,
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -532,13 +596,16 @@ pub fn rohm_mbc5() -> &'static impl LabelParser<Mapper> {
 pub fn rohm_mbc7() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MBC-7\ BU3667KS\ ([0-9])([0-9]{2})\ [0-9]{3}$"#,
+        "mapper::rohm_mbc7",
+        r#"^MBC-7\ BU3667KS\ ([0-9]{3})\ [0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc7,
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -553,13 +620,16 @@ pub fn rohm_mbc7() -> &'static impl LabelParser<Mapper> {
 pub fn texas_instruments_mbc5() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
+        "mapper::texas_instruments_mbc5",
         r#"^([0-9])[[:alnum:]][A-Z][[:alnum:]]{3}T\ MBC5\ 2417$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc5,
                 manufacturer: Some(Manufacturer::TexasInstruments),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         },
     )
@@ -574,13 +644,16 @@ pub fn texas_instruments_mbc5() -> &'static impl LabelParser<Mapper> {
 pub fn unknown_mbc1b() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^DMG\ MBC1B\ Nintendo\ J([0-9]{2})([0-9]{2})BR$"#,
+        "mapper::unknown_mbc1b",
+        r#"^DMG\ MBC1B\ Nintendo\ J([0-9]{4})BR$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B),
                 manufacturer: Some(Manufacturer::Motorola),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -595,13 +668,16 @@ pub fn unknown_mbc1b() -> &'static impl LabelParser<Mapper> {
 pub fn unknown_mbc1b_2() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^Nintendo\ DMG\ MBC1B\ ([0-9]{2})([0-9]{2})AJ$"#,
+        "mapper::unknown_mbc1b_2",
+        r#"^Nintendo\ DMG\ MBC1B\ ([0-9]{4})AJ$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B),
                 manufacturer: None,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -616,13 +692,16 @@ pub fn unknown_mbc1b_2() -> &'static impl LabelParser<Mapper> {
 pub fn unknown_mbc1b_3() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^Nintendo\ DMG\ MBC1B\ N([0-9]{2})([0-9]{2})B[0-9]{4}$"#,
+        "mapper::unknown_mbc1b_3",
+        r#"^Nintendo\ DMG\ MBC1B\ N([0-9]{4})B[0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mbc1(Mbc1Version::B),
                 manufacturer: None,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -637,13 +716,16 @@ pub fn unknown_mbc1b_3() -> &'static impl LabelParser<Mapper> {
 pub fn huc1() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^HuC-1\ ©\ HUDSON\ Nintendo\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mapper::huc1",
+        r#"^HuC-1\ ©\ HUDSON\ Nintendo\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Huc1(Huc1Version::Original),
                 manufacturer: Some(Manufacturer::Hudson),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -658,13 +740,16 @@ pub fn huc1() -> &'static impl LabelParser<Mapper> {
 pub fn huc1a() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^HuC1A\ ©\ HUDSON\ Nintendo\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mapper::huc1a",
+        r#"^HuC1A\ ©\ HUDSON\ Nintendo\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Huc1(Huc1Version::A),
                 manufacturer: Some(Manufacturer::Hudson),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -679,13 +764,16 @@ pub fn huc1a() -> &'static impl LabelParser<Mapper> {
 pub fn huc3() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^HuC-3\ ©\ HUDSON\ Nintendo\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mapper::huc3",
+        r#"^HuC-3\ ©\ HUDSON\ Nintendo\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Huc3,
                 manufacturer: Some(Manufacturer::Hudson),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -700,13 +788,16 @@ pub fn huc3() -> &'static impl LabelParser<Mapper> {
 pub fn mmm01() -> &'static impl LabelParser<Mapper> {
     single_parser!(
         Mapper,
-        r#"^MMM01\ ([0-9])([0-9]{2})\ [0-9]{3}$"#,
+        "mapper::mmm01",
+        r#"^MMM01\ ([0-9]{3})\ [0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
             Ok(Mapper {
                 mbc_type: MapperType::Mmm01,
                 manufacturer: None,
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, LabelParser, Manufacturer, Year};
+use super::{capture_country, Country, DateCodeConvention, LabelParser, Manufacturer, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -15,27 +15,32 @@ pub struct MaskRom {
     pub chip_type: Option<String>,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// Sharp ROM chip (1990+)
 ///
 /// ```
-/// use gbhwdb_backend::parser::{self, LabelParser};
-/// assert!(parser::mask_rom::sharp().parse("DMG-WJA-0 S LH534M05 JAPAN E1 9606 D").is_ok());
+/// use gbhwdb_backend::parser::{self, Country, LabelParser};
+/// let rom = parser::mask_rom::sharp().parse("DMG-WJA-0 S LH534M05 JAPAN E1 9606 D").unwrap();
+/// assert_eq!(rom.country, Some(Country::Japan));
 /// assert!(parser::mask_rom::sharp().parse("DMG-AP2J-0 S LH534MVD JAPAN E1 9639 D").is_ok());
 /// assert!(parser::mask_rom::sharp().parse("DMG-HFAJ-0 S LHMN4MTI JAPAN E 9838 E").is_ok());
 /// ```
 pub fn sharp() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ S\ (LH[[:alnum:]]{4})[[:alnum:]]{2} \ JAPAN\ [A-Z][0-9]?\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mask_rom::sharp",
+        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ S\ (LH[[:alnum:]]{4})[[:alnum:]]{2} \ (?P<country>JAPAN)\ [A-Z][0-9]?\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[5])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: Some(map_sharp_mask_rom(&c[3]).unwrap_or(&c[3]).to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -50,14 +55,17 @@ pub fn sharp() -> &'static impl LabelParser<MaskRom> {
 pub fn sharp2() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^(DMG-[[:alnum:]]{3}-[0-9])\ SHARP\ JAPAN\ [A-Z][0-9]?\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "mask_rom::sharp2",
+        r#"^(DMG-[[:alnum:]]{3}-[0-9])\ SHARP\ (?P<country>JAPAN)\ [A-Z][0-9]?\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: None,
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -72,14 +80,17 @@ pub fn sharp2() -> &'static impl LabelParser<MaskRom> {
 pub fn sharp3() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^(DMG-[[:alnum:]]{3}-[0-9])\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [A-Z]\ [A-Z]$"#,
+        "mask_rom::sharp3",
+        r#"^(DMG-[[:alnum:]]{3}-[0-9])\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [A-Z]\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: None,
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -97,14 +108,17 @@ pub fn sharp3() -> &'static impl LabelParser<MaskRom> {
 pub fn macronix() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^[A-Z]([0-9]{2})([0-9]{2})[0-9]{2}-M\ (MX23C[0-9]{4}-[0-9]{2}[A-Z]?[0-9]?)\ ([0-9]\ )? ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ ([0-9][0-9]\ )? [A-Z][0-9]?\ [[:alnum:]]{8,10}$"#,
+        "mask_rom::macronix",
+        r#"^[A-Z]([0-9]{4})[0-9]{2}-M\ (MX23C[0-9]{4}-[0-9]{2}[A-Z]?[0-9]?)\ ([0-9]\ )? ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ ([0-9][0-9]\ )? [A-Z][0-9]?\ [[:alnum:]]{8,10}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(MaskRom {
-                rom_code: c[5].to_owned(),
+                rom_code: c[4].to_owned(),
                 manufacturer: Some(Manufacturer::Macronix),
-                chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                chip_type: Some(c[2].to_owned()),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -119,14 +133,17 @@ pub fn macronix() -> &'static impl LabelParser<MaskRom> {
 pub fn macronix2() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^[A-Z]([0-9]{2})([0-9]{2})-M\ (MX23C[0-9]{4}-[0-9]{2}[A-Z]?[0-9]?)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]?\ [[:alnum:]]{6}$"#,
+        "mask_rom::macronix2",
+        r#"^[A-Z]([0-9]{4})-M\ (MX23C[0-9]{4}-[0-9]{2}[A-Z]?[0-9]?)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]?\ [[:alnum:]]{6}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(MaskRom {
-                rom_code: c[4].to_owned(),
+                rom_code: c[3].to_owned(),
                 manufacturer: Some(Manufacturer::Macronix),
-                chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                chip_type: Some(c[2].to_owned()),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -141,14 +158,17 @@ pub fn macronix2() -> &'static impl LabelParser<MaskRom> {
 pub fn oki_msm538011e() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (M538011E)-[[:alnum:]]{2}\ ([0-9])([0-9]{2})[0-9]{3}[[:alnum:]]$"#,
+        "mask_rom::oki_msm538011e",
+        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (M538011E)-[[:alnum:]]{2}\ ([0-9]{3})[0-9]{3}[[:alnum:]]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[4])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Oki),
                 chip_type: Some(format!("MS{}", &c[3])),
-                year: Some(year1(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -163,14 +183,17 @@ pub fn oki_msm538011e() -> &'static impl LabelParser<MaskRom> {
 pub fn oki_mr531614g() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (R531614G)-[[:alnum:]]{2}\ ([0-9])([0-9]{2})[0-9]{3}[[:alnum:]]$"#,
+        "mask_rom::oki_mr531614g",
+        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (R531614G)-[[:alnum:]]{2}\ ([0-9]{3})[0-9]{3}[[:alnum:]]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[4])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Oki),
                 chip_type: Some(format!("M{}", &c[3])),
-                year: Some(year1(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -185,14 +208,17 @@ pub fn oki_mr531614g() -> &'static impl LabelParser<MaskRom> {
 pub fn nec() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^NEC\ JAPAN\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (UPD23C[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "mask_rom::nec",
+        r#"^NEC\ (?P<country>JAPAN)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (UPD23C[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[5])?;
             Ok(MaskRom {
-                rom_code: c[1].to_owned(),
+                rom_code: c[2].to_owned(),
                 manufacturer: Some(Manufacturer::Nec),
-                chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                chip_type: Some(c[4].to_owned()),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -207,14 +233,17 @@ pub fn nec() -> &'static impl LabelParser<MaskRom> {
 pub fn nec_like() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (N-[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "mask_rom::nec_like",
+        r#"^((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (N-[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: None,
                 chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -229,14 +258,17 @@ pub fn nec_like() -> &'static impl LabelParser<MaskRom> {
 pub fn at_t() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^Ⓜ\ AT&T\ JAPAN\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (23C[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "mask_rom::at_t",
+        r#"^Ⓜ\ AT&T\ (?P<country>JAPAN)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (23C[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[5])?;
             Ok(MaskRom {
-                rom_code: c[1].to_owned(),
+                rom_code: c[2].to_owned(),
                 manufacturer: Some(Manufacturer::AtT),
-                chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                chip_type: Some(c[4].to_owned()),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -251,14 +283,17 @@ pub fn at_t() -> &'static impl LabelParser<MaskRom> {
 pub fn smsc() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^STANDARD\ MICRO\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (23C[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "mask_rom::smsc",
+        r#"^STANDARD\ MICRO\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ (23C[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(MaskRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Smsc),
                 chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -275,14 +310,17 @@ pub fn smsc() -> &'static impl LabelParser<MaskRom> {
 pub fn glop_top() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^(LR0G150)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ ([0-9]{2})([0-9]{2})[0-9]$"#,
+        "mask_rom::glop_top",
+        r#"^(LR0G150)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ ([0-9]{4})[0-9]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(MaskRom {
                 rom_code: c[2].to_owned(),
                 manufacturer: None,
                 chip_type: Some(c[1].to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -297,14 +335,17 @@ pub fn glop_top() -> &'static impl LabelParser<MaskRom> {
 pub fn toshiba() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^TOSHIBA\ ([0-9]{2})([0-9]{2})EAI\ (TC53[0-9]{4}[A-Z]{2})\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ [A-Z][0-9]{3}\ JAPAN$"#,
+        "mask_rom::toshiba",
+        r#"^TOSHIBA\ ([0-9]{4})EAI\ (TC53[0-9]{4}[A-Z]{2})\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ [A-Z][0-9]{3}\ (?P<country>JAPAN)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(MaskRom {
-                rom_code: c[4].to_owned(),
+                rom_code: c[3].to_owned(),
                 manufacturer: Some(Manufacturer::Toshiba),
-                chip_type: (Some(c[3].to_owned())),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                chip_type: (Some(c[2].to_owned())),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -319,6 +360,7 @@ pub fn toshiba() -> &'static impl LabelParser<MaskRom> {
 pub fn samsung() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
+        "mask_rom::samsung",
         r#"^SEC\ (KM23C[0-9]{4,5}[A-Z]{1,2})\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ [[:alnum:]]{10}$"#,
         move |c| {
             Ok(MaskRom {
@@ -327,6 +369,7 @@ pub fn samsung() -> &'static impl LabelParser<MaskRom> {
                 chip_type: (Some(c[1].to_owned())),
                 year: None,
                 week: None,
+                country: None,
             })
         },
     )
@@ -341,6 +384,7 @@ pub fn samsung() -> &'static impl LabelParser<MaskRom> {
 pub fn samsung2() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
+        "mask_rom::samsung2",
         r#"^SEC\ (KM23C[0-9]{4,5}[A-Z]{1,2})\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ KF[[:alnum:]]{4}[A-Z]$"#,
         move |c| {
             Ok(MaskRom {
@@ -349,6 +393,7 @@ pub fn samsung2() -> &'static impl LabelParser<MaskRom> {
                 chip_type: (Some(c[1].to_owned())),
                 year: None,
                 week: None,
+                country: None,
             })
         },
     )
@@ -364,14 +409,17 @@ pub fn samsung2() -> &'static impl LabelParser<MaskRom> {
 pub fn fujitsu() -> &'static impl LabelParser<MaskRom> {
     single_parser!(
         MaskRom,
-        r#"^JAPAN\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ [0-9][A-Z][[:alnum:]]\ [A-Z]{2}\ ([0-9]{2})([0-9]{2})\ [A-Z][0-9]{2}$"#,
+        "mask_rom::fujitsu",
+        r#"^(?P<country>JAPAN)\ ((DMG|CGB)-[[:alnum:]]{3,4}-[0-9])\ [A-Z][0-9]\ [0-9][A-Z][[:alnum:]]\ [A-Z]{2}\ ([0-9]{4})\ [A-Z][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(MaskRom {
-                rom_code: c[1].to_owned(),
+                rom_code: c[2].to_owned(),
                 manufacturer: Some(Manufacturer::Fujitsu),
                 chip_type: None,
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
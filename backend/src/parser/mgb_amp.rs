@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser, Manufacturer};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::{multi_parser, single_parser};
 
 pub type MgbAmp = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type MgbAmp = ChipYearWeek;
 pub fn sharp_ir3r53n() -> &'static impl LabelParser<MgbAmp> {
     single_parser!(
         MgbAmp,
-        r#"^AMP\ MGB\ IR3R53N\ ([0-9]{2})([0-9]{2})\ [a-zA-Z]$"#,
+        "mgb_amp::sharp_ir3r53n",
+        r#"^AMP\ MGB\ IR3R53N\ ([0-9]{4})\ [a-zA-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(MgbAmp {
                 kind: "IR3R53N".to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -33,13 +36,16 @@ pub fn sharp_ir3r53n() -> &'static impl LabelParser<MgbAmp> {
 pub fn sharp_ir3r56n() -> &'static impl LabelParser<MgbAmp> {
     single_parser!(
         MgbAmp,
-        r#"^AMP\ MGB\ IR3R56N\ ([0-9]{2})([0-9]{2})\ [a-zA-Z]$"#,
+        "mgb_amp::sharp_ir3r56n",
+        r#"^AMP\ MGB\ IR3R56N\ ([0-9]{4})\ [a-zA-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(MgbAmp {
                 kind: "IR3R56N".to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
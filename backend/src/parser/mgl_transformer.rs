@@ -2,13 +2,14 @@
 //
 // SPDX-License-Identifier: MIT
 
-use super::{LabelParser, Manufacturer};
+use super::{Country, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Transformer {
     pub kind: String,
     pub manufacturer: Option<Manufacturer>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -17,12 +18,18 @@ pub struct Transformer {
 /// assert!(parser::mgl_transformer::mitsumi_unknown().parse("84Z7").is_ok());
 /// ```
 pub fn mitsumi_unknown() -> &'static impl LabelParser<Transformer> {
-    single_parser!(Transformer, r#"^(82Y7|84Z7)$"#, move |c| {
-        Ok(Transformer {
-            kind: c[1].to_owned(),
-            manufacturer: Some(Manufacturer::Mitsumi),
-        })
-    })
+    single_parser!(
+        Transformer,
+        "mgl_transformer::mitsumi_unknown",
+        r#"^(82Y7|84Z7)$"#,
+        move |c| {
+            Ok(Transformer {
+                kind: c[1].to_owned(),
+                manufacturer: Some(Manufacturer::Mitsumi),
+                country: None,
+            })
+        }
+    )
 }
 
 pub fn mgl_transformer() -> &'static impl LabelParser<Transformer> {
@@ -0,0 +1,242 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::{fmt, iter};
+
+use super::LabelParser;
+
+/// Plain-text spellings of symbols printed on chips
+const SYMBOLS: [(&str, &str); 6] = [
+    ("(M)", "Ⓜ"),
+    ("(m)", "Ⓜ"),
+    ("ⓜ", "Ⓜ"),
+    ("(C)", "©"),
+    ("(c)", "©"),
+    ("Ⓒ", "©"),
+];
+
+/// Characters that are easy to mistype or misread as each other
+const CONFUSIONS: [(char, char); 5] = [('O', '0'), ('0', 'O'), ('I', '1'), ('l', '1'), ('1', 'I')];
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SubstitutionKind {
+    /// Plain-text or lookalike spelling of a symbol or punctuation character
+    Symbol,
+    /// Leading, trailing, repeated or non-standard whitespace
+    Whitespace,
+    /// Character that was most likely mistyped or misread as another one
+    Confusion,
+}
+
+/// Single change made while normalizing a label
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Substitution {
+    pub kind: SubstitutionKind,
+    pub from: String,
+    pub to: String,
+}
+
+impl fmt::Display for Substitution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} -> {:?}", self.from, self.to)
+    }
+}
+
+/// Label in canonical form, along with the substitutions that produced it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NormalizedLabel {
+    pub text: String,
+    pub substitutions: Vec<Substitution>,
+}
+
+impl NormalizedLabel {
+    pub fn is_changed(&self) -> bool {
+        !self.substitutions.is_empty()
+    }
+}
+
+/// Canonicalizes symbols, punctuation and whitespace in a label.
+///
+/// These substitutions never change the meaning of a label, so they are always safe to apply.
+/// Character confusions such as "O" vs "0" are only resolved by [`parse_normalized`], because
+/// they can only be detected by checking what a parser accepts.
+///
+/// ```
+/// use gbhwdb_backend::parser::normalize::normalize;
+///
+/// let label = normalize(" CPU CGB B (M) (C) 1998  Nintendo JAPAN 9837 I");
+/// assert_eq!(label.text, "CPU CGB B Ⓜ © 1998 Nintendo JAPAN 9837 I");
+/// assert_eq!(label.substitutions.len(), 4);
+///
+/// assert!(!normalize("DMG-CPU B 9404 D").is_changed());
+/// ```
+pub fn normalize(label: &str) -> NormalizedLabel {
+    let mut substitutions = Vec::new();
+    let mut text = String::with_capacity(label.len());
+    for c in label.chars() {
+        let canonical = match c {
+            // fullwidth ASCII variants, e.g. from Japanese input methods
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xff01 + 0x21).unwrap_or(c),
+            '\u{2018}' | '\u{2019}' | '\u{00b4}' | '`' => '\'',
+            '\u{2010}'..='\u{2015}' | '\u{2212}' => '-',
+            _ => c,
+        };
+        if canonical != c {
+            substitutions.push(Substitution {
+                kind: SubstitutionKind::Symbol,
+                from: c.to_string(),
+                to: canonical.to_string(),
+            });
+        }
+        text.push(canonical);
+    }
+    for (from, to) in SYMBOLS {
+        if text.contains(from) {
+            text = text.replace(from, to);
+            substitutions.push(Substitution {
+                kind: SubstitutionKind::Symbol,
+                from: from.to_owned(),
+                to: to.to_owned(),
+            });
+        }
+    }
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed != text {
+        substitutions.extend(whitespace_substitutions(&text));
+        text = collapsed;
+    }
+    NormalizedLabel {
+        text,
+        substitutions,
+    }
+}
+
+fn whitespace_substitutions(text: &str) -> Vec<Substitution> {
+    let mut result = Vec::new();
+    let mut run_start = None;
+    // the trailing NUL flushes a run at the end of the text
+    for (idx, c) in text.char_indices().chain(iter::once((text.len(), '\0'))) {
+        if c.is_whitespace() {
+            run_start.get_or_insert(idx);
+        } else if let Some(start) = run_start.take() {
+            let run = &text[start..idx];
+            let to = if start == 0 || idx == text.len() {
+                ""
+            } else if run != " " {
+                " "
+            } else {
+                continue;
+            };
+            result.push(Substitution {
+                kind: SubstitutionKind::Whitespace,
+                from: run.to_owned(),
+                to: to.to_owned(),
+            });
+        }
+    }
+    result
+}
+
+/// Parses a label, falling back to normalized forms if the raw label is not accepted.
+///
+/// The raw label is tried first, followed by the canonical form from [`normalize`]. If neither
+/// parses, single character confusions are tried one position at a time, and then applied to
+/// every occurrence of the character.
+///
+/// ```
+/// use gbhwdb_backend::parser::{self, normalize::{parse_normalized, SubstitutionKind}};
+///
+/// let (_, label) = parse_normalized(parser::mapper::mapper(), "MBC5 LZ9GB31 AL23 A").unwrap();
+/// assert!(!label.is_changed());
+///
+/// let (part, label) = parse_normalized(parser::gen1_soc::gen1_soc(), "DMG-CPU B 94O4 D").unwrap();
+/// assert_eq!(label.text, "DMG-CPU B 9404 D");
+/// assert_eq!(label.substitutions[0].kind, SubstitutionKind::Confusion);
+/// assert_eq!(part.year, Some(parser::Year::Full(1994)));
+/// ```
+pub fn parse_normalized<T, F: LabelParser<T> + ?Sized>(
+    f: &F,
+    label: &str,
+) -> Result<(T, NormalizedLabel), String> {
+    normalize_with(label, |label| f.parse(label))
+}
+
+/// Like [`parse_normalized`], but with an arbitrary parse function
+pub fn normalize_with<T>(
+    label: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Result<(T, NormalizedLabel), String> {
+    let err = match parse(label) {
+        Ok(part) => {
+            return Ok((
+                part,
+                NormalizedLabel {
+                    text: label.to_owned(),
+                    substitutions: Vec::new(),
+                },
+            ))
+        }
+        Err(err) => err,
+    };
+    let normalized = normalize(label);
+    if normalized.is_changed() {
+        if let Ok(part) = parse(&normalized.text) {
+            return Ok((part, normalized));
+        }
+    }
+    for (from, to) in CONFUSIONS {
+        for (idx, _) in normalized.text.match_indices(from) {
+            let mut text = normalized.text.clone();
+            text.replace_range(idx..idx + from.len_utf8(), to.encode_utf8(&mut [0; 4]));
+            if let Ok(part) = parse(&text) {
+                return Ok((part, with_confusion(&normalized, text, from, to)));
+            }
+        }
+    }
+    for (from, to) in CONFUSIONS {
+        if normalized.text.matches(from).count() > 1 {
+            let text = normalized.text.replace(from, to.encode_utf8(&mut [0; 4]));
+            if let Ok(part) = parse(&text) {
+                return Ok((part, with_confusion(&normalized, text, from, to)));
+            }
+        }
+    }
+    Err(err)
+}
+
+fn with_confusion(
+    normalized: &NormalizedLabel,
+    text: String,
+    from: char,
+    to: char,
+) -> NormalizedLabel {
+    let mut substitutions = normalized.substitutions.clone();
+    substitutions.push(Substitution {
+        kind: SubstitutionKind::Confusion,
+        from: from.to_string(),
+        to: to.to_string(),
+    });
+    NormalizedLabel {
+        text,
+        substitutions,
+    }
+}
+
+#[test]
+fn test_normalize_whitespace() {
+    let label = normalize("  MBC5\tLZ9GB31   AL23 A\n");
+    assert_eq!(label.text, "MBC5 LZ9GB31 AL23 A");
+    assert!(label
+        .substitutions
+        .iter()
+        .all(|s| s.kind == SubstitutionKind::Whitespace));
+    assert_eq!(label.substitutions.len(), 4);
+}
+
+#[test]
+fn test_normalize_fullwidth_and_dashes() {
+    let label = normalize("DMG\u{2010}CPU Ｂ 9404 D");
+    assert_eq!(label.text, "DMG-CPU B 9404 D");
+    assert_eq!(label.substitutions.len(), 2);
+}
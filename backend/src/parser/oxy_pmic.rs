@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser, Manufacturer};
+use super::{capture_country, ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer};
 use crate::macros::single_parser;
 
 pub type OxyPmic = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type OxyPmic = ChipYearWeek;
 pub fn mitsumi_pm_c() -> &'static impl LabelParser<OxyPmic> {
     single_parser!(
         OxyPmic,
-        r#"^MITSUMI\ JAPAN\ ([0-9])([0-9]{2})\ ?[A-Z]\ PM\ C$"#,
+        "oxy_pmic::mitsumi_pm_c",
+        r#"^MITSUMI\ (?P<country>JAPAN)\ ([0-9]{3})\ ?[A-Z]\ PM\ C$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(OxyPmic {
                 kind: "PM C".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
-                year: Some(year1(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::single_parser;
 
 pub type OxyU4 = ChipYearWeek;
@@ -12,12 +12,14 @@ pub type OxyU4 = ChipYearWeek;
 /// assert!(parser::oxy_u4::unknown().parse("AKV 522").is_ok());
 /// ```
 pub fn unknown() -> &'static impl LabelParser<OxyU4> {
-    single_parser!(OxyU4, r#"^AKV\ ([0-9])([0-9]{2})$"#, move |c| {
+    single_parser!(OxyU4, "oxy_u4::unknown", r#"^AKV\ ([0-9]{3})$"#, move |c| {
+        let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
         Ok(OxyU4 {
             kind: "AKV".to_owned(),
             manufacturer: None,
-            year: Some(year1(&c[1])?),
-            week: Some(week2(&c[2])?),
+            year: date.year,
+            week: date.week,
+            country: None,
         })
     })
 }
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::macros::single_parser;
 
 pub type OxyU5 = ChipYearWeek;
@@ -14,13 +14,16 @@ pub type OxyU5 = ChipYearWeek;
 pub fn unknown() -> &'static impl LabelParser<OxyU5> {
     single_parser!(
         OxyU5,
-        r#"^CP6465\ B\ 02\ KOR([0-9]{2})([0-9]{2})\ [0-9]{6}$"#,
+        "oxy_u5::unknown",
+        r#"^CP6465\ B\ 02\ KOR([0-9]{4})\ [0-9]{6}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(OxyU5 {
                 kind: "CP6465".to_owned(),
                 manufacturer: None,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, ChipYearWeek, LabelParser, Manufacturer};
+use super::{capture_country, ChipYearWeek, DateCodeConvention, LabelParser, Manufacturer, Year};
 use crate::macros::{multi_parser, single_parser};
 
 pub type Ram = ChipYearWeek;
@@ -18,13 +18,16 @@ pub type Ram = ChipYearWeek;
 pub fn lsi_logic_lh52xx() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5264N4T|LH52A64N-TL|LH5264TN-TL)\ LSI\ LOGIC\ JAPAN\ [A-Z]([0-9])\ ?([0-9]{2})\ [[:alnum:]]{2}\ [A-Z]$"#,
+        "ram::lsi_logic_lh52xx",
+        r#"^(LH5264N4T|LH52A64N-TL|LH5264TN-TL)\ LSI\ LOGIC\ (?P<country>JAPAN)\ [A-Z]([0-9]\ ?[0-9]{2})\ [[:alnum:]]{2}\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::LsiLogic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -39,13 +42,16 @@ pub fn lsi_logic_lh52xx() -> &'static impl LabelParser<Ram> {
 pub fn lsi_logic_lh52b256() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52B256[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ LSI\ LOGIC\ JAPAN\ [A-Z]([0-9])([0-9]{2})\ [[:alnum:]]{2}\ [A-Z]$"#,
+        "ram::lsi_logic_lh52b256",
+        r#"^(LH52B256[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ LSI\ LOGIC\ (?P<country>JAPAN)\ [A-Z]([0-9]{3})\ [[:alnum:]]{2}\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::LsiLogic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -60,13 +66,16 @@ pub fn lsi_logic_lh52b256() -> &'static impl LabelParser<Ram> {
 pub fn lsi_logic_lh5168() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5168[A-Z]{0,3}-[0-9]{2}[A-Z]{2,3})\ LSI\ LOGIC\ JAPAN\ [A-Z]([0-9])([0-9]{2})\ [0-9]\ [[:alnum:]]{2}$"#,
+        "ram::lsi_logic_lh5168",
+        r#"^(LH5168[A-Z]{0,3}-[0-9]{2}[A-Z]{2,3})\ LSI\ LOGIC\ (?P<country>JAPAN)\ [A-Z]([0-9]{3})\ [0-9]\ [[:alnum:]]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::LsiLogic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -81,13 +90,16 @@ pub fn lsi_logic_lh5168() -> &'static impl LabelParser<Ram> {
 pub fn mosel_vitelic_lh52b256() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52B256[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ MOSEL-VITELIC\ JAPAN\ [A-Z]([0-9])([0-9]{2})\ [[:alnum:]]{2}\ [A-Z]{1,2}$"#,
+        "ram::mosel_vitelic_lh52b256",
+        r#"^(LH52B256[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ MOSEL-VITELIC\ (?P<country>JAPAN)\ [A-Z]([0-9]{3})\ [[:alnum:]]{2}\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::MoselVitelic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -103,13 +115,16 @@ pub fn mosel_vitelic_lh52b256() -> &'static impl LabelParser<Ram> {
 pub fn mosel_vitelic_lh5168() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5168[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ MOSEL-VITELIC\ JAPAN\ [A-Z]([0-9])\ ?([0-9]{2})\ [[:alnum:]]{2}\ [[:alnum:]]{2}$"#,
+        "ram::mosel_vitelic_lh5168",
+        r#"^(LH5168[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ MOSEL-VITELIC\ (?P<country>JAPAN)\ [A-Z]([0-9]\ ?[0-9]{2})\ [[:alnum:]]{2}\ [[:alnum:]]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::MoselVitelic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -124,13 +139,16 @@ pub fn mosel_vitelic_lh5168() -> &'static impl LabelParser<Ram> {
 pub fn mosel_vitelic_lh5268a() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5268A[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ MOSEL-VITELIC\ JAPAN\ [A-Z]([0-9])([0-9]{2})\ [[:alnum:]]{2}\ [[:alnum:]]{2}$"#,
+        "ram::mosel_vitelic_lh5268a",
+        r#"^(LH5268A[A-Z]{0,2}-[0-9]{2}[A-Z]{2,3})\ MOSEL-VITELIC\ (?P<country>JAPAN)\ [A-Z]([0-9]{3})\ [[:alnum:]]{2}\ [[:alnum:]]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::MoselVitelic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -145,13 +163,16 @@ pub fn mosel_vitelic_lh5268a() -> &'static impl LabelParser<Ram> {
 pub fn sanyo_lc35256d() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^SANYO\ (LC35256D[MT]-[0-9]{2}W)\ JAPAN\ ([0-9])[[:alnum:]]{4}$"#,
+        "ram::sanyo_lc35256d",
+        r#"^SANYO\ (LC35256D[MT]-[0-9]{2}W)\ (?P<country>JAPAN)\ ([0-9])[[:alnum:]]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sanyo),
-                year: Some(year1(&c[2])?),
+                year: date.year,
                 week: None,
+                country: capture_country(&c),
             })
         },
     )
@@ -166,13 +187,16 @@ pub fn sanyo_lc35256d() -> &'static impl LabelParser<Ram> {
 pub fn sanyo_lc35256f() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^SANYO\ (LC35256F[MT]-[0-9]{2}U)\ JAPAN\ ([0-9])[[:alnum:]]{4}$"#,
+        "ram::sanyo_lc35256f",
+        r#"^SANYO\ (LC35256F[MT]-[0-9]{2}U)\ (?P<country>JAPAN)\ ([0-9])[[:alnum:]]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sanyo),
-                year: Some(year1(&c[2])?),
+                year: date.year,
                 week: None,
+                country: capture_country(&c),
             })
         },
     )
@@ -187,13 +211,16 @@ pub fn sanyo_lc35256f() -> &'static impl LabelParser<Ram> {
 pub fn sanyo_lc3564b() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^SANYO\ (LC3564B[A-Z]?-[0-9]{2})\ JAPAN\ ([0-9])[[:alnum:]]{4}$"#,
+        "ram::sanyo_lc3564b",
+        r#"^SANYO\ (LC3564B[A-Z]?-[0-9]{2})\ (?P<country>JAPAN)\ ([0-9])[[:alnum:]]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sanyo),
-                year: Some(year1(&c[2])?),
+                year: date.year,
                 week: None,
+                country: capture_country(&c),
             })
         },
     )
@@ -208,13 +235,16 @@ pub fn sanyo_lc3564b() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh52256c() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52256C[A-Z]{1,2}-[0-9]{2}[A-Z]{0,2})\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{2}$"#,
+        "ram::sharp_lh52256c",
+        r#"^(LH52256C[A-Z]{1,2}-[0-9]{2}[A-Z]{0,2})\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -229,13 +259,16 @@ pub fn sharp_lh52256c() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh52256cvt() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52256CVT)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{2}$"#,
+        "ram::sharp_lh52256cvt",
+        r#"^(LH52256CVT)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -250,13 +283,16 @@ pub fn sharp_lh52256cvt() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh52cv256() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52CV256[A-Z]{1,2}-[0-9]{2}[A-Z]{0,2})\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{2}$"#,
+        "ram::sharp_lh52cv256",
+        r#"^(LH52CV256[A-Z]{1,2}-[0-9]{2}[A-Z]{0,2})\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -272,13 +308,20 @@ pub fn sharp_lh52cv256() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh51d256t() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH51D256T-Z[0-9])\ SHARP(\ JAPAN)?\ A?Y([0-9])\ ?([0-9]{2})\ [0-9]\ [A-Z]{1,2}$"#,
+        "ram::sharp_lh51d256t",
+        r#"^(LH51D256T-Z[0-9])\ SHARP(\ (?P<country>JAPAN))?\ A?Y([0-9]\ ?[0-9]{2})\ [0-9]\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[4])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                // "Y0" is the year 2000
+                year: match date.year {
+                    Some(Year::Partial(year)) => Some(Year::Full(2000 + u16::from(year))),
+                    year => year,
+                },
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -293,13 +336,16 @@ pub fn sharp_lh51d256t() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5160() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5160[A-Z]{0,3}-[0-9]{2}[A-Z]?)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})(\ [0-9])?\ [A-Z]{2}$"#,
+        "ram::sharp_lh5160",
+        r#"^(LH5160[A-Z]{0,3}-[0-9]{2}[A-Z]?)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})(\ [0-9])?\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -316,13 +362,16 @@ pub fn sharp_lh5160() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5168() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5168[A-Z]{0,3}-[0-9]{2}[A-Z]?)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})(\ [0-9])?\ [A-Z]{2}$"#,
+        "ram::sharp_lh5168",
+        r#"^(LH5168[A-Z]{0,3}-[0-9]{2}[A-Z]?)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})(\ [0-9])?\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -337,13 +386,16 @@ pub fn sharp_lh5168() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5164an() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5164AN-[0-9]{2}[A-Z]?)\ SHARP\ JAPAN\ A?([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{2}$"#,
+        "ram::sharp_lh5164an",
+        r#"^(LH5164AN-[0-9]{2}[A-Z]?)\ SHARP\ (?P<country>JAPAN)\ A?([0-9]{4})\ [0-9]\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -358,13 +410,16 @@ pub fn sharp_lh5164an() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5164an_2() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5164AN-[0-9]{2}[A-Z]?)\ SHARP\ A([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{2}$"#,
+        "ram::sharp_lh5164an_2",
+        r#"^(LH5164AN-[0-9]{2}[A-Z]?)\ SHARP\ A([0-9]{4})\ [0-9]\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -379,13 +434,16 @@ pub fn sharp_lh5164an_2() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5164ln() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5164LN-[0-9]{2})\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]$"#,
+        "ram::sharp_lh5164ln",
+        r#"^(LH5164LN-[0-9]{2})\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -401,13 +459,16 @@ pub fn sharp_lh5164ln() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5264n() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5264N4?)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]$"#,
+        "ram::sharp_lh5264n",
+        r#"^(LH5264N4?)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -422,13 +483,16 @@ pub fn sharp_lh5264n() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5264tn_l() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5264TN-L)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]$"#,
+        "ram::sharp_lh5264tn_l",
+        r#"^(LH5264TN-L)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -443,13 +507,16 @@ pub fn sharp_lh5264tn_l() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh5164n() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5164N-[0-9]{2}[A-Z]?)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]{2}$"#,
+        "ram::sharp_lh5164n",
+        r#"^(LH5164N-[0-9]{2}[A-Z]?)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -464,13 +531,16 @@ pub fn sharp_lh5164n() -> &'static impl LabelParser<Ram> {
 pub fn sharp_lh52a64n_l() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52A64N-L)\ SHARP\ JAPAN\ ([0-9]{2})([0-9]{2})\ [0-9]\ [A-Z]"#,
+        "ram::sharp_lh52a64n_l",
+        r#"^(LH52A64N-L)\ SHARP\ (?P<country>JAPAN)\ ([0-9]{4})\ [0-9]\ [A-Z]"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -486,13 +556,16 @@ pub fn sharp_lh52a64n_l() -> &'static impl LabelParser<Ram> {
 pub fn bsi_bs62lv256sc() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^BSI\ (BS62LV256SC-[0-9]{2})\ [[:alnum:]]{10,11}(.[0-9])?\ [A-Z]([0-9]{2})([0-9]{2})\ TAIWAN$"#,
+        "ram::bsi_bs62lv256sc",
+        r#"^BSI\ (BS62LV256SC-[0-9]{2})\ [[:alnum:]]{10,11}(.[0-9])?\ [A-Z]([0-9]{4})\ (?P<country>TAIWAN)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Bsi),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -507,13 +580,16 @@ pub fn bsi_bs62lv256sc() -> &'static impl LabelParser<Ram> {
 pub fn winbond_w2465() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^Winbond\ (W2465[A-Z]?-[0-9]{2}[A-Z]{1,2})\ ([0-9])([0-9]{2})[A-Z]{2}[0-9]{8}-II1RA$"#,
+        "ram::winbond_w2465",
+        r#"^Winbond\ (W2465[A-Z]?-[0-9]{2}[A-Z]{1,2})\ ([0-9]{3})[A-Z]{2}[0-9]{8}-II1RA$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Winbond),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -528,13 +604,16 @@ pub fn winbond_w2465() -> &'static impl LabelParser<Ram> {
 pub fn winbond_w24257() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^Winbond\ (W24257[A-Z]?(-[0-9]{2}[A-Z]{1,2})?)\ ([0-9])([0-9]{2})[A-Z]{2}[0-9]{9}[A-Z]{2}$"#,
+        "ram::winbond_w24257",
+        r#"^Winbond\ (W24257[A-Z]?(-[0-9]{2}[A-Z]{1,2})?)\ ([0-9]{3})[A-Z]{2}[0-9]{9}[A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Winbond),
-                year: Some(year1(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -549,13 +628,16 @@ pub fn winbond_w24257() -> &'static impl LabelParser<Ram> {
 pub fn winbond_w24258() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^Winbond\ (W24258[A-Z]?(-[0-9]{2}[A-Z]{1,2})?)\ ([0-9])([0-9]{2})[A-Z]{2}[0-9]{9}[A-Z]{2}$"#,
+        "ram::winbond_w24258",
+        r#"^Winbond\ (W24258[A-Z]?(-[0-9]{2}[A-Z]{1,2})?)\ ([0-9]{3})[A-Z]{2}[0-9]{9}[A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Winbond),
-                year: Some(year1(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -570,13 +652,16 @@ pub fn winbond_w24258() -> &'static impl LabelParser<Ram> {
 pub fn rohm_xlj6265() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(XLJ6265[AB]?F?-N?[0-9]{2}[A-Z]{2})\ ([0-9])([0-9]{2})\ [0-9]{3}[A-Z]{0,2}$"#,
+        "ram::rohm_xlj6265",
+        r#"^(XLJ6265[AB]?F?-N?[0-9]{2}[A-Z]{2})\ ([0-9]{3})\ [0-9]{3}[A-Z]{0,2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -591,13 +676,16 @@ pub fn rohm_xlj6265() -> &'static impl LabelParser<Ram> {
 pub fn rohm_br6265() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(BR6265[AB]?F?-N?[0-9]{2}[A-Z]{2})\ ([0-9])([0-9]{2})\ [0-9]{3}[A-Z]{1,2}$"#,
+        "ram::rohm_br6265",
+        r#"^(BR6265[AB]?F?-N?[0-9]{2}[A-Z]{2})\ ([0-9]{3})\ [0-9]{3}[A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -612,13 +700,16 @@ pub fn rohm_br6265() -> &'static impl LabelParser<Ram> {
 pub fn rohm_br62256f() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(BR62256F-[0-9]{2}[A-Z]{2})\ ([0-9])([0-9]{2})\ [0-9]{3}[A-Z]{0,2}$"#,
+        "ram::rohm_br62256f",
+        r#"^(BR62256F-[0-9]{2}[A-Z]{2})\ ([0-9]{3})\ [0-9]{3}[A-Z]{0,2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -633,13 +724,16 @@ pub fn rohm_br62256f() -> &'static impl LabelParser<Ram> {
 pub fn lgs_gm76c256() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^LGS\ (GM76C256[ABC][A-Z]{1,4}[0-9]{2}E?)\ ([0-9]{2})([0-9]{2})\ KOREA$"#,
+        "ram::lgs_gm76c256",
+        r#"^LGS\ (GM76C256[ABC][A-Z]{1,4}[0-9]{2}E?)\ ([0-9]{4})\ (?P<country>KOREA)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Lgs),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -654,13 +748,16 @@ pub fn lgs_gm76c256() -> &'static impl LabelParser<Ram> {
 pub fn hyundai_gm76c256c() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^HYUNDAI\ (GM76C256C[A-Z]{1,4}[0-9]{2}E?)\ ([0-9]{2})([0-9]{2})\ KOREA$"#,
+        "ram::hyundai_gm76c256c",
+        r#"^HYUNDAI\ (GM76C256C[A-Z]{1,4}[0-9]{2}E?)\ ([0-9]{4})\ (?P<country>KOREA)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Hyundai),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -675,13 +772,16 @@ pub fn hyundai_gm76c256c() -> &'static impl LabelParser<Ram> {
 pub fn hyundai_hy628100b() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^HYUNDAI\ KOREA\ HY628100B\ ([0-9]{2})([0-9]{2})[A-Z]\ ([[:alnum:]]{2,4}-[0-9]{2}[EI]?)$"#,
+        "ram::hyundai_hy628100b",
+        r#"^HYUNDAI\ (?P<country>KOREA)\ HY628100B\ ([0-9]{4})[A-Z]\ ([[:alnum:]]{2,4}-[0-9]{2}[EI]?)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: format!("HY628100B{}", &c[3]),
                 manufacturer: Some(Manufacturer::Hyundai),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -696,13 +796,16 @@ pub fn hyundai_hy628100b() -> &'static impl LabelParser<Ram> {
 pub fn hyundai_hy6264a() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^HY6264A\ ([A-Z]{2,3}-[0-9]{2})\ ([0-9]{2})([0-9]{2})[A-Z]\ KOREA$"#,
+        "ram::hyundai_hy6264a",
+        r#"^HY6264A\ ([A-Z]{2,3}-[0-9]{2})\ ([0-9]{4})[A-Z]\ (?P<country>KOREA)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: format!("HY6264A{}", &c[1]),
                 manufacturer: Some(Manufacturer::Hyundai),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -717,13 +820,16 @@ pub fn hyundai_hy6264a() -> &'static impl LabelParser<Ram> {
 pub fn hyundai_hy6264a_2() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^HYUNDAI\ (HY6264A[A-Z]{3}-[0-9]{2})\ ([0-9]{2})([0-9]{2})[A-Z]\ KOREA$"#,
+        "ram::hyundai_hy6264a_2",
+        r#"^HYUNDAI\ (HY6264A[A-Z]{3}-[0-9]{2})\ ([0-9]{4})[A-Z]\ (?P<country>KOREA)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Hyundai),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -738,13 +844,16 @@ pub fn hyundai_hy6264a_2() -> &'static impl LabelParser<Ram> {
 pub fn victronix_vn4464s() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^Victronix\ (VN4464S-08LL)\ ([0-9]{2})([0-9]{2})[0-9][A-Z][0-9]{3}$"#,
+        "ram::victronix_vn4464s",
+        r#"^Victronix\ (VN4464S-08LL)\ ([0-9]{4})[0-9][A-Z][0-9]{3}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Victronix),
-                year: Some(year2(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -759,13 +868,16 @@ pub fn victronix_vn4464s() -> &'static impl LabelParser<Ram> {
 pub fn crosslink_lh52a64n_yl() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52A64N-YL)\ Xlink\ JAPAN\ H([0-9]{1})\ ?([0-9]{2})\ [[:alnum:]]{2}\ [A-Z]$"#,
+        "ram::crosslink_lh52a64n_yl",
+        r#"^(LH52A64N-YL)\ Xlink\ (?P<country>JAPAN)\ H([0-9]{1}\ ?[0-9]{2})\ [[:alnum:]]{2}\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Crosslink),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -780,13 +892,16 @@ pub fn crosslink_lh52a64n_yl() -> &'static impl LabelParser<Ram> {
 pub fn crosslink_lh5268anf() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH5268ANF-10YLL)\ Xlink\ JAPAN\ H([0-9]{1})\ ?([0-9]{2})\ [[:alnum:]]{2}\ [A-Z]{2}$"#,
+        "ram::crosslink_lh5268anf",
+        r#"^(LH5268ANF-10YLL)\ Xlink\ (?P<country>JAPAN)\ H([0-9]{1}\ ?[0-9]{2})\ [[:alnum:]]{2}\ [A-Z]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Crosslink),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -801,13 +916,16 @@ pub fn crosslink_lh5268anf() -> &'static impl LabelParser<Ram> {
 pub fn mosel_vitelic_lh52a64n_pl() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^(LH52A64N-PL)\ MOSEL-VITELIC\ JAPAN\ [A-Z]([0-9])([0-9]{2})\ [[:alnum:]]{2}\ [A-Z]{1,2}$"#,
+        "ram::mosel_vitelic_lh52a64n_pl",
+        r#"^(LH52A64N-PL)\ MOSEL-VITELIC\ (?P<country>JAPAN)\ [A-Z]([0-9]{3})\ [[:alnum:]]{2}\ [A-Z]{1,2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(Ram {
                 kind: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::MoselVitelic),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -822,13 +940,16 @@ pub fn mosel_vitelic_lh52a64n_pl() -> &'static impl LabelParser<Ram> {
 pub fn hynix_hy62wt08081e() -> &'static impl LabelParser<Ram> {
     single_parser!(
         Ram,
-        r#"^hynix\ ([0-9]{2})([0-9]{2})[A-Z]\ (HY62WT081E[LD][0-9][0-9][CEI])\ KOREA$"#,
+        "ram::hynix_hy62wt08081e",
+        r#"^hynix\ ([0-9]{4})[A-Z]\ (HY62WT081E[LD][0-9][0-9][CEI])\ (?P<country>KOREA)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Ram {
-                kind: c[3].to_owned(),
+                kind: c[2].to_owned(),
                 manufacturer: Some(Manufacturer::Hynix),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
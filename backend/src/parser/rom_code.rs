@@ -0,0 +1,137 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use regex::Regex;
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+use crate::Console;
+
+/// Game code printed on mask ROMs and used as the cartridge code, e.g. DMG-AP2J-0
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RomCode {
+    /// Console the game was made for (DMG, CGB or AGB)
+    pub platform: Console,
+    /// Game code without the region letter, e.g. "AP2"
+    pub game_code: String,
+    pub region: char,
+    /// Mask revision
+    pub revision: u8,
+}
+
+/// Difference between two ROM codes, in decreasing order of severity
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RomCodeMismatch {
+    Game,
+    Region,
+    Revision,
+}
+
+impl RomCode {
+    /// Game code including the region letter, e.g. "AP2J"
+    pub fn full_game_code(&self) -> String {
+        format!("{}{}", self.game_code, self.region)
+    }
+    /// Human-readable name of the region letter, if known
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::RomCode;
+    ///
+    /// let code: RomCode = "DMG-AP2J-0".parse().unwrap();
+    /// assert_eq!(code.region_name(), Some("Japan"));
+    /// ```
+    pub fn region_name(&self) -> Option<&'static str> {
+        match self.region {
+            'A' => Some("All regions"),
+            'B' => Some("Brazil"),
+            'C' => Some("China"),
+            'D' => Some("Germany"),
+            'E' => Some("USA"),
+            'F' => Some("France"),
+            'H' => Some("Netherlands"),
+            'I' => Some("Italy"),
+            'J' => Some("Japan"),
+            'K' => Some("Korea"),
+            'P' | 'X' | 'Y' | 'Z' => Some("Europe"),
+            'S' => Some("Spain"),
+            'U' => Some("Australia"),
+            _ => None,
+        }
+    }
+    /// Compares this code to an expected code
+    ///
+    /// ```
+    /// use gbhwdb_backend::parser::{RomCode, RomCodeMismatch};
+    ///
+    /// let code: RomCode = "DMG-APSJ-2".parse().unwrap();
+    /// assert_eq!(code.mismatch(&"DMG-APSJ-2".parse().unwrap()), None);
+    /// assert_eq!(code.mismatch(&"DMG-APSJ-0".parse().unwrap()), Some(RomCodeMismatch::Revision));
+    /// assert_eq!(code.mismatch(&"DMG-APSE-0".parse().unwrap()), Some(RomCodeMismatch::Region));
+    /// assert_eq!(code.mismatch(&"CGB-APSJ-2".parse().unwrap()), Some(RomCodeMismatch::Game));
+    /// ```
+    pub fn mismatch(&self, expected: &RomCode) -> Option<RomCodeMismatch> {
+        if self.platform != expected.platform || self.game_code != expected.game_code {
+            Some(RomCodeMismatch::Game)
+        } else if self.region != expected.region {
+            Some(RomCodeMismatch::Region)
+        } else if self.revision != expected.revision {
+            Some(RomCodeMismatch::Revision)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for RomCode {
+    type Err = String;
+
+    /// ```
+    /// use gbhwdb_backend::{parser::RomCode, Console};
+    ///
+    /// let code: RomCode = "CGB-BFPU-0".parse().unwrap();
+    /// assert_eq!(code.platform, Console::Cgb);
+    /// assert_eq!(code.game_code, "BFP");
+    /// assert_eq!(code.region, 'U');
+    /// assert_eq!(code.revision, 0);
+    ///
+    /// let code: RomCode = "DMG-TRA-1".parse().unwrap();
+    /// assert_eq!(code.game_code, "TR");
+    /// assert_eq!(code.revision, 1);
+    /// assert_eq!(code.to_string(), "DMG-TRA-1");
+    ///
+    /// assert!("DMG-TR-USA".parse::<RomCode>().is_err());
+    /// ```
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        static REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = REGEX.get_or_init(|| {
+            Regex::new(r#"^(DMG|CGB|AGB)-([[:alnum:]]{2,3})([A-Z])-([0-9])$"#).unwrap()
+        });
+        let c = regex
+            .captures(code)
+            .ok_or_else(|| format!("Invalid ROM code {code}"))?;
+        Ok(RomCode {
+            platform: match &c[1] {
+                "DMG" => Console::Dmg,
+                "CGB" => Console::Cgb,
+                _ => Console::Agb,
+            },
+            game_code: c[2].to_owned(),
+            region: c[3].chars().next().unwrap_or_default(),
+            revision: c[4]
+                .parse()
+                .map_err(|_| format!("Invalid ROM code {code}"))?,
+        })
+    }
+}
+
+impl fmt::Display for RomCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}-{}",
+            self.platform.code(),
+            self.full_game_code(),
+            self.revision
+        )
+    }
+}
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, ChipYearWeek, LabelParser};
+use super::{ChipYearWeek, DateCodeConvention, LabelParser};
 use crate::{macros::single_parser, parser::Manufacturer};
 
 pub type Rtc = ChipYearWeek;
@@ -12,14 +12,21 @@ pub type Rtc = ChipYearWeek;
 /// assert!(parser::rtc::toshiba_tc8521am().parse("T 9722HB 8521AM").is_ok());
 /// ```
 pub fn toshiba_tc8521am() -> &'static impl LabelParser<Rtc> {
-    single_parser!(Rtc, r#"^T\ ([0-9]{2})([0-9]{2})HB\ 8521AM$"#, move |c| {
-        Ok(Rtc {
-            kind: "TC8521AM".to_owned(),
-            manufacturer: Some(Manufacturer::Toshiba),
-            year: Some(year2(&c[1])?),
-            week: Some(week2(&c[2])?),
-        })
-    },)
+    single_parser!(
+        Rtc,
+        "rtc::toshiba_tc8521am",
+        r#"^T\ ([0-9]{4})HB\ 8521AM$"#,
+        move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
+            Ok(Rtc {
+                kind: "TC8521AM".to_owned(),
+                manufacturer: Some(Manufacturer::Toshiba),
+                year: date.year,
+                week: date.week,
+                country: None,
+            })
+        },
+    )
 }
 
 pub fn rtc() -> &'static impl LabelParser<Rtc> {
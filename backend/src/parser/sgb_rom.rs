@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, LabelParser, Manufacturer, Year};
+use super::{capture_country, Country, DateCodeConvention, LabelParser, Manufacturer, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -15,6 +15,7 @@ pub struct SgbRom {
     pub chip_type: Option<String>,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// ```
@@ -24,14 +25,17 @@ pub struct SgbRom {
 pub fn unknown() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^(SYS-SGB-(NT|2))\ ©\ 1994\ Nintendo\ ([0-9]{2})([0-9]{2})\ [A-Z][0-9]{2}$"#,
+        "sgb_rom::unknown",
+        r#"^(SYS-SGB-(NT|2))\ ©\ 1994\ Nintendo\ ([0-9]{4})\ [A-Z][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: None,
                 chip_type: None,
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -44,14 +48,17 @@ pub fn unknown() -> &'static impl LabelParser<SgbRom> {
 pub fn unknown2() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^(SYS-SGB-(NT|2))\ ©\ 1994\ Nintendo\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "sgb_rom::unknown2",
+        r#"^(SYS-SGB-(NT|2))\ ©\ 1994\ Nintendo\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: None,
                 chip_type: None,
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -64,7 +71,8 @@ pub fn unknown2() -> &'static impl LabelParser<SgbRom> {
 pub fn unknown3() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^(SYS-SGB-(NT|2))\ JAPAN\ ©\ 1994\ Nintendo\ [[:alnum:]]{5}\ [[:alnum:]]{3}\ [A-Z]{3}$"#,
+        "sgb_rom::unknown3",
+        r#"^(SYS-SGB-(NT|2))\ (?P<country>JAPAN)\ ©\ 1994\ Nintendo\ [[:alnum:]]{5}\ [[:alnum:]]{3}\ [A-Z]{3}$"#,
         move |c| {
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
@@ -72,6 +80,7 @@ pub fn unknown3() -> &'static impl LabelParser<SgbRom> {
                 chip_type: None,
                 year: None,
                 week: None,
+                country: capture_country(&c),
             })
         },
     )
@@ -84,14 +93,17 @@ pub fn unknown3() -> &'static impl LabelParser<SgbRom> {
 pub fn unknown4() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^©\ 1994\ Nintendo\ (SYS-SGB-(NT|2))\ (N-[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "sgb_rom::unknown4",
+        r#"^©\ 1994\ Nintendo\ (SYS-SGB-(NT|2))\ (N-[0-9]{4}[[:alnum:]]{3,4})-[A-Z][0-9]{2}\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: None,
                 chip_type: Some(c[3].to_owned()),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -106,14 +118,17 @@ pub fn unknown4() -> &'static impl LabelParser<SgbRom> {
 pub fn toshiba() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^(SYS-SGB-(NT|2))\ ©\ 1994\ Nintendo\ (TC53[0-9]{4}[A-Z]{2})-[A-Z][0-9]{3}\ JAPAN\ ([0-9]{2})([0-9]{2})EAI$"#,
+        "sgb_rom::toshiba",
+        r#"^(SYS-SGB-(NT|2))\ ©\ 1994\ Nintendo\ (TC53[0-9]{4}[A-Z]{2})-[A-Z][0-9]{3}\ (?P<country>JAPAN)\ ([0-9]{4})EAI$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[5])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Toshiba),
                 chip_type: (Some(c[3].to_owned())),
-                year: Some(year2(&c[4])?),
-                week: Some(week2(&c[5])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -128,14 +143,17 @@ pub fn toshiba() -> &'static impl LabelParser<SgbRom> {
 pub fn sharp_sgb() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^(SYS-SGB-NT|SYS-SGB-2)\ ©\ 1994\ Nintendo\ (LH[[:alnum:]]{4})[[:alnum:]]{2}\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "sgb_rom::sharp_sgb",
+        r#"^(SYS-SGB-NT|SYS-SGB-2)\ ©\ 1994\ Nintendo\ (LH[[:alnum:]]{4})[[:alnum:]]{2}\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: Some(c[2].to_owned()),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -150,14 +168,17 @@ pub fn sharp_sgb() -> &'static impl LabelParser<SgbRom> {
 pub fn sharp_sgb2() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^©\ 1998\ Nintendo\ (SYS-SGB2-10)\ (LH[[:alnum:]]{4})[[:alnum:]]{2}\ ([0-9]{2})([0-9]{2})\ [A-Z]$"#,
+        "sgb_rom::sharp_sgb2",
+        r#"^©\ 1998\ Nintendo\ (SYS-SGB2-10)\ (LH[[:alnum:]]{4})[[:alnum:]]{2}\ ([0-9]{4})\ [A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Sharp),
                 chip_type: Some(c[2].to_owned()),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -172,14 +193,17 @@ pub fn sharp_sgb2() -> &'static impl LabelParser<SgbRom> {
 pub fn oki() -> &'static impl LabelParser<SgbRom> {
     single_parser!(
         SgbRom,
-        r#"^(SYS-SGB-NT|SYS-SGB-2|SYS-SGB2-10)\ ©\ 1998\ Nintendo\ (M534011E)-[[:alnum:]]{2}\ ([0-9])([0-9]{2})[0-9]{3}[[:alnum:]]$"#,
+        "sgb_rom::oki",
+        r#"^(SYS-SGB-NT|SYS-SGB-2|SYS-SGB2-10)\ ©\ 1998\ Nintendo\ (M534011E)-[[:alnum:]]{2}\ ([0-9]{3})[0-9]{3}[[:alnum:]]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(SgbRom {
                 rom_code: c[1].to_owned(),
                 manufacturer: Some(Manufacturer::Oki),
                 chip_type: Some(c[2].to_owned()),
-                year: Some(year1(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, year2, LabelParser, Manufacturer, StaticRam};
+use super::{capture_country, DateCodeConvention, LabelParser, Manufacturer, StaticRam};
 use crate::macros::{multi_parser, single_parser};
 
 /// NEC μPD442012A-X
@@ -18,18 +18,21 @@ use crate::macros::{multi_parser, single_parser};
 pub fn nec_upd442012a() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^NEC\ JAPAN\ D442012AGY-(BB|BC|DD)([0-9]{2})X-MJH\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "sram_tsop1_48::nec_upd442012a",
+        r#"^NEC\ (?P<country>JAPAN)\ D442012AGY-(BB|BC|DD)([0-9]{2})X-MJH\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(StaticRam {
                 family: Some("μPD442012A-X"),
                 part: Some(format!(
                     "μPD442012AGY-{version}{access_time}X-MJH",
-                    version = &c[1],
-                    access_time = &c[2]
+                    version = &c[2],
+                    access_time = &c[3]
                 )),
                 manufacturer: Some(Manufacturer::Nec),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -47,18 +50,21 @@ pub fn nec_upd442012a() -> &'static impl LabelParser<StaticRam> {
 pub fn nec_upd442012l() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^NEC\ JAPAN\ D442012LGY-(B|C|D)([0-9]{2})X-MJH\ ([0-9]{2})([0-9]{2})[A-Z][0-9]{4}$"#,
+        "sram_tsop1_48::nec_upd442012l",
+        r#"^NEC\ (?P<country>JAPAN)\ D442012LGY-(B|C|D)([0-9]{2})X-MJH\ ([0-9]{4})[A-Z][0-9]{4}$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[4])?;
             Ok(StaticRam {
                 family: Some("μPD442012L-X"),
                 part: Some(format!(
                     "μPD442012LGY-{version}{access_time}X-MJH",
-                    version = &c[1],
-                    access_time = &c[2]
+                    version = &c[2],
+                    access_time = &c[3]
                 )),
                 manufacturer: Some(Manufacturer::Nec),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -73,14 +79,17 @@ pub fn nec_upd442012l() -> &'static impl LabelParser<StaticRam> {
 pub fn fujitsu_mb82d12160() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^JAPAN\ 82D12160-10FN\ ([0-9]{2})([0-9]{2})\ [A-Z][0-9]{2}[A-Z]$"#,
+        "sram_tsop1_48::fujitsu_mb82d12160",
+        r#"^(?P<country>JAPAN)\ 82D12160-10FN\ ([0-9]{4})\ [A-Z][0-9]{2}[A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(StaticRam {
                 family: Some("MB82D12160"),
                 part: Some("MB82D12160-10FN".to_owned()),
                 manufacturer: Some(Manufacturer::Fujitsu),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -98,14 +107,17 @@ pub fn fujitsu_mb82d12160() -> &'static impl LabelParser<StaticRam> {
 pub fn hynix_hy62lf16206a() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^Hynix\ KOREA\ HY62LF16206A\ ([0-9]{2})([0-9]{2})[A-Z]\ LT12C$"#,
+        "sram_tsop1_48::hynix_hy62lf16206a",
+        r#"^Hynix\ (?P<country>KOREA)\ HY62LF16206A\ ([0-9]{4})[A-Z]\ LT12C$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(StaticRam {
                 family: Some("HY62LF16206A"),
                 part: Some("HY62LF16206A-LT12C".to_owned()),
                 manufacturer: Some(Manufacturer::Hynix),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -120,14 +132,17 @@ pub fn hynix_hy62lf16206a() -> &'static impl LabelParser<StaticRam> {
 pub fn st_micro_m68as128dl70n6() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^([A-Z]\ )?M68AS128\ DL70N6\ [A-Z]{5}\ F6\ TWN\ [[:alnum:]]{2}\ ([0-9])([0-9]{2})$"#,
+        "sram_tsop1_48::st_micro_m68as128dl70n6",
+        r#"^([A-Z]\ )?M68AS128\ DL70N6\ [A-Z]{5}\ F6\ (?P<country>TWN)\ [[:alnum:]]{2}\ ([0-9]{3})$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[3])?;
             Ok(StaticRam {
                 family: Some("M68AS128"),
                 part: Some("M68AS128DL70N6".to_owned()),
                 manufacturer: Some(Manufacturer::StMicro),
-                year: Some(year1(&c[2])?),
-                week: Some(week2(&c[3])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -145,8 +160,10 @@ pub fn st_micro_m68as128dl70n6() -> &'static impl LabelParser<StaticRam> {
 pub fn amic_lp62s16128bw() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^AMIC\ LP62S16128BW-([0-9]{2})(LLT|LLTF)\ [[:alnum:]]{10}\ ([0-9]{2})([0-9]{2})[A-Z]$"#,
+        "sram_tsop1_48::amic_lp62s16128bw",
+        r#"^AMIC\ LP62S16128BW-([0-9]{2})(LLT|LLTF)\ [[:alnum:]]{10}\ ([0-9]{4})[A-Z]$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(StaticRam {
                 family: Some("LP62S16128BW-T"),
                 part: Some(format!(
@@ -155,8 +172,9 @@ pub fn amic_lp62s16128bw() -> &'static impl LabelParser<StaticRam> {
                     version = &c[2],
                 )),
                 manufacturer: Some(Manufacturer::Amic),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         },
     )
@@ -175,8 +193,10 @@ pub fn amic_lp62s16128bw() -> &'static impl LabelParser<StaticRam> {
 pub fn bsi_bs616lv2018() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^BSI\ BS616LV2018(TC|TI)-([0-9]{2})\ S31686-2FY[0-9]{5}.1\ [A-Z]([0-9]{2})([0-9]{2})\ TAIWAN$"#,
+        "sram_tsop1_48::bsi_bs616lv2018",
+        r#"^BSI\ BS616LV2018(TC|TI)-([0-9]{2})\ S31686-2FY[0-9]{5}.1\ [A-Z]([0-9]{4})\ (?P<country>TAIWAN)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(StaticRam {
                 family: Some("BS616LV2018"),
                 part: Some(format!(
@@ -185,8 +205,9 @@ pub fn bsi_bs616lv2018() -> &'static impl LabelParser<StaticRam> {
                     access_time = &c[2],
                 )),
                 manufacturer: Some(Manufacturer::Bsi),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -206,8 +227,10 @@ pub fn bsi_bs616lv2018() -> &'static impl LabelParser<StaticRam> {
 pub fn bsi_bs616lv2019() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^BSI\ BS616LV2019(TC|TI)-([0-9]{2})\ S31687FZ[0-9]{5}.1\ [A-Z]([0-9]{2})([0-9]{2})\ TAIWAN$"#,
+        "sram_tsop1_48::bsi_bs616lv2019",
+        r#"^BSI\ BS616LV2019(TC|TI)-([0-9]{2})\ S31687FZ[0-9]{5}.1\ [A-Z]([0-9]{4})\ (?P<country>TAIWAN)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[3])?;
             Ok(StaticRam {
                 family: Some("BS616LV2019"),
                 part: Some(format!(
@@ -216,8 +239,9 @@ pub fn bsi_bs616lv2019() -> &'static impl LabelParser<StaticRam> {
                     access_time = &c[2],
                 )),
                 manufacturer: Some(Manufacturer::Bsi),
-                year: Some(year2(&c[3])?),
-                week: Some(week2(&c[4])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -232,14 +256,17 @@ pub fn bsi_bs616lv2019() -> &'static impl LabelParser<StaticRam> {
 pub fn toshiba_tc55v200() -> &'static impl LabelParser<StaticRam> {
     single_parser!(
         StaticRam,
-        r#"^K13529\ JAPAN\ ([0-9]{2})([0-9]{2})\ MAD\ TC55V200\ FT-([0-9]{2})$"#,
+        "sram_tsop1_48::toshiba_tc55v200",
+        r#"^K13529\ (?P<country>JAPAN)\ ([0-9]{4})\ MAD\ TC55V200\ FT-([0-9]{2})$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(StaticRam {
                 family: Some("TC55V200"),
                 part: Some(format!("TC55V200FT-{access_time}", access_time = &c[3],)),
                 manufacturer: Some(Manufacturer::Toshiba),
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
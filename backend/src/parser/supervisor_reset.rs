@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year1, LabelParser, Manufacturer, Year};
+use super::{Country, DateCodeConvention, LabelParser, Manufacturer, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -14,6 +14,7 @@ pub struct SupervisorReset {
     pub manufacturer: Option<Manufacturer>,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 /// Mitsubishi M62021P
@@ -25,13 +26,16 @@ pub struct SupervisorReset {
 pub fn mitsubishi_m62021p() -> &'static impl LabelParser<SupervisorReset> {
     single_parser!(
         SupervisorReset,
+        "supervisor_reset::mitsubishi_m62021p",
         r#"^2021\ ([0-9])[[:alnum:]][0-9]$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(SupervisorReset {
                 chip_type: "M62021P".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsubishi),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
@@ -47,13 +51,16 @@ pub fn mitsubishi_m62021p() -> &'static impl LabelParser<SupervisorReset> {
 pub fn mitsumi_mm1026a() -> &'static impl LabelParser<SupervisorReset> {
     single_parser!(
         SupervisorReset,
+        "supervisor_reset::mitsumi_mm1026a",
         r#"^([0-9])([[:alnum:]][0-9]{1,2})\ 26A$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(SupervisorReset {
                 chip_type: "MM1026A".to_owned(),
                 manufacturer: Some(Manufacturer::Mitsumi),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
@@ -66,14 +73,21 @@ pub fn mitsumi_mm1026a() -> &'static impl LabelParser<SupervisorReset> {
 /// assert!(parser::supervisor_reset::mitsumi_mm1134a().parse("939 134A").is_ok());
 /// ```
 pub fn mitsumi_mm1134a() -> &'static impl LabelParser<SupervisorReset> {
-    single_parser!(SupervisorReset, r#"^([0-9])([0-9]{2})\ 134A$"#, move |c| {
-        Ok(SupervisorReset {
-            chip_type: "MM1134A".to_owned(),
-            manufacturer: Some(Manufacturer::Mitsumi),
-            year: Some(year1(&c[1])?),
-            week: Some(week2(&c[2])?),
-        })
-    })
+    single_parser!(
+        SupervisorReset,
+        "supervisor_reset::mitsumi_mm1134a",
+        r#"^([0-9]{3})\ 134A$"#,
+        move |c| {
+            let date = DateCodeConvention::ShortYearWeek.decode(&c[1])?;
+            Ok(SupervisorReset {
+                chip_type: "MM1134A".to_owned(),
+                manufacturer: Some(Manufacturer::Mitsumi),
+                year: date.year,
+                week: date.week,
+                country: None,
+            })
+        }
+    )
 }
 
 /// ROHM BA6129
@@ -85,13 +99,16 @@ pub fn mitsumi_mm1134a() -> &'static impl LabelParser<SupervisorReset> {
 pub fn rohm_ba6129() -> &'static impl LabelParser<SupervisorReset> {
     single_parser!(
         SupervisorReset,
+        "supervisor_reset::rohm_ba6129",
         r#"^6129\ ([0-9])[[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(SupervisorReset {
                 chip_type: "BA6129".to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
@@ -106,13 +123,16 @@ pub fn rohm_ba6129() -> &'static impl LabelParser<SupervisorReset> {
 pub fn rohm_ba6129a() -> &'static impl LabelParser<SupervisorReset> {
     single_parser!(
         SupervisorReset,
+        "supervisor_reset::rohm_ba6129a",
         r#"^6129A\ ([0-9])[[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(SupervisorReset {
                 chip_type: "BA6129A".to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
@@ -127,13 +147,16 @@ pub fn rohm_ba6129a() -> &'static impl LabelParser<SupervisorReset> {
 pub fn rohm_ba6735() -> &'static impl LabelParser<SupervisorReset> {
     single_parser!(
         SupervisorReset,
+        "supervisor_reset::rohm_ba6735",
         r#"^6735\ ([0-9])[[:alnum:]][0-9]{2}$"#,
         move |c| {
+            let date = DateCodeConvention::ShortYear.decode(&c[1])?;
             Ok(SupervisorReset {
                 chip_type: "BA6735".to_owned(),
                 manufacturer: Some(Manufacturer::Rohm),
-                year: Some(year1(&c[1])?),
+                year: date.year,
                 week: None,
+                country: None,
             })
         }
     )
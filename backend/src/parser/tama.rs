@@ -1,8 +1,8 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use super::{week2, year2, LabelParser, Year};
+use super::{capture_country, Country, DateCodeConvention, LabelParser, Year};
 use crate::{
     macros::{multi_parser, single_parser},
     time::Week,
@@ -13,6 +13,7 @@ pub struct Tama {
     pub tama_type: TamaType,
     pub year: Option<Year>,
     pub week: Option<Week>,
+    pub country: Option<Country>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -22,6 +23,16 @@ pub enum TamaType {
     Tama7,
 }
 
+impl TamaType {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TamaType::Tama5 => "TAMA5",
+            TamaType::Tama6 => "TAMA6",
+            TamaType::Tama7 => "TAMA7",
+        }
+    }
+}
+
 /// TAMA5
 ///
 /// ```
@@ -31,12 +42,15 @@ pub enum TamaType {
 pub fn tama5() -> &'static impl LabelParser<Tama> {
     single_parser!(
         Tama,
-        r#"^TAMA5\ ([0-9]{2})([0-9]{2})\ EA[A-Z]1$"#,
+        "tama::tama5",
+        r#"^TAMA5\ ([0-9]{4})\ EA[A-Z]1$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Tama {
                 tama_type: TamaType::Tama5,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: None,
             })
         }
     )
@@ -51,12 +65,15 @@ pub fn tama5() -> &'static impl LabelParser<Tama> {
 pub fn tama6() -> &'static impl LabelParser<Tama> {
     single_parser!(
         Tama,
-        r#"^TAMA6\ JAPAN\ 47C243M\ FV61\ ([0-9]{2})([0-9]{2})H$"#,
+        "tama::tama6",
+        r#"^TAMA6\ (?P<country>JAPAN)\ 47C243M\ FV61\ ([0-9]{4})H$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[2])?;
             Ok(Tama {
                 tama_type: TamaType::Tama6,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -71,12 +88,15 @@ pub fn tama6() -> &'static impl LabelParser<Tama> {
 pub fn tama7() -> &'static impl LabelParser<Tama> {
     single_parser!(
         Tama,
-        r#"^TAMA7\ [A-Z]([0-9]{2})([0-9]{2})\ [0-9]{5}[A-Z]\ TAIWAN$"#,
+        "tama::tama7",
+        r#"^TAMA7\ [A-Z]([0-9]{4})\ [0-9]{5}[A-Z]\ (?P<country>TAIWAN)$"#,
         move |c| {
+            let date = DateCodeConvention::YearWeek.decode(&c[1])?;
             Ok(Tama {
                 tama_type: TamaType::Tama7,
-                year: Some(year2(&c[1])?),
-                week: Some(week2(&c[2])?),
+                year: date.year,
+                week: date.week,
+                country: capture_country(&c),
             })
         },
     )
@@ -0,0 +1,266 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Predicts what is inside a console from its serial and shell markings.
+//!
+//! The prediction is a weighted vote of the most similar submitted units. The site writes one
+//! [`UnitIndex`] per console, which is then used by both the command line tool and the lookup
+//! page on the site.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::serial::Serial;
+
+/// How many similar units take part in the vote
+pub const NEIGHBOURS: usize = 5;
+
+/// Serial distance at which serial similarity drops to half
+const SERIAL_SCALE: f32 = 100_000.0;
+
+const SERIAL_WEIGHT: f32 = 2.0;
+const RELEASE_CODE_WEIGHT: f32 = 1.0;
+const STAMP_WEIGHT: f32 = 0.5;
+const COLOR_WEIGHT: f32 = 0.5;
+
+/// Hardware of all submitted units of one console
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitIndex {
+    /// Console id, e.g. "dmg"
+    pub console: String,
+    pub units: Vec<IndexedUnit>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedUnit {
+    pub slug: String,
+    pub title: String,
+    pub serial: Option<String>,
+    pub color: Option<String>,
+    pub release_code: Option<String>,
+    pub stamp: Option<String>,
+    pub mainboard: String,
+    pub chips: Vec<IndexedChip>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedChip {
+    pub label: String,
+    pub designator: String,
+    pub kind: String,
+}
+
+/// What is known about the unit. Empty fields are ignored
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Query {
+    pub serial: Option<String>,
+    pub color: Option<String>,
+    pub release_code: Option<String>,
+    pub stamp: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Prediction {
+    pub mainboard: Option<Vote>,
+    pub chips: Vec<ChipVote>,
+    /// Units the prediction is based on, most similar first
+    pub neighbours: Vec<Neighbour>,
+}
+
+/// The winning value of a vote
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vote {
+    pub value: String,
+    /// Share of the total similarity of the neighbours that agree, from 0.0 to 1.0
+    pub confidence: f32,
+    /// How many neighbours agree
+    pub support: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChipVote {
+    pub label: String,
+    pub designator: String,
+    #[serde(flatten)]
+    pub vote: Vote,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Neighbour {
+    pub slug: String,
+    pub title: String,
+    pub similarity: f32,
+}
+
+/// Compares shell markings ignoring case, spaces and punctuation, so "pearl blue" matches
+/// "PearlBlue"
+fn matches(query: &Option<String>, value: &Option<String>) -> bool {
+    let normalize = |text: &Option<String>| {
+        text.iter()
+            .flat_map(|text| text.chars())
+            .filter(|ch| ch.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+    let query = normalize(query);
+    !query.is_empty() && query == normalize(value)
+}
+
+impl Query {
+    /// Similarity of a submitted unit to this query. Zero means nothing in common
+    ///
+    /// Serials only count when the prefix matches, because the numbering runs separately for
+    /// every prefix.
+    pub fn similarity(&self, unit: &IndexedUnit) -> f32 {
+        let query_serial = self.serial.as_deref().and_then(Serial::parse);
+        let unit_serial = unit.serial.as_deref().and_then(Serial::parse);
+        let serial = match (query_serial, unit_serial) {
+            (Some(query), Some(unit)) if query.prefix == unit.prefix => {
+                let distance = query.number.abs_diff(unit.number) as f32;
+                SERIAL_WEIGHT / (1.0 + distance / SERIAL_SCALE)
+            }
+            _ => 0.0,
+        };
+        let weight = |matching: bool, weight: f32| if matching { weight } else { 0.0 };
+        serial
+            + weight(
+                matches(&self.release_code, &unit.release_code),
+                RELEASE_CODE_WEIGHT,
+            )
+            + weight(matches(&self.stamp, &unit.stamp), STAMP_WEIGHT)
+            + weight(matches(&self.color, &unit.color), COLOR_WEIGHT)
+    }
+}
+
+fn vote<'a>(values: impl Iterator<Item = (&'a str, f32)>) -> Option<Vote> {
+    let mut totals: BTreeMap<&str, (f32, usize)> = BTreeMap::new();
+    let mut total = 0.0;
+    for (value, similarity) in values {
+        let entry = totals.entry(value).or_default();
+        entry.0 += similarity;
+        entry.1 += 1;
+        total += similarity;
+    }
+    let (value, (weight, support)) = totals
+        .into_iter()
+        .max_by(|(_, (a, _)), (_, (b, _))| a.total_cmp(b))?;
+    Some(Vote {
+        value: value.to_owned(),
+        confidence: weight / total,
+        support,
+    })
+}
+
+impl UnitIndex {
+    /// Predicts the mainboard and chips of a unit from its most similar submitted units.
+    ///
+    /// ```
+    /// # use gbhwdb_backend::prediction::*;
+    /// let unit = |serial: &str, mainboard: &str| IndexedUnit {
+    ///     serial: Some(serial.to_owned()),
+    ///     mainboard: mainboard.to_owned(),
+    ///     ..IndexedUnit::default()
+    /// };
+    /// let index = UnitIndex {
+    ///     console: "cgb".to_owned(),
+    ///     units: vec![
+    ///         unit("C10100000", "CGB-CPU-01"),
+    ///         unit("C10200000", "CGB-CPU-01"),
+    ///         unit("C20100000", "CGB-CPU-05"),
+    ///     ],
+    /// };
+    /// let query = Query {
+    ///     serial: Some("C10150000".to_owned()),
+    ///     ..Query::default()
+    /// };
+    /// let prediction = index.predict(&query);
+    /// assert_eq!(prediction.mainboard.unwrap().value, "CGB-CPU-01");
+    /// assert_eq!(prediction.neighbours.len(), 3);
+    /// ```
+    pub fn predict(&self, query: &Query) -> Prediction {
+        let mut neighbours = self
+            .units
+            .iter()
+            .map(|unit| (unit, query.similarity(unit)))
+            .filter(|&(_, similarity)| similarity > 0.0)
+            .collect::<Vec<_>>();
+        neighbours.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        neighbours.truncate(NEIGHBOURS);
+
+        let mainboard = vote(
+            neighbours
+                .iter()
+                .map(|&(unit, similarity)| (unit.mainboard.as_str(), similarity)),
+        );
+        let mut designators: Vec<(&str, &str)> = Vec::new();
+        for chip in neighbours.iter().flat_map(|(unit, _)| &unit.chips) {
+            if !designators
+                .iter()
+                .any(|&(_, designator)| designator == chip.designator)
+            {
+                designators.push((&chip.label, &chip.designator));
+            }
+        }
+        let chips = designators
+            .into_iter()
+            .filter_map(|(label, designator)| {
+                let vote = vote(neighbours.iter().filter_map(|&(unit, similarity)| {
+                    let chip = unit
+                        .chips
+                        .iter()
+                        .find(|chip| chip.designator == designator)?;
+                    Some((chip.kind.as_str(), similarity))
+                }))?;
+                Some(ChipVote {
+                    label: label.to_owned(),
+                    designator: designator.to_owned(),
+                    vote,
+                })
+            })
+            .collect();
+        Prediction {
+            mainboard,
+            chips,
+            neighbours: neighbours
+                .into_iter()
+                .map(|(unit, similarity)| Neighbour {
+                    slug: unit.slug.clone(),
+                    title: unit.title.clone(),
+                    similarity,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[test]
+fn test_similarity() {
+    let unit = IndexedUnit {
+        serial: Some("C10100000".to_owned()),
+        release_code: Some("CGB-JPN".to_owned()),
+        ..IndexedUnit::default()
+    };
+    let query = |serial: &str, release_code: Option<&str>| Query {
+        serial: Some(serial.to_owned()),
+        release_code: release_code.map(str::to_owned),
+        ..Query::default()
+    };
+    assert_eq!(query("C10100000", None).similarity(&unit), SERIAL_WEIGHT);
+    assert_eq!(query("CH10100000", None).similarity(&unit), 0.0);
+    assert_eq!(
+        query("CH10100000", Some("cgb-jpn")).similarity(&unit),
+        RELEASE_CODE_WEIGHT
+    );
+    assert!(
+        query("C10200000", None).similarity(&unit) < query("C10150000", None).similarity(&unit)
+    );
+}
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+/// Serial number printed on a console shell, e.g. "G01009132" or "MG12345678-9"
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Serial<'a> {
+    /// Letters in front of the number. Depends on the console, region and factory
+    pub prefix: &'a str,
+    /// Sequence number
+    pub number: u32,
+    /// The serial as printed, including a possible check digit
+    pub text: &'a str,
+}
+
+impl<'a> Serial<'a> {
+    /// Splits a serial into its prefix and sequence number.
+    ///
+    /// ```
+    /// # use gbhwdb_backend::serial::Serial;
+    /// let serial = Serial::parse("CG10004893-3").unwrap();
+    /// assert_eq!(serial.prefix, "CG");
+    /// assert_eq!(serial.number, 10004893);
+    /// assert_eq!(Serial::parse("Unit #1"), None);
+    /// ```
+    pub fn parse(text: &'a str) -> Option<Self> {
+        let digits = text.find(|ch: char| ch.is_ascii_digit())?;
+        let (prefix, rest) = text.split_at(digits);
+        if prefix.is_empty() || !prefix.chars().all(|ch| ch.is_ascii_uppercase()) {
+            return None;
+        }
+        let is_number = |text: &str| !text.is_empty() && text.chars().all(|ch| ch.is_ascii_digit());
+        let number = match rest.split_once('-') {
+            Some((number, check_digit)) if check_digit.len() == 1 && is_number(check_digit) => {
+                number
+            }
+            Some(_) => return None,
+            None => rest,
+        };
+        if !is_number(number) {
+            return None;
+        }
+        Some(Serial {
+            prefix,
+            number: number.parse().ok()?,
+            text,
+        })
+    }
+}
+
+impl<'a> fmt::Display for Serial<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.text)
+    }
+}
+
+#[test]
+fn test_serial() {
+    assert_eq!(
+        Serial::parse("G01009132"),
+        Some(Serial {
+            prefix: "G",
+            number: 1009132,
+            text: "G01009132"
+        })
+    );
+    assert_eq!(
+        Serial::parse("MG12345678-9"),
+        Some(Serial {
+            prefix: "MG",
+            number: 12345678,
+            text: "MG12345678-9"
+        })
+    );
+    assert_eq!(Serial::parse("12345678"), None);
+    assert_eq!(Serial::parse("G1234X"), None);
+    assert_eq!(Serial::parse("G1234-56"), None);
+}
@@ -26,6 +26,8 @@ static MIME_MAPPING: &[(&str, &str)] = &[
     ("png", "image/png"),
     ("jpg", "image/jpeg"),
     ("css", "text/css"),
+    ("js", "text/javascript"),
+    ("wasm", "application/wasm"),
     ("csv", "text/csv"),
     ("svg", "image/svg+xml"),
     ("txt", "text/plain"),
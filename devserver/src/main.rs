@@ -1,14 +1,82 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use axum::{routing::get_service, Router};
-use std::net::SocketAddr;
+use anyhow::{anyhow, Error};
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{Html, IntoResponse, Response},
+    routing::{get, get_service},
+    Router,
+};
+use std::{fs, net::SocketAddr, process::Command};
 use tower_http::services::ServeDir;
 
+/// Console ids, game codes and submission slugs only use these characters, so anything else
+/// can't name a submission and is never passed on to the site generator or used in a path
+fn is_valid_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && segment
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+}
+
+/// Tells listing pages that comparisons are available, so they show the compare button
+async fn compare_available(Path(kind): Path<String>) -> StatusCode {
+    if is_valid_segment(&kind) {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::NOT_FOUND
+    }
+}
+
+/// Generates a comparison page on demand, since there are too many combinations to pre-generate.
+///
+/// Every request runs the site generator with `cargo run`, which loads and processes all
+/// submissions again, so a comparison takes several seconds. In exchange the page always
+/// reflects the current data/ and site code.
+async fn compare(Path((kind, page)): Path<(String, String)>) -> Response {
+    let Some(slugs) = page.strip_suffix(".html") else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let slugs = slugs.split(',').map(str::to_owned).collect::<Vec<_>>();
+    if !is_valid_segment(&kind) || !slugs.iter().all(|slug| is_valid_segment(slug)) {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    let path = format!("build/compare/{kind}/{page}");
+    let result = tokio::task::spawn_blocking(move || -> Result<String, Error> {
+        let output = Command::new("cargo")
+            .args([
+                "run",
+                "--quiet",
+                "--bin",
+                "gbhwdb-site",
+                "--",
+                "compare",
+                &kind,
+            ])
+            .args(&slugs)
+            .output()?;
+        if !output.status.success() {
+            return Err(anyhow!("{}", String::from_utf8_lossy(&output.stderr)));
+        }
+        Ok(fs::read_to_string(path)?)
+    })
+    .await;
+    match result {
+        Ok(Ok(html)) => Html(html).into_response(),
+        Ok(Err(err)) => (StatusCode::NOT_FOUND, err.to_string()).into_response(),
+        Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    }
+}
+
 #[tokio::main]
 async fn main() {
-    let app = Router::new().fallback(get_service(ServeDir::new("build")));
+    let app = Router::new()
+        .route("/compare/:kind/", get(compare_available))
+        .route("/compare/:kind/:page", get(compare))
+        .fallback(get_service(ServeDir::new("build")));
 
     let port = 8080;
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
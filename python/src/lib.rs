@@ -0,0 +1,340 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Python bindings for the label parsers and the submission data model
+//!
+//! Build and install into the active virtualenv with `maturin develop -m python/Cargo.toml`.
+//!
+//! ```python
+//! import gbhwdb
+//!
+//! part = gbhwdb.parse_label("mapper", "MBC5 LZ9GB31 AL23 A")
+//! assert (part.kind, part.year, part.week) == ("MBC5", 2000, 23)
+//!
+//! metadata = gbhwdb.load_metadata("data/consoles/DMG/G01009132/metadata.json")
+//! assert metadata.kind == "DMG"
+//! shell = metadata.to_dict()["shell"]
+//! games = gbhwdb.load_games("config/games.json")
+//! ```
+
+use gbhwdb_backend::{
+    config::cartridge::load_cfgs,
+    diagnostics::{self, DocumentKind, Severity},
+    input,
+    parser::{self, PartFamily, Year},
+    Console,
+};
+use pyo3::{
+    exceptions::{PyOSError, PyValueError},
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::{fs, path::Path};
+
+/// Result of parsing a label
+#[pyclass(get_all, frozen, module = "gbhwdb")]
+#[derive(Clone, Debug)]
+pub struct Identification {
+    /// Part family id, e.g. "mapper"
+    family: &'static str,
+    kind: Option<String>,
+    /// Manufacturer id, e.g. "sharp"
+    manufacturer: Option<&'static str>,
+    rom_code: Option<String>,
+    /// Full year, or None if the label only has a partial year
+    year: Option<u16>,
+    /// Last digit of the year if the label doesn't identify the decade
+    partial_year: Option<u8>,
+    month: Option<u8>,
+    week: Option<u8>,
+    /// Country of origin id, e.g. "japan"
+    country: Option<&'static str>,
+}
+
+#[pymethods]
+impl Identification {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl From<parser::Identification> for Identification {
+    fn from(part: parser::Identification) -> Self {
+        Identification {
+            family: part.family.id(),
+            kind: part.kind,
+            manufacturer: part.manufacturer.map(|manufacturer| manufacturer.id()),
+            rom_code: part.rom_code,
+            year: match part.date_code.year {
+                Some(Year::Full(year)) => Some(year),
+                _ => None,
+            },
+            partial_year: match part.date_code.year {
+                Some(Year::Partial(year)) => Some(year),
+                _ => None,
+            },
+            month: part.date_code.month.map(u8::from),
+            week: part.date_code.week.map(u8::from),
+            country: part.country.map(|country| country.id()),
+        }
+    }
+}
+
+/// Problem found in a metadata document or a label
+#[pyclass(get_all, frozen, module = "gbhwdb")]
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    /// "error" or "warning"
+    severity: &'static str,
+    /// JSON pointer to the problematic value, or an empty string for the whole document
+    pointer: String,
+    message: String,
+}
+
+#[pymethods]
+impl Diagnostic {
+    fn __repr__(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl From<diagnostics::Diagnostic> for Diagnostic {
+    fn from(diagnostic: diagnostics::Diagnostic) -> Self {
+        Diagnostic {
+            severity: match diagnostic.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+            },
+            pointer: diagnostic.pointer,
+            message: diagnostic.message,
+        }
+    }
+}
+
+/// Validated metadata.json document
+#[pyclass(frozen, module = "gbhwdb")]
+#[derive(Clone, Debug)]
+pub struct Metadata {
+    /// Console code (e.g. "DMG") or "cartridge"
+    #[pyo3(get)]
+    kind: &'static str,
+    #[pyo3(get)]
+    slug: String,
+    #[pyo3(get)]
+    contributor: String,
+    /// Labels as (JSON pointer, label) pairs, e.g. ("/mainboard/u1/label", "DMG-CPU B")
+    #[pyo3(get)]
+    labels: Vec<(String, String)>,
+    /// Document as serialized from the input structs
+    document: Value,
+}
+
+#[pymethods]
+impl Metadata {
+    /// The whole document as dicts and lists
+    fn to_dict(&self, py: Python) -> PyResult<PyObject> {
+        to_py(py, &self.document)
+    }
+    fn __repr__(&self) -> String {
+        format!(
+            "Metadata(kind={:?}, slug={:?}, contributor={:?})",
+            self.kind, self.slug, self.contributor
+        )
+    }
+}
+
+/// Parses a document with its input struct, and serializes it back
+fn parse_document<T: DeserializeOwned + Serialize>(json: &str) -> Result<Value, String> {
+    let document = serde_json::from_str::<T>(json).map_err(|err| err.to_string())?;
+    serde_json::to_value(document).map_err(|err| err.to_string())
+}
+
+impl Metadata {
+    fn parse(kind: DocumentKind, json: &str) -> Result<Metadata, String> {
+        let document = match kind {
+            DocumentKind::Console(Console::Dmg) => parse_document::<input::dmg::DmgConsole>(json),
+            DocumentKind::Console(Console::Sgb) => parse_document::<input::sgb::SgbConsole>(json),
+            DocumentKind::Console(Console::Mgb) => parse_document::<input::mgb::MgbConsole>(json),
+            DocumentKind::Console(Console::Mgl) => parse_document::<input::mgl::MglConsole>(json),
+            DocumentKind::Console(Console::Sgb2) => {
+                parse_document::<input::sgb2::Sgb2Console>(json)
+            }
+            DocumentKind::Console(Console::Cgb) => parse_document::<input::cgb::CgbConsole>(json),
+            DocumentKind::Console(Console::Agb) => parse_document::<input::agb::AgbConsole>(json),
+            DocumentKind::Console(Console::Ags) => parse_document::<input::ags::AgsConsole>(json),
+            DocumentKind::Console(Console::Gbs) => parse_document::<input::gbs::GbsConsole>(json),
+            DocumentKind::Console(Console::Oxy) => parse_document::<input::oxy::OxyConsole>(json),
+            DocumentKind::Cartridge => parse_document::<input::cartridge::Cartridge>(json),
+        }?;
+        let labels = diagnostics::document_labels(kind, &document)?
+            .into_iter()
+            .filter_map(|entry| Some((entry.pointer, entry.label?)))
+            .collect();
+        // every input struct has these fields
+        let field = |name: &str| document[name].as_str().unwrap_or_default().to_owned();
+        Ok(Metadata {
+            kind: match kind {
+                DocumentKind::Console(console) => console.code(),
+                DocumentKind::Cartridge => "cartridge",
+            },
+            slug: field("slug"),
+            contributor: field("contributor"),
+            labels,
+            document,
+        })
+    }
+}
+
+fn family(id: &str) -> PyResult<PartFamily> {
+    PartFamily::from_id(id)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown part family {id}")))
+}
+
+fn document_kind(kind: &str) -> PyResult<DocumentKind> {
+    DocumentKind::from_code(kind)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown document kind {kind}")))
+}
+
+/// Infers the document kind from a path like data/consoles/DMG/x/metadata.json
+fn document_kind_from_path(path: &Path) -> Option<DocumentKind> {
+    // data/consoles/DMG or data/cartridges/DMG-TRA-1
+    let dir = path.parent()?.parent()?;
+    if dir.parent()?.file_name()? == "cartridges" {
+        Some(DocumentKind::Cartridge)
+    } else {
+        DocumentKind::from_code(dir.file_name()?.to_str()?)
+    }
+}
+
+fn to_py(py: Python, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(value) => value.to_object(py),
+        Value::Number(number) => match number.as_i64() {
+            Some(value) => value.into_py(py),
+            None => number.as_f64().into_py(py),
+        },
+        Value::String(value) => value.to_object(py),
+        Value::Array(values) => {
+            let list = PyList::empty_bound(py);
+            for value in values {
+                list.append(to_py(py, value)?)?;
+            }
+            list.into_any().unbind()
+        }
+        Value::Object(map) => {
+            let dict = PyDict::new_bound(py);
+            for (key, value) in map {
+                dict.set_item(key, to_py(py, value)?)?;
+            }
+            dict.into_any().unbind()
+        }
+    })
+}
+
+/// Ids of all part families
+#[pyfunction]
+fn families() -> Vec<&'static str> {
+    PartFamily::ALL.iter().map(|family| family.id()).collect()
+}
+
+/// Part families used for each label in console metadata, as (JSON pointer, family ids) pairs
+#[pyfunction]
+fn console_label_slots(console: &str) -> PyResult<Vec<(&'static str, Vec<&'static str>)>> {
+    let console = Console::ALL
+        .into_iter()
+        .find(|c| c.code().eq_ignore_ascii_case(console))
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown console {console}")))?;
+    Ok(console
+        .label_slots()
+        .iter()
+        .map(|slot| {
+            let families = slot.families.iter().map(|family| family.id()).collect();
+            (slot.pointer, families)
+        })
+        .collect())
+}
+
+/// Parses a label with the parsers of one part family
+#[pyfunction]
+fn parse_label(family_id: &str, label: &str) -> PyResult<Identification> {
+    family(family_id)?
+        .identify(label)
+        .map(Identification::from)
+        .map_err(PyValueError::new_err)
+}
+
+/// Identifies a label with every part family, best candidates first
+#[pyfunction]
+fn identify(label: &str) -> Vec<Identification> {
+    parser::identify(label)
+        .into_iter()
+        .map(Identification::from)
+        .collect()
+}
+
+/// Validates a metadata.json document, given as a string
+///
+/// `kind` is a console code (e.g. "DMG") or "cartridge".
+#[pyfunction]
+fn validate_metadata(kind: &str, json: &str) -> PyResult<Vec<Diagnostic>> {
+    Ok(diagnostics::check_metadata(document_kind(kind)?, json)
+        .into_iter()
+        .map(Diagnostic::from)
+        .collect())
+}
+
+/// Loads and validates a metadata.json file
+///
+/// The document kind is inferred from the path if not given. Raises ValueError if the
+/// document has errors.
+#[pyfunction]
+#[pyo3(signature = (path, kind=None))]
+fn load_metadata(path: &str, kind: Option<&str>) -> PyResult<Metadata> {
+    let kind = match kind {
+        Some(kind) => document_kind(kind)?,
+        None => document_kind_from_path(Path::new(path)).ok_or_else(|| {
+            PyValueError::new_err(format!("Can't infer document kind from {path}"))
+        })?,
+    };
+    let json = fs::read_to_string(path).map_err(|err| PyOSError::new_err(err.to_string()))?;
+    let errors = diagnostics::check_metadata(kind, &json)
+        .into_iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .map(|diagnostic| diagnostic.to_string())
+        .collect::<Vec<_>>();
+    if !errors.is_empty() {
+        return Err(PyValueError::new_err(format!(
+            "{path}: {}",
+            errors.join("\n")
+        )));
+    }
+    Metadata::parse(kind, &json).map_err(|err| PyValueError::new_err(format!("{path}: {err}")))
+}
+
+/// Loads games.json as a dict from ROM id (e.g. "DMG-TRA-1") to game configuration
+#[pyfunction]
+#[pyo3(signature = (path="config/games.json"))]
+fn load_games(py: Python, path: &str) -> PyResult<PyObject> {
+    let cfgs = load_cfgs(path).map_err(|err| PyOSError::new_err(err.to_string()))?;
+    let value = serde_json::to_value(cfgs).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    to_py(py, &value)
+}
+
+#[pymodule]
+fn gbhwdb(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Identification>()?;
+    m.add_class::<Diagnostic>()?;
+    m.add_class::<Metadata>()?;
+    m.add_function(wrap_pyfunction!(families, m)?)?;
+    m.add_function(wrap_pyfunction!(console_label_slots, m)?)?;
+    m.add_function(wrap_pyfunction!(parse_label, m)?)?;
+    m.add_function(wrap_pyfunction!(identify, m)?)?;
+    m.add_function(wrap_pyfunction!(validate_metadata, m)?)?;
+    m.add_function(wrap_pyfunction!(load_metadata, m)?)?;
+    m.add_function(wrap_pyfunction!(load_games, m)?)?;
+    Ok(())
+}
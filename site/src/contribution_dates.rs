@@ -0,0 +1,83 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{anyhow, Error};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use time::{Date, Month};
+
+/// Dates when submissions were added to the repository, keyed by submission directory
+#[derive(Clone, Debug, Default)]
+pub struct ContributionDates {
+    dates: HashMap<PathBuf, Date>,
+}
+
+impl ContributionDates {
+    /// Reads the dates from git history.
+    ///
+    /// Shallow clones don't have the full history, so they're treated as an error.
+    pub fn from_git(data_dir: impl AsRef<Path>) -> Result<Self, Error> {
+        let data_dir = data_dir.as_ref();
+        let shallow = git(&["rev-parse", "--is-shallow-repository"])?;
+        if shallow.trim() == "true" {
+            return Err(anyhow!("Git history is not available in a shallow clone"));
+        }
+        let log = git(&[
+            "log",
+            "--diff-filter=A",
+            "--no-renames",
+            "--format=@%as",
+            "--name-only",
+            "--",
+            &data_dir.display().to_string(),
+        ])?;
+        let mut dates = HashMap::new();
+        let mut date = None;
+        for line in log.lines() {
+            if let Some(text) = line.strip_prefix('@') {
+                date = Some(parse_date(text)?);
+            } else if let Some(dir) = line.strip_suffix("/metadata.json") {
+                // Log is in reverse chronological order, so the last date is the earliest one
+                if let Some(date) = date {
+                    dates.insert(PathBuf::from(dir), date);
+                }
+            }
+        }
+        Ok(ContributionDates { dates })
+    }
+    pub fn get(&self, submission_dir: impl AsRef<Path>) -> Option<Date> {
+        self.dates.get(submission_dir.as_ref()).copied()
+    }
+}
+
+fn git(args: &[&str]) -> Result<String, Error> {
+    let output = Command::new("git").args(args).output()?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+fn parse_date(text: &str) -> Result<Date, Error> {
+    let invalid = || anyhow!("Invalid date {text}");
+    let mut parts = text.split('-').map(|part| part.parse::<u16>());
+    let (Some(Ok(year)), Some(Ok(month)), Some(Ok(day)), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    let month = Month::try_from(u8::try_from(month)?)?;
+    Ok(Date::from_calendar_date(
+        i32::from(year),
+        month,
+        u8::try_from(day)?,
+    )?)
+}
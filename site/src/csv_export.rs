@@ -1,11 +1,16 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
 use anyhow::Error;
+use gbhwdb_backend::serial::Serial;
 use std::{borrow::Cow, io, marker::PhantomData};
 
-use crate::{legacy::LegacySubmission, process::part::ProcessedPart, process::DateCode};
+use crate::{
+    legacy::{console::LegacyConsoleMetadata, LegacySubmission},
+    process::part::ProcessedPart,
+    process::DateCode,
+};
 
 mod agb;
 mod ags;
@@ -54,6 +59,70 @@ where
     Ok(())
 }
 
+/// Writes one row per unit with a readable serial, sorted by serial, with the mainboard and chip
+/// revisions of the unit
+pub fn write_serial_csv<W, M, P>(
+    writer: W,
+    url_prefix: &'static str,
+    submissions: &[LegacySubmission<M, P>],
+) -> Result<(), Error>
+where
+    W: io::Write,
+    M: LegacyConsoleMetadata,
+{
+    let mut w = csv::Writer::from_writer(writer);
+
+    let chips = M::parts()
+        .into_iter()
+        .filter(|chip| !chip.hide_type)
+        .collect::<Vec<_>>();
+    let header = [
+        "serial",
+        "prefix",
+        "number",
+        "url",
+        "mainboard",
+        "assembly_date",
+    ]
+    .into_iter()
+    .map(str::to_owned)
+    .chain(
+        chips
+            .iter()
+            .map(|chip| format!("{}_kind", chip.designator.to_lowercase())),
+    );
+    w.write_record(header)?;
+
+    let mut units = submissions
+        .iter()
+        .filter_map(|s| Some((Serial::parse(s.metadata.shell().serial?)?, s)))
+        .collect::<Vec<_>>();
+    units.sort_by_key(|&(serial, _)| serial);
+    for (serial, s) in units {
+        let record = [
+            serial.text.to_owned(),
+            serial.prefix.to_owned(),
+            serial.number.to_string(),
+            format!("{url_prefix}/{}/{}.html", s.code, s.slug),
+            s.metadata.mainboard().kind.to_owned(),
+            s.metadata
+                .assembly_date()
+                .and_then(|date| date.date_code.calendar())
+                .unwrap_or_default(),
+        ]
+        .into_iter()
+        .chain(chips.iter().map(|chip| {
+            (chip.getter)(&s.metadata)
+                .and_then(|part| part.kind.clone())
+                .unwrap_or_default()
+        }));
+        w.write_record(record)?;
+    }
+    w.flush()?;
+
+    Ok(())
+}
+
 fn part() -> Builder<ProcessedPart> {
     Builder::<ProcessedPart>::new()
         .add("kind", |c| (&c.kind).csv())
@@ -61,6 +130,7 @@ fn part() -> Builder<ProcessedPart> {
         .add("manufacturer", |c| {
             (&c.manufacturer).map(|m| m.name()).csv()
         })
+        .add("country", |c| c.country.map(|country| country.name()).csv())
         .add_date_code(|c| c.date_code)
 }
 
@@ -0,0 +1,291 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! JSON export of fully processed submissions.
+//!
+//! Unlike the CSV export, boards and parts keep their nesting, and date codes are exported both
+//! parsed and in the same calendar format as on the site.
+
+use anyhow::Error;
+use gbhwdb_backend::config::cartridge::PartRoleConfig;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::{fs::File, io, path::Path};
+
+use crate::{
+    legacy::{console::*, HasDateCode, LegacyCartridgeSubmission, LegacyPhotos, LegacySubmission},
+    process::{part::ProcessedPart, DateCode},
+};
+
+/// Version of the JSON export format. Bump this whenever existing fields are changed or removed
+pub const SCHEMA_VERSION: u32 = 1;
+
+const SITE_URL: &str = "https://gbhwdb.gekkio.fi";
+
+pub trait ToJson {
+    fn to_json(&self) -> Value;
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn to_json(&self) -> Value {
+        self.as_ref().map(ToJson::to_json).unwrap_or_default()
+    }
+}
+
+impl ToJson for DateCode {
+    fn to_json(&self) -> Value {
+        json!({
+            "calendar": self.calendar(),
+            "year": self.year,
+            "month": self.month,
+            "jun": self.jun,
+            "week": self.week,
+        })
+    }
+}
+
+impl ToJson for ProcessedPart {
+    fn to_json(&self) -> Value {
+        json!({
+            "kind": self.kind,
+            "label": self.label,
+            "manufacturer": self.manufacturer.map(|manufacturer| manufacturer.name()),
+            "country": self.country.map(|country| country.name()),
+            "rom_code": self.rom_code,
+            "date_code": self.date_code.to_json(),
+        })
+    }
+}
+
+impl ToJson for LegacyLcdPanel {
+    fn to_json(&self) -> Value {
+        json!({
+            "label": self.label,
+            "date_code": self.date_code().to_json(),
+            "column_driver": self.column_driver.to_json(),
+            "row_driver": self.row_driver.to_json(),
+        })
+    }
+}
+
+/// A part with the role it has on the board, e.g. "CPU"
+fn board_part(role: &str, part: &ProcessedPart) -> Value {
+    let mut value = part.to_json();
+    value["role"] = json!(role);
+    value
+}
+
+pub trait ConsoleToJson: LegacyConsoleMetadata {
+    /// Adds boards and parts that are not covered by the common console data
+    fn extra_json(&self, _metadata: &mut Value) {}
+}
+
+impl ConsoleToJson for LegacyDmgMetadata {
+    fn extra_json(&self, metadata: &mut Value) {
+        metadata["lcd_board"] = self.lcd_board.as_ref().map_or(Value::Null, |board| {
+            let regulator = board
+                .regulator
+                .as_ref()
+                .map(|part| board_part("LCD bias generator", part));
+            json!({
+                "kind": board.kind,
+                "circled_letters": board.circled_letters,
+                "stamp": board.stamp,
+                "date_code": board.date_code().to_json(),
+                "regulator": regulator,
+            })
+        });
+        metadata["power_board"] = self.power_board.as_ref().map_or(Value::Null, |board| {
+            json!({
+                "kind": board.kind,
+                "label": board.label,
+                "date_code": board.date_code().to_json(),
+            })
+        });
+        metadata["jack_board"] = self.jack_board.as_ref().map_or(Value::Null, |board| {
+            json!({
+                "kind": board.kind,
+                "extra_label": board.extra_label,
+            })
+        });
+    }
+}
+
+impl ConsoleToJson for LegacySgbMetadata {}
+impl ConsoleToJson for LegacyMgbMetadata {}
+impl ConsoleToJson for LegacyMglMetadata {}
+impl ConsoleToJson for LegacySgb2Metadata {}
+impl ConsoleToJson for LegacyCgbMetadata {}
+impl ConsoleToJson for LegacyAgbMetadata {}
+impl ConsoleToJson for LegacyAgsMetadata {}
+impl ConsoleToJson for LegacyOxyMetadata {}
+
+impl ConsoleToJson for LegacyGbsMetadata {}
+
+fn console_metadata<M: ConsoleToJson>(metadata: &M) -> Value {
+    let shell = metadata.shell();
+    let mainboard = metadata.mainboard();
+    let parts = M::parts()
+        .into_iter()
+        .filter_map(|chip| {
+            let part = (chip.getter)(metadata)?;
+            Some((chip.designator.to_owned(), board_part(chip.label, part)))
+        })
+        .collect::<Map<_, _>>();
+    let mut value = json!({
+        "shell": {
+            "serial": shell.serial,
+            "color": shell.color,
+            "release_code": shell.release_code,
+            "stamp": shell.stamp,
+            "date_code": shell.date_code.to_json(),
+        },
+        "mainboard": {
+            "kind": mainboard.kind,
+            "number_pair": mainboard.number_pair,
+            "stamp": mainboard.stamp,
+            "stamp_front": mainboard.stamp_front,
+            "stamp_back": mainboard.stamp_back,
+            "circled_letters": mainboard.circled_letters,
+            "letter_at_top_right": mainboard.letter_at_top_right,
+            "extra_label": mainboard.extra_label,
+            "date_code": mainboard.date_code.to_json(),
+            "parts": parts,
+        },
+        "lcd_panel": metadata.lcd_panel().map(ToJson::to_json),
+    });
+    metadata.extra_json(&mut value);
+    value
+}
+
+fn cartridge_metadata(submission: &LegacyCartridgeSubmission) -> Value {
+    let metadata = &submission.metadata;
+    let board = &metadata.board;
+    let parts = PartRoleConfig::from(board.layout)
+        .into_iter()
+        .filter_map(|(designator, role)| {
+            let part = board.parts.get(&designator)?;
+            Some((
+                designator.as_str().to_owned(),
+                board_part(role.display(), part),
+            ))
+        })
+        .collect::<Map<_, _>>();
+    json!({
+        "game": {
+            "code": submission.code,
+            "name": metadata.cfg.name,
+        },
+        "release_code": metadata.code,
+        "stamp": metadata.stamp,
+        "board": {
+            "kind": board.kind,
+            "circled_letters": board.circled_letters,
+            "panel_position": board.panel_position,
+            "date_code": board.date_code.to_json(),
+            "parts": parts,
+        },
+        "dump": metadata.dump,
+    })
+}
+
+fn submission<M, P: LegacyPhotos>(
+    url_prefix: &str,
+    submission: &LegacySubmission<M, P>,
+    metadata: Value,
+) -> Value {
+    let photos = P::infos()
+        .into_iter()
+        .filter_map(|info| {
+            let photo = (info.getter)(&submission.photos)?;
+            let url = format!(
+                "{SITE_URL}/static/{}/{}_{}",
+                submission.code, submission.slug, photo.name
+            );
+            Some(json!({ "label": info.label, "url": url }))
+        })
+        .collect::<Vec<_>>();
+    let url = format!(
+        "{SITE_URL}/{url_prefix}/{}/{}.html",
+        submission.code, submission.slug
+    );
+    json!({
+        "code": submission.code,
+        "slug": submission.slug,
+        "title": submission.title,
+        "url": url,
+        "contributor": submission.contributor,
+        "outlier": submission.outlier,
+        "metadata": metadata,
+        "photos": photos,
+    })
+}
+
+fn write_submissions<W: io::Write>(writer: W, submissions: Vec<Value>) -> Result<(), Error> {
+    let export = json!({
+        "schema_version": SCHEMA_VERSION,
+        "submissions": submissions,
+    });
+    serde_json::to_writer(writer, &export)?;
+    Ok(())
+}
+
+pub fn write_submission_json<W, M, P>(
+    writer: W,
+    submissions: &[LegacySubmission<M, P>],
+) -> Result<(), Error>
+where
+    W: io::Write,
+    M: ConsoleToJson,
+    P: LegacyPhotos,
+{
+    let submissions = submissions
+        .iter()
+        .map(|s| submission("consoles", s, console_metadata(&s.metadata)))
+        .collect();
+    write_submissions(writer, submissions)
+}
+
+pub fn write_cartridge_json<W: io::Write>(
+    writer: W,
+    submissions: &[LegacyCartridgeSubmission],
+) -> Result<(), Error> {
+    let submissions = submissions
+        .iter()
+        .map(|s| submission("cartridges", s, cartridge_metadata(s)))
+        .collect();
+    write_submissions(writer, submissions)
+}
+
+/// A file under build/static/export that is listed in the manifest
+#[derive(Clone, Debug, Serialize)]
+pub struct ManifestEntry {
+    /// Path relative to the export directory, e.g. "consoles/dmg.json"
+    pub path: String,
+    pub description: &'static str,
+    pub schema_version: u32,
+}
+
+/// Writes manifest.json listing the given exported files in `dir`
+pub fn write_manifest(dir: impl AsRef<Path>, files: &[ManifestEntry]) -> Result<(), Error> {
+    let manifest = json!({ "files": files });
+    serde_json::to_writer_pretty(File::create(dir.as_ref().join("manifest.json"))?, &manifest)?;
+    Ok(())
+}
+
+#[test]
+fn test_date_code_json() {
+    use gbhwdb_backend::time::Week;
+    let date_code = DateCode::from((Some(1998), Week::try_from(37).ok()));
+    assert_eq!(
+        date_code.to_json(),
+        json!({
+            "calendar": "Week 37/1998",
+            "year": 1998,
+            "month": null,
+            "jun": null,
+            "week": 37,
+        })
+    );
+}
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -35,6 +35,8 @@ pub struct LegacySubmission<M, P> {
     pub title: String,
     pub slug: String,
     pub sort_group: Option<String>,
+    /// Some part of the submission is marked as an outlier
+    pub outlier: bool,
     pub contributor: String,
     pub metadata: M,
     pub photos: P,
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -8,13 +8,13 @@ use gbhwdb_backend::{
     parser::{
         accelerometer::accelerometer, crystal_32kihz::crystal_32kihz, eeprom::eeprom, flash::flash,
         hex_inverter::hex_inverter, line_decoder::line_decoder, mapper::mapper, mask_rom::mask_rom,
-        ram::ram, rtc::rtc, supervisor_reset::supervisor_reset, tama::tama,
+        ram::ram, rtc::rtc, supervisor_reset::supervisor_reset, tama::tama, PartFamily,
     },
 };
 use std::collections::HashMap;
 
 use crate::{
-    process::part::{boxed_parser, BoxedParser, ProcessedPart},
+    process::part::{boxed_parser, family_parser, BoxedParser, ProcessedPart},
     process::DateCode,
 };
 
@@ -47,7 +47,13 @@ impl LegacyBoard {
             .filter_map(|(designator, role)| {
                 let parser = part_parser(layout, role);
                 let part = board[designator].as_ref()?;
-                let part = parser(board.year, part).unwrap()?;
+                let part = parser(board.year, part)
+                    .or_else(|err| {
+                        // labels not handled by a hand-written parser may match a label format in config/
+                        let family = PartFamily::for_cartridge_role(layout, role).ok_or(err)?;
+                        family_parser(family)(board.year, part)
+                    })
+                    .unwrap()?;
                 Some((designator, part))
             })
             .collect();
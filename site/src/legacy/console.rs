@@ -1,17 +1,24 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
 use gbhwdb_backend::{
     input::{LcdChip, LcdScreen},
-    parser::{LabelParser, Manufacturer},
+    parser::{
+        lcd_chip::lcd_chip,
+        lcd_screen::lcd_screen,
+        normalize::{parse_normalized, Substitution},
+        LabelParser, Manufacturer,
+    },
     time::{Jun, Month, Week},
     Console,
 };
 
+use std::rc::Rc;
+
 use crate::{
     legacy::{HasDateCode, LegacyMetadata, LegacyPhoto, LegacyPhotos, PhotoInfo, PhotoKind},
-    process::part::ProcessedPart,
+    process::part::{warn_confusions, ProcessedPart},
     process::{to_full_year, DateCode},
 };
 
@@ -30,6 +37,7 @@ pub struct LegacyMainboard<'a> {
 
 #[derive(Clone, Debug, PartialEq, Eq, Default)]
 pub struct LegacyConsoleShell<'a> {
+    pub serial: Option<&'a str>,
     pub color: Option<&'a str>,
     pub release_code: Option<&'a str>,
     pub stamp: Option<&'a str>,
@@ -45,21 +53,66 @@ pub trait LegacyConsoleMetadata: LegacyMetadata {
     fn lcd_panel(&self) -> Option<&LegacyLcdPanel> {
         None
     }
+    /// Properties that group submissions, used for cross-tabulating them
+    fn attributes() -> Vec<AttributeInfo<Self>>
+    where
+        Self: Sized,
+    {
+        default_attributes()
+    }
+    /// Best estimate of when the unit was assembled.
+    ///
+    /// Uses the shell date code if there is one, then the mainboard date code, and finally the
+    /// newest part, since the unit can't have been assembled before all of its parts were made.
+    fn assembly_date(&self) -> Option<AssemblyDate>
+    where
+        Self: Sized,
+    {
+        let shell = self.shell().date_code;
+        let mainboard = self.mainboard().date_code;
+        if shell.year.is_some() {
+            Some(AssemblyDate {
+                date_code: shell,
+                source: "shell",
+            })
+        } else if mainboard.year.is_some() {
+            Some(AssemblyDate {
+                date_code: mainboard,
+                source: "mainboard",
+            })
+        } else {
+            Self::parts()
+                .iter()
+                .filter_map(|chip| (chip.getter)(self))
+                .map(|part| part.date_code)
+                .filter_map(|date_code| Some((date_code.fractional_year()?, date_code)))
+                .max_by(|(a, _), (b, _)| a.total_cmp(b))
+                .map(|(_, date_code)| AssemblyDate {
+                    date_code,
+                    source: "newest part",
+                })
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AssemblyDate {
+    pub date_code: DateCode,
+    /// Where the date comes from, e.g. "shell"
+    pub source: &'static str,
 }
 
+type ChipGetter<M> = Box<dyn Fn(&M) -> Option<&ProcessedPart>>;
+
 pub struct ChipInfo<M: ?Sized> {
     pub label: &'static str,
     pub designator: &'static str,
     pub hide_type: bool,
-    pub getter: Box<dyn Fn(&M) -> Option<&ProcessedPart>>,
+    pub getter: ChipGetter<M>,
 }
 
 impl<M: ?Sized> ChipInfo<M> {
-    pub fn new(
-        label: &'static str,
-        designator: &'static str,
-        getter: Box<dyn Fn(&M) -> Option<&ProcessedPart>>,
-    ) -> Self {
+    pub fn new(label: &'static str, designator: &'static str, getter: ChipGetter<M>) -> Self {
         ChipInfo {
             label,
             designator,
@@ -69,6 +122,56 @@ impl<M: ?Sized> ChipInfo<M> {
     }
 }
 
+/// A property of a submission with a small set of possible values, e.g. the mainboard revision or
+/// the manufacturer of a chip
+type AttributeGetter<M> = Box<dyn Fn(&M) -> Option<&str>>;
+
+pub struct AttributeInfo<M: ?Sized> {
+    pub label: String,
+    pub getter: AttributeGetter<M>,
+}
+
+impl<M: ?Sized> AttributeInfo<M> {
+    pub fn new(label: impl Into<String>, getter: AttributeGetter<M>) -> Self {
+        AttributeInfo {
+            label: label.into(),
+            getter,
+        }
+    }
+}
+
+/// The mainboard, shell color, LCD drivers and the kind and manufacturer of every chip
+pub fn default_attributes<M: LegacyConsoleMetadata>() -> Vec<AttributeInfo<M>> {
+    let mut attributes = vec![
+        AttributeInfo::new("Mainboard", Box::new(|m: &M| Some(m.mainboard().kind))),
+        AttributeInfo::new("Shell color", Box::new(|m: &M| m.shell().color)),
+    ];
+    for chip in M::parts() {
+        let getter = Rc::new(chip.getter);
+        let label = format!("{} ({})", chip.label, chip.designator);
+        if !chip.hide_type {
+            let getter = getter.clone();
+            attributes.push(AttributeInfo::new(
+                label.clone(),
+                Box::new(move |m| getter(m)?.kind.as_deref()),
+            ));
+        }
+        attributes.push(AttributeInfo::new(
+            format!("{label} manufacturer"),
+            Box::new(move |m| Some(getter(m)?.manufacturer?.name())),
+        ));
+    }
+    attributes.push(AttributeInfo::new(
+        "LCD column driver",
+        Box::new(|m: &M| m.lcd_panel()?.column_driver.as_ref()?.kind.as_deref()),
+    ));
+    attributes.push(AttributeInfo::new(
+        "LCD row driver",
+        Box::new(|m: &M| m.lcd_panel()?.row_driver.as_ref()?.kind.as_deref()),
+    ));
+    attributes
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct LegacyDmgPhotos {
     pub front: Option<LegacyPhoto>,
@@ -199,6 +302,7 @@ impl LegacyPhotos for LegacyAgsPhotos {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyDmgMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub year: Option<u16>,
     pub month: Option<Month>,
@@ -253,6 +357,7 @@ impl LegacyConsoleMetadata for LegacyDmgMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             date_code: self.date_code(),
             ..LegacyConsoleShell::default()
@@ -275,6 +380,19 @@ impl LegacyConsoleMetadata for LegacyDmgMetadata {
             .as_ref()
             .and_then(|board| board.lcd_panel.as_ref())
     }
+
+    fn attributes() -> Vec<AttributeInfo<Self>> {
+        let mut attributes = default_attributes();
+        attributes.push(AttributeInfo::new(
+            "LCD board",
+            Box::new(|m: &Self| Some(m.lcd_board.as_ref()?.kind.as_str())),
+        ));
+        attributes.push(AttributeInfo::new(
+            "Power board",
+            Box::new(|m: &Self| Some(m.power_board.as_ref()?.kind.as_str())),
+        ));
+        attributes
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -478,6 +596,7 @@ impl LegacyConsoleMetadata for LegacySgb2Metadata {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyMgbMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub release_code: Option<String>,
     pub year: Option<u16>,
@@ -529,6 +648,7 @@ impl LegacyConsoleMetadata for LegacyMgbMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
             date_code: self.date_code(),
@@ -581,6 +701,7 @@ impl HasDateCode for LegacyMgbMainboard {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyMglMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub release_code: Option<String>,
     pub year: Option<u16>,
@@ -633,6 +754,7 @@ impl LegacyConsoleMetadata for LegacyMglMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
             date_code: self.date_code(),
@@ -686,6 +808,7 @@ impl HasDateCode for LegacyMglMainboard {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyCgbMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub release_code: Option<String>,
     pub year: Option<u16>,
@@ -735,6 +858,7 @@ impl LegacyConsoleMetadata for LegacyCgbMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
             date_code: self.date_code(),
@@ -783,6 +907,7 @@ impl HasDateCode for LegacyCgbMainboard {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyAgbMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub release_code: Option<String>,
     pub year: Option<u16>,
@@ -832,6 +957,7 @@ impl LegacyConsoleMetadata for LegacyAgbMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
             date_code: self.date_code(),
@@ -880,6 +1006,7 @@ impl HasDateCode for LegacyAgbMainboard {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyAgsMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub release_code: Option<String>,
     pub mainboard: LegacyAgsMainboard,
@@ -916,6 +1043,7 @@ impl LegacyConsoleMetadata for LegacyAgsMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
             ..LegacyConsoleShell::default()
@@ -992,7 +1120,7 @@ impl LegacyConsoleMetadata for LegacyGbsMetadata {
             ChipInfo::new("WRAM", "U2", Box::new(|m| m.mainboard.work_ram.as_ref())),
             ChipInfo::new("?", "U4", Box::new(|m| m.mainboard.u4.as_ref())),
             ChipInfo::new("Regulator", "U5", Box::new(|m| m.mainboard.u5.as_ref())),
-            ChipInfo::new("Regulator", "U6", Box::new(|m| m.mainboard.u5.as_ref())),
+            ChipInfo::new("Regulator", "U6", Box::new(|m| m.mainboard.u6.as_ref())),
             ChipInfo {
                 label: "Crystal",
                 designator: "Y1",
@@ -1056,6 +1184,7 @@ impl HasDateCode for LegacyGbsMainboard {
 
 #[derive(Clone, Debug, Default)]
 pub struct LegacyOxyMetadata {
+    pub serial: Option<String>,
     pub color: Option<String>,
     pub release_code: Option<String>,
     pub mainboard: LegacyOxyMainboard,
@@ -1077,6 +1206,7 @@ impl LegacyConsoleMetadata for LegacyOxyMetadata {
 
     fn shell(&self) -> LegacyConsoleShell {
         LegacyConsoleShell {
+            serial: self.serial.as_deref(),
             color: self.color.as_deref(),
             release_code: self.release_code.as_deref(),
             ..LegacyConsoleShell::default()
@@ -1119,6 +1249,8 @@ impl HasDateCode for LegacyOxyMainboard {
 #[derive(Clone, Debug, Default)]
 pub struct LegacyLcdPanel {
     pub label: Option<String>,
+    /// Changes that were needed to parse the label as recorded
+    pub substitutions: Vec<Substitution>,
     pub year: Option<u16>,
     pub month: Option<Month>,
     pub column_driver: Option<ProcessedPart>,
@@ -1139,9 +1271,9 @@ impl HasDateCode for LegacyLcdPanel {
 pub fn to_legacy_lcd_chip(year_hint: Option<u16>, chip: &LcdChip) -> ProcessedPart {
     let ribbon_label = &chip.ribbon_label;
     if let Some(label) = &chip.label {
-        let chip = gbhwdb_backend::parser::lcd_chip::lcd_chip()
-            .parse(&label)
-            .unwrap_or_else(|_| panic!("{}", label));
+        let (chip, normalized) =
+            parse_normalized(lcd_chip(), label).unwrap_or_else(|_| panic!("{}", label));
+        warn_confusions(label, &normalized);
         ProcessedPart {
             label: Some(match &ribbon_label {
                 Some(ribbon_label) => format!("{} {}", ribbon_label, label),
@@ -1156,6 +1288,8 @@ pub fn to_legacy_lcd_chip(year_hint: Option<u16>, chip: &LcdChip) -> ProcessedPa
                 jun: None,
             },
             rom_code: None,
+            country: chip.country,
+            substitutions: normalized.substitutions,
         }
     } else {
         ProcessedPart {
@@ -1177,13 +1311,18 @@ pub fn to_legacy_lcd_panel(year_hint: Option<u16>, screen: &LcdScreen) -> Option
         .as_ref()
         .map(|chip| to_legacy_lcd_chip(year_hint, chip));
     let label = screen.label.clone();
-    let screen = screen.label.as_ref().map(|label| {
-        gbhwdb_backend::parser::lcd_screen::lcd_screen()
-            .parse(label)
-            .unwrap_or_else(|_| panic!("{}", label))
-    });
+    let (screen, substitutions) = match &screen.label {
+        Some(label) => {
+            let (screen, normalized) =
+                parse_normalized(lcd_screen(), label).unwrap_or_else(|_| panic!("{}", label));
+            warn_confusions(label, &normalized);
+            (Some(screen), normalized.substitutions)
+        }
+        None => (None, Vec::new()),
+    };
     Some(LegacyLcdPanel {
         label,
+        substitutions,
         year: screen
             .as_ref()
             .and_then(|screen| to_full_year(year_hint, screen.year)),
@@ -1,36 +1,47 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use anyhow::Error;
-use csv_export::{write_submission_csv, ToCsv};
+use anyhow::{anyhow, Error};
+use csv_export::{write_serial_csv, write_submission_csv, ToCsv};
 use filetime::{set_file_mtime, FileTime};
 use gbhwdb_backend::{
     config::cartridge::*,
     input::cartridge::*,
-    parser::{self, LabelParser},
+    parser::{self, normalize::parse_normalized, LabelParser, RomCode},
+    prediction::{IndexedChip, IndexedUnit, UnitIndex},
     Console,
 };
 use glob::glob;
 use image::{imageops::FilterType, ImageOutputFormat};
+use json_export::{
+    write_cartridge_json, write_manifest, write_submission_json, ConsoleToJson, ManifestEntry,
+    SCHEMA_VERSION,
+};
 use log::{debug, info, warn, LevelFilter};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use simplelog::{ColorChoice, TermLogger, TerminalMode};
 use std::{
     collections::{BTreeMap, HashMap},
+    env,
     fs::{self, create_dir_all, File, Metadata},
     io::{BufWriter, Write},
     path::Path,
 };
 use walkdir::{DirEntry, WalkDir};
 
-use legacy::*;
-use site::{build_site, SubmissionCounts};
+use contribution_dates::ContributionDates;
+use legacy::{console::LegacyConsoleMetadata, *};
+use process::part::warn_confusions;
+use site::{build_site, comparison_page, SubmissionCounts};
 
+mod contribution_dates;
 mod css;
 mod csv_export;
+mod json_export;
 mod legacy;
 mod process;
+mod search;
 mod site;
 mod template;
 
@@ -68,6 +79,7 @@ pub struct SiteData {
     ags: Vec<LegacyAgsSubmission>,
     gbs: Vec<LegacyGbsSubmission>,
     oxy: Vec<LegacyOxySubmission>,
+    contribution_dates: ContributionDates,
 }
 
 impl SiteData {
@@ -170,12 +182,24 @@ fn main() -> Result<(), Error> {
         ColorChoice::Auto,
     );
 
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let comparison = match args.split_first() {
+        None => None,
+        Some((command, [kind, slugs @ ..])) if command == "compare" => Some((kind, slugs)),
+        _ => {
+            return Err(anyhow!(
+                "Usage: gbhwdb-site [compare <console id or game code> <slug> <slug>...]"
+            ))
+        }
+    };
+
     let mut data = SiteData::default();
-    create_dir_all("build/static/export/consoles")?;
 
     info!("Processing submissions");
 
     let cfgs = gbhwdb_backend::config::cartridge::load_cfgs("config/games.json")?;
+    gbhwdb_backend::config::label_format::load_label_formats("config/label-formats.json")?
+        .install()?;
 
     data.cartridges = process_cartridge_submissions(&cfgs)?;
     data.dmg = process_dmg_submissions()?;
@@ -189,6 +213,29 @@ fn main() -> Result<(), Error> {
     data.gbs = process_gbs_submissions()?;
     data.oxy = process_oxy_submissions()?;
     data.cfgs = cfgs;
+    data.contribution_dates = ContributionDates::from_git("data").unwrap_or_else(|err| {
+        warn!("Contribution dates are not available: {err}");
+        ContributionDates::default()
+    });
+
+    if let Some((kind, slugs)) = comparison {
+        let slugs = slugs.iter().map(String::as_str).collect::<Vec<_>>();
+        let (path, page) = comparison_page(&data, kind, &slugs)?;
+        let target_file = path.join("build");
+        if let Some(parent) = target_file.parent() {
+            create_dir_all(parent)?;
+        }
+        fs::write(&target_file, page.generate()?)?;
+        info!("Wrote comparison to {}", target_file.display());
+        return Ok(());
+    }
+
+    info!("Writing exports");
+
+    let exports = write_exports(&data)?;
+    let index = BufWriter::new(File::create("build/static/search-index.json")?);
+    search::write_search_index(index, &data)?;
+    write_manifest("build/static/export", &exports)?;
 
     info!("Processing photos");
 
@@ -215,17 +262,186 @@ fn main() -> Result<(), Error> {
     Ok(())
 }
 
+/// Writes the downloadable CSV and JSON exports of all submissions and returns their manifest
+/// entries
+fn write_exports(data: &SiteData) -> Result<Vec<ManifestEntry>, Error> {
+    create_dir_all("build/static/export/consoles")?;
+    let mut files = Vec::new();
+
+    let csv = BufWriter::new(File::create("build/static/export/cartridges.csv")?);
+    write_submission_csv(csv, "https://gbhwdb.gekkio/cartridges", &data.cartridges)?;
+    let json = BufWriter::new(File::create("build/static/export/cartridges.json")?);
+    write_cartridge_json(json, &data.cartridges)?;
+    files.push(ManifestEntry {
+        path: "cartridges.csv".to_owned(),
+        description: "Submissions",
+        schema_version: 1,
+    });
+    files.push(ManifestEntry {
+        path: "cartridges.json".to_owned(),
+        description: "Processed submissions",
+        schema_version: SCHEMA_VERSION,
+    });
+
+    files.push(write_console_submission_csv("dmg", &data.dmg)?);
+    files.push(write_console_submission_json("dmg", &data.dmg)?);
+    files.push(write_console_unit_index("dmg", &data.dmg)?);
+    files.push(write_console_serial_csv("dmg", &data.dmg)?);
+
+    files.push(write_console_submission_csv("sgb", &data.sgb)?);
+    files.push(write_console_submission_json("sgb", &data.sgb)?);
+    files.push(write_console_unit_index("sgb", &data.sgb)?);
+
+    files.push(write_console_submission_csv("mgb", &data.mgb)?);
+    files.push(write_console_submission_json("mgb", &data.mgb)?);
+    files.push(write_console_unit_index("mgb", &data.mgb)?);
+    files.push(write_console_serial_csv("mgb", &data.mgb)?);
+
+    files.push(write_console_submission_csv("mgl", &data.mgl)?);
+    files.push(write_console_submission_json("mgl", &data.mgl)?);
+    files.push(write_console_unit_index("mgl", &data.mgl)?);
+    files.push(write_console_serial_csv("mgl", &data.mgl)?);
+
+    files.push(write_console_submission_csv("sgb2", &data.sgb2)?);
+    files.push(write_console_submission_json("sgb2", &data.sgb2)?);
+    files.push(write_console_unit_index("sgb2", &data.sgb2)?);
+
+    files.push(write_console_submission_csv("cgb", &data.cgb)?);
+    files.push(write_console_submission_json("cgb", &data.cgb)?);
+    files.push(write_console_unit_index("cgb", &data.cgb)?);
+    files.push(write_console_serial_csv("cgb", &data.cgb)?);
+
+    files.push(write_console_submission_csv("agb", &data.agb)?);
+    files.push(write_console_submission_json("agb", &data.agb)?);
+    files.push(write_console_unit_index("agb", &data.agb)?);
+    files.push(write_console_serial_csv("agb", &data.agb)?);
+
+    files.push(write_console_submission_csv("ags", &data.ags)?);
+    files.push(write_console_submission_json("ags", &data.ags)?);
+    files.push(write_console_unit_index("ags", &data.ags)?);
+    files.push(write_console_serial_csv("ags", &data.ags)?);
+
+    files.push(write_console_submission_csv("gbs", &data.gbs)?);
+    files.push(write_console_submission_json("gbs", &data.gbs)?);
+    files.push(write_console_unit_index("gbs", &data.gbs)?);
+
+    files.push(write_console_submission_csv("oxy", &data.oxy)?);
+    files.push(write_console_submission_json("oxy", &data.oxy)?);
+    files.push(write_console_unit_index("oxy", &data.oxy)?);
+    files.push(write_console_serial_csv("oxy", &data.oxy)?);
+    Ok(files)
+}
+
 fn write_console_submission_csv<M, P>(
     kind: &'static str,
     submissions: &[LegacySubmission<M, P>],
-) -> Result<(), Error>
+) -> Result<ManifestEntry, Error>
 where
     M: ToCsv,
 {
     let csv = BufWriter::new(File::create(format!(
         "build/static/export/consoles/{kind}.csv"
     ))?);
-    write_submission_csv(csv, "https://gbhwdb.gekkio/consoles", submissions)
+    write_submission_csv(csv, "https://gbhwdb.gekkio/consoles", submissions)?;
+    Ok(ManifestEntry {
+        path: format!("consoles/{kind}.csv"),
+        description: "Submissions",
+        schema_version: 1,
+    })
+}
+
+fn write_console_submission_json<M, P>(
+    kind: &'static str,
+    submissions: &[LegacySubmission<M, P>],
+) -> Result<ManifestEntry, Error>
+where
+    M: ConsoleToJson,
+    P: LegacyPhotos,
+{
+    let json = BufWriter::new(File::create(format!(
+        "build/static/export/consoles/{kind}.json"
+    ))?);
+    write_submission_json(json, submissions)?;
+    Ok(ManifestEntry {
+        path: format!("consoles/{kind}.json"),
+        description: "Processed submissions",
+        schema_version: SCHEMA_VERSION,
+    })
+}
+
+fn write_console_serial_csv<M, P>(
+    kind: &'static str,
+    submissions: &[LegacySubmission<M, P>],
+) -> Result<ManifestEntry, Error>
+where
+    M: LegacyConsoleMetadata,
+{
+    let csv = BufWriter::new(File::create(format!(
+        "build/static/export/consoles/{kind}-serials.csv"
+    ))?);
+    write_serial_csv(csv, "https://gbhwdb.gekkio/consoles", submissions)?;
+    Ok(ManifestEntry {
+        path: format!("consoles/{kind}-serials.csv"),
+        description: "Serial numbers and revisions",
+        schema_version: 1,
+    })
+}
+
+/// Writes the hardware of every unit for the "what's inside my unit" lookup
+fn write_console_unit_index<M, P>(
+    kind: &'static str,
+    submissions: &[LegacySubmission<M, P>],
+) -> Result<ManifestEntry, Error>
+where
+    M: LegacyConsoleMetadata,
+{
+    let parts = M::parts();
+    let index = UnitIndex {
+        console: kind.to_owned(),
+        units: submissions
+            .iter()
+            .map(|submission| {
+                let shell = submission.metadata.shell();
+                IndexedUnit {
+                    slug: submission.slug.clone(),
+                    title: submission.title.clone(),
+                    serial: shell.serial.map(str::to_owned),
+                    color: shell.color.map(str::to_owned),
+                    release_code: shell.release_code.map(str::to_owned),
+                    stamp: shell.stamp.map(str::to_owned),
+                    mainboard: submission.metadata.mainboard().kind.to_owned(),
+                    chips: parts
+                        .iter()
+                        .filter(|chip| !chip.hide_type)
+                        .filter_map(|chip| {
+                            let part = (chip.getter)(&submission.metadata)?;
+                            Some(IndexedChip {
+                                label: chip.label.to_owned(),
+                                designator: chip.designator.to_owned(),
+                                kind: part.kind.clone()?,
+                            })
+                        })
+                        .collect(),
+                }
+            })
+            .collect(),
+    };
+    let file = BufWriter::new(File::create(format!(
+        "build/static/export/consoles/{kind}-units.json"
+    ))?);
+    serde_json::to_writer(file, &index)?;
+    Ok(ManifestEntry {
+        path: format!("consoles/{kind}-units.json"),
+        description: "Unit index for the hardware lookup",
+        schema_version: 1,
+    })
+}
+
+/// Parses a board stamp, accepting the same transcription variants as part labels
+fn parse_stamp<T>(f: &impl LabelParser<T>, stamp: &str) -> T {
+    let (parsed, normalized) = parse_normalized(f, stamp).unwrap_or_else(|_| panic!("{}", stamp));
+    warn_confusions(stamp, &normalized);
+    parsed
 }
 
 fn process_cartridge_submissions(
@@ -240,6 +456,7 @@ fn process_cartridge_submissions(
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let cartridge: Cartridge = serde_json::from_reader(file)?;
+            let outlier = cartridge.shell.outlier || cartridge.board.outlier;
             assert_eq!(
                 Some(cartridge.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -270,6 +487,29 @@ fn process_cartridge_submissions(
             }
 
             let board = LegacyBoard::new(cartridge.board, layout);
+            let rom_code = PartRoleConfig::from(layout)
+                .into_iter()
+                .filter(|(_, role)| *role == PartRole::Rom)
+                .find_map(|(designator, _)| board.parts.get(&designator)?.rom_code.as_ref());
+            if let Some(rom_code) = rom_code {
+                if !cfgs.contains_key(rom_code) {
+                    warn!(
+                        "ROM code {rom_code} is not in games.json: {}",
+                        cartridge.code
+                    );
+                }
+                let mismatch = rom_code
+                    .parse::<RomCode>()
+                    .ok()
+                    .zip(cartridge.code.parse::<RomCode>().ok())
+                    .and_then(|(rom_code, code)| rom_code.mismatch(&code));
+                if let Some(mismatch) = mismatch {
+                    warn!(
+                        "ROM code {rom_code} doesn't match cartridge code ({mismatch:?}): {}",
+                        cartridge.code
+                    );
+                }
+            }
             let metadata = LegacyMetadata {
                 cfg: cfg.clone(),
                 code: cartridge.shell.code,
@@ -286,6 +526,7 @@ fn process_cartridge_submissions(
                 title: format!("Entry #{}", cartridge.index),
                 slug: cartridge.slug,
                 sort_group: None,
+                outlier,
                 contributor: cartridge.contributor,
                 metadata,
                 photos,
@@ -293,8 +534,6 @@ fn process_cartridge_submissions(
         }
     }
     submissions.sort_by_key(|submission| (submission.code.clone(), submission.slug.clone()));
-    let csv = BufWriter::new(File::create("build/static/export/cartridges.csv")?);
-    write_submission_csv(csv, "https://gbhwdb.gekkio/cartridges", &submissions)?;
     Ok(submissions)
 }
 
@@ -414,23 +653,16 @@ fn process_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
                 .stamp
                 .as_ref()
                 .filter(|_| !console.mainboard.outlier)
-                .map(|stamp| {
-                    gbhwdb_backend::parser::dmg_stamp::dmg_stamp()
-                        .parse(&stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .map(|stamp| parse_stamp(gbhwdb_backend::parser::dmg_stamp::dmg_stamp(), stamp));
             let lcd_board_stamp = console
                 .lcd_board
                 .as_ref()
                 .and_then(|board| board.stamp.as_ref().filter(|_| !board.outlier))
-                .map(|stamp| {
-                    gbhwdb_backend::parser::dmg_stamp::dmg_stamp()
-                        .parse(&stamp)
-                        .unwrap_or_else(|_| panic!("{}", stamp))
-                });
+                .map(|stamp| parse_stamp(gbhwdb_backend::parser::dmg_stamp::dmg_stamp(), stamp));
             let stamp = mainboard_stamp.or(lcd_board_stamp);
 
             let metadata = LegacyDmgMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 year: stamp
                     .as_ref()
@@ -494,6 +726,7 @@ fn process_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
                     })
                     .to_owned(),
                 ),
+                outlier: has_outliers,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -501,7 +734,6 @@ fn process_dmg_submissions() -> Result<Vec<LegacyDmgSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("dmg", &submissions)?;
     Ok(submissions)
 }
 
@@ -517,6 +749,7 @@ fn process_sgb_submissions() -> Result<Vec<LegacySgbSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: SgbConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -562,6 +795,7 @@ fn process_sgb_submissions() -> Result<Vec<LegacySgbSubmission>, Error> {
                 title: format!("Unit #{}", console.index),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -569,7 +803,6 @@ fn process_sgb_submissions() -> Result<Vec<LegacySgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("sgb", &submissions)?;
     Ok(submissions)
 }
 
@@ -586,6 +819,7 @@ fn process_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: MgbConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -624,13 +858,13 @@ fn process_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
             };
             let lcd_panel = to_legacy_lcd_panel(year_hint, &console.screen);
 
-            let stamp = console.mainboard.stamp.as_ref().map(|stamp| {
-                gbhwdb_backend::parser::dmg_stamp::dmg_stamp()
-                    .parse(&stamp)
-                    .unwrap_or_else(|_| panic!("{}", stamp))
-            });
+            let stamp =
+                console.mainboard.stamp.as_ref().map(|stamp| {
+                    parse_stamp(gbhwdb_backend::parser::dmg_stamp::dmg_stamp(), stamp)
+                });
 
             let metadata = LegacyMgbMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 release_code: console.shell.release_code.clone(),
                 year: stamp
@@ -655,6 +889,7 @@ fn process_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
                     .unwrap_or_else(|| format!("Unit #{}", console.index.unwrap())),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -662,7 +897,6 @@ fn process_mgb_submissions() -> Result<Vec<LegacyMgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("mgb", &submissions)?;
     Ok(submissions)
 }
 
@@ -679,6 +913,7 @@ fn process_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: MglConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -723,13 +958,13 @@ fn process_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
             };
             let lcd_panel = to_legacy_lcd_panel(year_hint, &console.screen);
 
-            let stamp = console.mainboard.stamp.as_ref().map(|stamp| {
-                gbhwdb_backend::parser::cgb_stamp::cgb_stamp()
-                    .parse(&stamp)
-                    .unwrap_or_else(|_| panic!("{}", stamp))
-            });
+            let stamp =
+                console.mainboard.stamp.as_ref().map(|stamp| {
+                    parse_stamp(gbhwdb_backend::parser::cgb_stamp::cgb_stamp(), stamp)
+                });
 
             let metadata = LegacyMglMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 release_code: console.shell.release_code.clone(),
                 year: stamp
@@ -754,6 +989,7 @@ fn process_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
                     .unwrap_or_else(|| format!("Unit #{}", console.index.unwrap())),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -761,7 +997,6 @@ fn process_mgl_submissions() -> Result<Vec<LegacyMglSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("mgl", &submissions)?;
     Ok(submissions)
 }
 
@@ -777,6 +1012,7 @@ fn process_sgb2_submissions() -> Result<Vec<LegacySgb2Submission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: Sgb2Console = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -828,6 +1064,7 @@ fn process_sgb2_submissions() -> Result<Vec<LegacySgb2Submission>, Error> {
                 title: format!("Unit #{}", console.index),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -835,7 +1072,6 @@ fn process_sgb2_submissions() -> Result<Vec<LegacySgb2Submission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("sgb2", &submissions)?;
     Ok(submissions)
 }
 
@@ -852,6 +1088,7 @@ fn process_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: CgbConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -889,21 +1126,19 @@ fn process_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
                 Some(stamp) => {
                     if stamp.starts_with(&['6', '7', '8', '9'][..]) {
                         (
-                            Some(
-                                gbhwdb_backend::parser::dmg_stamp::dmg_stamp()
-                                    .parse(&stamp)
-                                    .unwrap_or_else(|_| panic!("{}", stamp)),
-                            ),
+                            Some(parse_stamp(
+                                gbhwdb_backend::parser::dmg_stamp::dmg_stamp(),
+                                stamp,
+                            )),
                             None,
                         )
                     } else {
                         (
                             None,
-                            Some(
-                                gbhwdb_backend::parser::cgb_stamp::cgb_stamp()
-                                    .parse(&stamp)
-                                    .unwrap_or_else(|_| panic!("{}", stamp)),
-                            ),
+                            Some(parse_stamp(
+                                gbhwdb_backend::parser::cgb_stamp::cgb_stamp(),
+                                stamp,
+                            )),
                         )
                     }
                 }
@@ -915,6 +1150,7 @@ fn process_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
                 .or(old_stamp.as_ref().and_then(|stamp| stamp.year));
 
             let metadata = LegacyCgbMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 release_code: console.shell.release_code.clone(),
                 year: to_full_year(year_hint, stamp_year),
@@ -937,6 +1173,7 @@ fn process_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
                     .unwrap_or_else(|| format!("Unit #{}", console.index.unwrap())),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -944,7 +1181,6 @@ fn process_cgb_submissions() -> Result<Vec<LegacyCgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("cgb", &submissions)?;
     Ok(submissions)
 }
 
@@ -961,6 +1197,7 @@ fn process_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: AgbConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1007,13 +1244,13 @@ fn process_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
                 u4,
             };
 
-            let stamp = console.mainboard.stamp.as_ref().map(|stamp| {
-                gbhwdb_backend::parser::cgb_stamp::cgb_stamp()
-                    .parse(&stamp)
-                    .unwrap_or_else(|_| panic!("{}", stamp))
-            });
+            let stamp =
+                console.mainboard.stamp.as_ref().map(|stamp| {
+                    parse_stamp(gbhwdb_backend::parser::cgb_stamp::cgb_stamp(), stamp)
+                });
 
             let metadata = LegacyAgbMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 release_code: console.shell.release_code.clone(),
                 year: stamp
@@ -1037,6 +1274,7 @@ fn process_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
                     .unwrap_or_else(|| format!("Unit #{}", console.index.unwrap())),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -1044,7 +1282,6 @@ fn process_agb_submissions() -> Result<Vec<LegacyAgbSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("agb", &submissions)?;
     Ok(submissions)
 }
 
@@ -1060,6 +1297,7 @@ fn process_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: AgsConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1119,6 +1357,7 @@ fn process_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
             };
 
             let metadata = LegacyAgsMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 release_code: console.shell.release_code.clone(),
                 mainboard,
@@ -1139,6 +1378,7 @@ fn process_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
                     .unwrap_or_else(|| format!("Unit #{}", console.index.unwrap())),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -1146,7 +1386,6 @@ fn process_ags_submissions() -> Result<Vec<LegacyAgsSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("ags", &submissions)?;
     Ok(submissions)
 }
 
@@ -1163,6 +1402,7 @@ fn process_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: GbsConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1204,11 +1444,10 @@ fn process_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
                 u6,
             };
 
-            let stamp = console.mainboard.stamp.as_ref().map(|stamp| {
-                gbhwdb_backend::parser::cgb_stamp::cgb_stamp()
-                    .parse(&stamp)
-                    .unwrap_or_else(|_| panic!("{}", stamp))
-            });
+            let stamp =
+                console.mainboard.stamp.as_ref().map(|stamp| {
+                    parse_stamp(gbhwdb_backend::parser::cgb_stamp::cgb_stamp(), stamp)
+                });
 
             let metadata = LegacyGbsMetadata {
                 color: console.shell.color.map(|c| format!("{:?}", c)),
@@ -1230,6 +1469,7 @@ fn process_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
                 title: format!("Unit #{}", console.index),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -1237,7 +1477,6 @@ fn process_gbs_submissions() -> Result<Vec<LegacyGbsSubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("gbs", &submissions)?;
     Ok(submissions)
 }
 
@@ -1253,6 +1492,7 @@ fn process_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
             debug!("{}", entry.path().display());
             let file = File::open(&entry.path())?;
             let console: OxyConsole = serde_json::from_reader(file)?;
+            let outlier = console.shell.outlier || console.mainboard.outlier;
             assert_eq!(
                 Some(console.slug.as_str()),
                 root.file_name().and_then(|name| name.to_str())
@@ -1286,6 +1526,7 @@ fn process_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
             };
 
             let metadata = LegacyOxyMetadata {
+                serial: console.shell.serial.clone(),
                 color: console.shell.color.map(|c| format!("{:?}", c)),
                 release_code: console.shell.release_code.clone(),
                 mainboard,
@@ -1305,6 +1546,7 @@ fn process_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
                     .unwrap_or_else(|| format!("Unit #{}", console.index.unwrap())),
                 slug: console.slug,
                 sort_group: None,
+                outlier,
                 contributor: console.contributor,
                 metadata,
                 photos,
@@ -1312,13 +1554,14 @@ fn process_oxy_submissions() -> Result<Vec<LegacyOxySubmission>, Error> {
         }
     }
     submissions.sort_by_key(|submission| (submission.sort_group.clone(), submission.slug.clone()));
-    write_console_submission_csv("oxy", &submissions)?;
     Ok(submissions)
 }
 
 fn copy_static_files() -> Result<(), Error> {
-    static PATTERNS: [&str; 8] = [
+    static PATTERNS: [&str; 10] = [
         "site/static/**/*.html",
+        "site/static/**/*.js",
+        "site/static/**/*.pdf",
         "site/static/**/*.txt",
         "site/static/**/*.ico",
         "site/static/**/*.jpg",
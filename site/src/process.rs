@@ -7,7 +7,9 @@ use gbhwdb_backend::{
     time::{Jun, Month, Week},
 };
 
+pub mod facet;
 pub mod part;
+pub mod serial;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub struct DateCode {
@@ -32,6 +34,17 @@ impl DateCode {
             ..DateCode::default()
         }
     }
+    /// Approximate date as a fractional year, e.g. 1990.04 for January 1990
+    ///
+    /// The middle of the month, week or year is used, depending on the precision of the date code.
+    pub fn fractional_year(&self) -> Option<f64> {
+        let year = f64::from(self.year?);
+        Some(match (self.month, self.week) {
+            (Some(month), _) => year + (f64::from(u8::from(month)) - 0.5) / 12.0,
+            (None, Some(week)) => year + (f64::from(u8::from(week)) - 0.5) / 53.0,
+            (None, None) => year + 0.5,
+        })
+    }
     pub fn calendar(&self) -> Option<String> {
         match (self.year, self.month, self.week) {
             (Some(year), Some(month), _) => match self.jun {
@@ -0,0 +1,168 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use itertools::Itertools;
+use std::collections::BTreeMap;
+
+use crate::legacy::{
+    cartridge, console::LegacyConsoleMetadata, LegacyCartridgeSubmission, LegacyDefaultPhotos,
+    LegacyPhotos, LegacySubmission,
+};
+
+/// Region codes used in release codes
+const REGIONS: &[&str] = &[
+    "AUS", "CAN", "CHN", "EUR", "FAH", "FRA", "FRG", "HKG", "HOL", "ITA", "JPN", "KOR", "NOE",
+    "SCN", "UKV", "USA",
+];
+
+/// Region of a release code, e.g. "JPN" for "C/CGB-JPN-2"
+pub fn region(release_code: &str) -> Option<&'static str> {
+    release_code
+        .split('-')
+        .skip(1)
+        .find_map(|segment| REGIONS.iter().find(|&&region| segment.starts_with(region)))
+        .copied()
+}
+
+type FacetGetter<M, P> = Box<dyn Fn(&LegacySubmission<M, P>) -> Vec<String>>;
+
+/// A property that listings can be filtered by
+pub struct Facet<M, P> {
+    /// Key of the facet in the listing data, e.g. "color"
+    pub key: &'static str,
+    pub label: &'static str,
+    /// All values of the facet for one submission. Empty if the value is not known
+    pub getter: FacetGetter<M, P>,
+}
+
+impl<M, P> Facet<M, P> {
+    fn new(
+        key: &'static str,
+        label: &'static str,
+        getter: impl Fn(&LegacySubmission<M, P>) -> Vec<String> + 'static,
+    ) -> Self {
+        Facet {
+            key,
+            label,
+            getter: Box::new(getter),
+        }
+    }
+}
+
+/// Shell color, mainboard revision, CPU kind, year, region, manufacturer and outlier status
+pub fn console_facets<M: LegacyConsoleMetadata, P: LegacyPhotos>() -> Vec<Facet<M, P>> {
+    let parts = M::parts();
+    let cpu = M::parts().into_iter().next();
+    let cpu_label = cpu.as_ref().map(|chip| chip.label).unwrap_or("CPU");
+    vec![
+        Facet::new("color", "Shell color", |s: &LegacySubmission<M, P>| {
+            s.metadata
+                .shell()
+                .color
+                .map(str::to_owned)
+                .into_iter()
+                .collect()
+        }),
+        Facet::new("mainboard", "Mainboard", |s: &LegacySubmission<M, P>| {
+            vec![s.metadata.mainboard().kind.to_owned()]
+        }),
+        Facet::new("cpu", cpu_label, move |s: &LegacySubmission<M, P>| {
+            cpu.as_ref()
+                .and_then(|chip| (chip.getter)(&s.metadata))
+                .and_then(|part| part.kind.clone())
+                .into_iter()
+                .collect()
+        }),
+        Facet::new("year", "Year", |s: &LegacySubmission<M, P>| {
+            // the shell is assembled last, so the mainboard date is only a fallback
+            let metadata = &s.metadata;
+            metadata
+                .shell()
+                .date_code
+                .year
+                .or(metadata.mainboard().date_code.year)
+                .map(|year| year.to_string())
+                .into_iter()
+                .collect()
+        }),
+        Facet::new("region", "Region", |s: &LegacySubmission<M, P>| {
+            s.metadata
+                .shell()
+                .release_code
+                .and_then(region)
+                .map(str::to_owned)
+                .into_iter()
+                .collect()
+        }),
+        Facet::new(
+            "manufacturer",
+            "Chip manufacturer",
+            move |s: &LegacySubmission<M, P>| {
+                parts
+                    .iter()
+                    .filter_map(|chip| (chip.getter)(&s.metadata)?.manufacturer)
+                    .map(|manufacturer| manufacturer.name().to_owned())
+                    .unique()
+                    .collect()
+            },
+        ),
+        Facet::new("outlier", "Outlier", |s: &LegacySubmission<M, P>| {
+            vec![if s.outlier { "Yes" } else { "No" }.to_owned()]
+        }),
+    ]
+}
+
+/// Board type, year and manufacturer
+pub fn cartridge_facets() -> Vec<Facet<cartridge::LegacyMetadata, LegacyDefaultPhotos>> {
+    vec![
+        Facet::new("board", "Board", |s: &LegacyCartridgeSubmission| {
+            vec![s.metadata.board.kind.clone()]
+        }),
+        Facet::new("year", "Year", |s: &LegacyCartridgeSubmission| {
+            s.metadata
+                .board
+                .date_code
+                .year
+                .map(|year| year.to_string())
+                .into_iter()
+                .collect()
+        }),
+        Facet::new(
+            "manufacturer",
+            "Chip manufacturer",
+            |s: &LegacyCartridgeSubmission| {
+                s.metadata
+                    .board
+                    .parts
+                    .values()
+                    .filter_map(|part| part.manufacturer)
+                    .map(|manufacturer| manufacturer.name().to_owned())
+                    .unique()
+                    .collect()
+            },
+        ),
+        Facet::new("outlier", "Outlier", |s: &LegacyCartridgeSubmission| {
+            vec![if s.outlier { "Yes" } else { "No" }.to_owned()]
+        }),
+    ]
+}
+
+/// Facet values of one submission, keyed by facet
+pub fn facet_values<'a, M, P>(
+    facets: &'a [Facet<M, P>],
+    submission: &LegacySubmission<M, P>,
+) -> BTreeMap<&'a str, Vec<String>> {
+    facets
+        .iter()
+        .map(|facet| (facet.key, (facet.getter)(submission)))
+        .collect()
+}
+
+#[test]
+fn test_region() {
+    assert_eq!(region("CGB-JPN"), Some("JPN"));
+    assert_eq!(region("C/CGB-JPN-2"), Some("JPN"));
+    assert_eq!(region("C/AGT-AUS(E4)-2"), Some("AUS"));
+    assert_eq!(region("C/L-DOL-A-GP"), None);
+}
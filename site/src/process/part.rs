@@ -6,9 +6,16 @@ use anyhow::{anyhow, Error};
 use gbhwdb_backend::{
     input::Part,
     parser,
-    parser::{LabelParser, Manufacturer},
+    parser::{
+        normalize::{
+            normalize_with, parse_normalized, NormalizedLabel, Substitution, SubstitutionKind,
+        },
+        ChipSpec, Country, LabelParser, Manufacturer, PartFamily,
+    },
 };
 
+use log::warn;
+
 use crate::{process::to_full_year, process::DateCode};
 
 #[derive(Clone, Debug, Eq, PartialEq, Default)]
@@ -18,6 +25,28 @@ pub struct ProcessedPart {
     pub manufacturer: Option<Manufacturer>,
     pub date_code: DateCode,
     pub rom_code: Option<String>,
+    pub country: Option<Country>,
+    /// Changes that were needed to parse the label as recorded
+    pub substitutions: Vec<Substitution>,
+}
+
+impl ProcessedPart {
+    /// Capacity, bus width, speed grade and package decoded from the part number
+    pub fn spec(&self) -> Option<ChipSpec> {
+        self.kind.as_deref().and_then(ChipSpec::from_part_number)
+    }
+}
+
+/// Logs character confusions that were resolved while parsing a label.
+///
+/// Confusions are accepted so that a misread character doesn't fail the build, but they may as
+/// well hide a real transcription error in data/, so every one of them is reported.
+pub fn warn_confusions(label: &str, normalized: &NormalizedLabel) {
+    for substitution in &normalized.substitutions {
+        if substitution.kind == SubstitutionKind::Confusion {
+            warn!("Read {label:?} as {:?} ({substitution})", normalized.text);
+        }
+    }
 }
 
 pub trait ParsedPart {
@@ -44,10 +73,31 @@ pub fn boxed_parser<'a, T: ParsedPart, F: LabelParser<T>>(f: &'a F) -> BoxedPars
         part.label
             .as_ref()
             .map(|label| {
-                let part = f
-                    .parse(label)
+                let (part, normalized) = parse_normalized(f, label)
+                    .map_err(|label| anyhow!("Failed to parse {label}"))?;
+                warn_confusions(label, &normalized);
+                Ok(ProcessedPart {
+                    substitutions: normalized.substitutions,
+                    ..part.process(year_hint, label.clone())
+                })
+            })
+            .transpose()
+    })
+}
+
+/// Parser that identifies labels with the family, including the label formats in config/
+pub fn family_parser(family: PartFamily) -> BoxedParser<'static> {
+    Box::new(move |year_hint, part| {
+        part.label
+            .as_ref()
+            .map(|label| {
+                let (part, normalized) = normalize_with(label, |label| family.identify(label))
                     .map_err(|label| anyhow!("Failed to parse {label}"))?;
-                Ok(part.process(year_hint, label.clone()))
+                warn_confusions(label, &normalized);
+                Ok(ProcessedPart {
+                    substitutions: normalized.substitutions,
+                    ..part.process(year_hint, label.clone())
+                })
             })
             .transpose()
     })
@@ -55,23 +105,12 @@ pub fn boxed_parser<'a, T: ParsedPart, F: LabelParser<T>>(f: &'a F) -> BoxedPars
 
 impl ParsedPart for parser::Gen1Soc {
     fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
-        use gbhwdb_backend::parser::Gen1SocKind::*;
         ProcessedPart {
             label: Some(label),
-            kind: Some(
-                match self.kind {
-                    Dmg0 => "DMG-CPU",
-                    DmgA => "DMG-CPU A",
-                    DmgB => "DMG-CPU B",
-                    DmgC => "DMG-CPU C",
-                    DmgBlobB => "DMG-CPU B (blob)",
-                    DmgBlobC => "DMG-CPU C (blob)",
-                    Sgb => "SGB-CPU 01",
-                }
-                .to_owned(),
-            ),
+            kind: Some(self.kind.display_name().to_owned()),
             manufacturer: Some(Manufacturer::Sharp),
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -79,18 +118,12 @@ impl ParsedPart for parser::Gen1Soc {
 
 impl ParsedPart for parser::Gen2Soc {
     fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
-        use gbhwdb_backend::parser::Gen2SocKind::*;
         ProcessedPart {
             label: Some(label),
-            kind: Some(
-                match self.kind {
-                    Mgb => "CPU MGB",
-                    Sgb2 => "CPU SGB2",
-                }
-                .to_owned(),
-            ),
+            kind: Some(self.kind.display_name().to_owned()),
             manufacturer: Some(Manufacturer::Sharp),
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -103,6 +136,7 @@ impl ParsedPart for parser::StaticRam {
             kind: self.part,
             manufacturer: self.manufacturer,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -120,6 +154,7 @@ impl ParsedPart for parser::Crystal {
                 month: self.month,
                 ..DateCode::default()
             },
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -131,6 +166,7 @@ impl ParsedPart for parser::Coil {
             label: Some(label),
             kind: Some(self.kind),
             manufacturer: self.manufacturer,
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -142,6 +178,7 @@ impl ParsedPart for parser::Transformer {
             label: Some(label),
             kind: Some(self.kind),
             manufacturer: self.manufacturer,
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -155,6 +192,7 @@ impl ParsedPart for parser::SgbRom {
             manufacturer: self.manufacturer,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
             rom_code: Some(self.rom_code),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -167,6 +205,7 @@ impl ParsedPart for parser::ChipYearWeek {
             kind: Some(self.kind),
             manufacturer: self.manufacturer,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -179,6 +218,8 @@ impl ParsedPart for parser::MaskRom {
             kind: self.chip_type,
             manufacturer: self.manufacturer,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            rom_code: Some(self.rom_code),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -191,6 +232,7 @@ impl ParsedPart for parser::Mapper {
             kind: Some(self.mbc_type.display_name().to_owned()),
             manufacturer: self.manufacturer,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -203,6 +245,7 @@ impl ParsedPart for parser::SupervisorReset {
             kind: Some(self.chip_type),
             manufacturer: self.manufacturer,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
@@ -212,14 +255,30 @@ impl ParsedPart for parser::Tama {
     fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
         ProcessedPart {
             label: Some(label),
-            kind: match self.tama_type {
-                parser::TamaType::Tama5 => Some("TAMA5".to_owned()),
-                parser::TamaType::Tama6 => Some("TAMA6".to_owned()),
-                parser::TamaType::Tama7 => Some("TAMA7".to_owned()),
-            },
+            kind: Some(self.tama_type.display_name().to_owned()),
             manufacturer: None,
             date_code: DateCode::loose_year_week(year_hint, self.year, self.week),
+            country: self.country,
             ..ProcessedPart::default()
         }
     }
 }
+
+impl ParsedPart for parser::Identification {
+    fn process(self, year_hint: Option<u16>, label: String) -> ProcessedPart {
+        ProcessedPart {
+            label: Some(label),
+            kind: self.kind,
+            manufacturer: self.manufacturer,
+            date_code: DateCode {
+                year: to_full_year(year_hint, self.date_code.year),
+                month: self.date_code.month,
+                week: self.date_code.week,
+                ..DateCode::default()
+            },
+            rom_code: self.rom_code,
+            country: self.country,
+            substitutions: Vec::new(),
+        }
+    }
+}
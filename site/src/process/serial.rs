@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::serial::Serial;
+
+/// Consecutive serials that share the same value, e.g. the same mainboard revision
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerialRun<'a, T> {
+    pub value: T,
+    pub first: Serial<'a>,
+    pub last: Serial<'a>,
+    pub units: usize,
+}
+
+/// Groups serials sorted by prefix and number into runs of the same value.
+///
+/// A run ends whenever the prefix or the value changes, so the runs show the serial ranges in
+/// which a revision was seen without assuming anything about the gaps between submitted units.
+pub fn serial_runs<'a, T: PartialEq>(
+    units: impl IntoIterator<Item = (Serial<'a>, T)>,
+) -> Vec<SerialRun<'a, T>> {
+    let mut runs: Vec<SerialRun<T>> = Vec::new();
+    for (serial, value) in units {
+        match runs.last_mut() {
+            Some(run) if run.last.prefix == serial.prefix && run.value == value => {
+                run.last = serial;
+                run.units += 1;
+            }
+            _ => runs.push(SerialRun {
+                value,
+                first: serial,
+                last: serial,
+                units: 1,
+            }),
+        }
+    }
+    runs
+}
+
+#[test]
+fn test_serial_runs() {
+    let serial = |text| Serial::parse(text).unwrap();
+    let runs = serial_runs([
+        (serial("G01000000"), "DMG-CPU-01"),
+        (serial("G01000100"), "DMG-CPU-01"),
+        (serial("G01000200"), "DMG-CPU-02"),
+        (serial("GH1000300"), "DMG-CPU-02"),
+    ]);
+    assert_eq!(
+        runs.iter()
+            .map(|run| (run.value, run.first.text, run.units))
+            .collect::<Vec<_>>(),
+        [
+            ("DMG-CPU-01", "G01000000", 2),
+            ("DMG-CPU-02", "G01000200", 1),
+            ("DMG-CPU-02", "GH1000300", 1),
+        ]
+    );
+}
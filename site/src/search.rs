@@ -0,0 +1,147 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::Error;
+use itertools::Itertools;
+use serde::Serialize;
+use std::{collections::BTreeSet, io};
+
+use crate::{
+    legacy::{console::LegacyConsoleMetadata, LegacyCartridgeSubmission, LegacySubmission},
+    process::part::ProcessedPart,
+    SiteData,
+};
+
+/// One searchable page. Field names are kept short, because the index is downloaded in full
+#[derive(Clone, Debug, Serialize)]
+pub struct SearchEntry {
+    #[serde(rename = "u")]
+    pub url: String,
+    #[serde(rename = "t")]
+    pub title: String,
+    #[serde(rename = "d")]
+    pub description: String,
+    /// Lowercase words separated by spaces
+    #[serde(rename = "k")]
+    pub keywords: String,
+}
+
+/// Unique lowercase words of everything that should find a submission
+#[derive(Clone, Debug, Default)]
+struct Keywords(BTreeSet<String>);
+
+impl Keywords {
+    fn add(&mut self, text: Option<&str>) {
+        for word in text.into_iter().flat_map(str::split_whitespace) {
+            self.0.insert(word.to_lowercase());
+        }
+    }
+    fn add_part(&mut self, part: &ProcessedPart) {
+        self.add(part.label.as_deref());
+        self.add(part.kind.as_deref());
+        self.add(part.rom_code.as_deref());
+        self.add(part.manufacturer.map(|manufacturer| manufacturer.name()));
+    }
+    fn finish(self) -> String {
+        self.0.into_iter().join(" ")
+    }
+}
+
+fn console_entries<M, P>(submissions: &[LegacySubmission<M, P>]) -> Vec<SearchEntry>
+where
+    M: LegacyConsoleMetadata,
+{
+    let console = M::CONSOLE;
+    let parts = M::parts();
+    let attributes = M::attributes();
+    submissions
+        .iter()
+        .map(|submission| {
+            let metadata = &submission.metadata;
+            let shell = metadata.shell();
+            let mut keywords = Keywords::default();
+            keywords.add(Some(console.code()));
+            keywords.add(Some(&submission.slug));
+            keywords.add(Some(&submission.title));
+            keywords.add(Some(&submission.contributor));
+            keywords.add(shell.serial);
+            keywords.add(shell.release_code);
+            keywords.add(shell.stamp);
+            for attribute in &attributes {
+                keywords.add((attribute.getter)(metadata));
+            }
+            for part in parts.iter().filter_map(|chip| (chip.getter)(metadata)) {
+                keywords.add_part(part);
+            }
+            SearchEntry {
+                url: format!("/consoles/{}/{}.html", submission.code, submission.slug),
+                title: format!("{} {}", console.code(), submission.title),
+                description: format!(
+                    "{}, submitted by {}",
+                    metadata.mainboard().kind,
+                    submission.contributor
+                ),
+                keywords: keywords.finish(),
+            }
+        })
+        .collect()
+}
+
+fn cartridge_entries(submissions: &[LegacyCartridgeSubmission]) -> Vec<SearchEntry> {
+    submissions
+        .iter()
+        .map(|submission| {
+            let metadata = &submission.metadata;
+            let mut keywords = Keywords::default();
+            keywords.add(Some(&submission.code));
+            keywords.add(Some(&submission.slug));
+            keywords.add(Some(&submission.contributor));
+            keywords.add(Some(&metadata.cfg.name));
+            keywords.add(metadata.code.as_deref());
+            keywords.add(metadata.stamp.as_deref());
+            keywords.add(Some(&metadata.board.kind));
+            for part in metadata.board.parts.values() {
+                keywords.add_part(part);
+            }
+            SearchEntry {
+                url: format!("/cartridges/{}/{}.html", submission.code, submission.slug),
+                title: metadata.cfg.name.clone(),
+                description: format!(
+                    "{}, {}, submitted by {}",
+                    submission.code, metadata.board.kind, submission.contributor
+                ),
+                keywords: keywords.finish(),
+            }
+        })
+        .collect()
+}
+
+/// Writes the index used by the search page, with every console and cartridge submission
+pub fn write_search_index<W: io::Write>(writer: W, data: &SiteData) -> Result<(), Error> {
+    let entries = [
+        console_entries(&data.dmg),
+        console_entries(&data.sgb),
+        console_entries(&data.mgb),
+        console_entries(&data.mgl),
+        console_entries(&data.sgb2),
+        console_entries(&data.cgb),
+        console_entries(&data.agb),
+        console_entries(&data.ags),
+        console_entries(&data.gbs),
+        console_entries(&data.oxy),
+        cartridge_entries(&data.cartridges),
+    ]
+    .concat();
+    serde_json::to_writer(writer, &entries)?;
+    Ok(())
+}
+
+#[test]
+fn test_keywords() {
+    let mut keywords = Keywords::default();
+    keywords.add(Some("LH5164AN-10 SHARP"));
+    keywords.add(None);
+    keywords.add(Some("Sharp  JAPAN"));
+    assert_eq!(keywords.finish(), "japan lh5164an-10 sharp");
+}
@@ -1,18 +1,23 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use anyhow::Error;
+use anyhow::{anyhow, bail, Error};
 use gbhwdb_backend::{
-    config::cartridge::{BoardLayout, PartRole, PartRoleConfig},
+    config::{
+        cartridge::{BoardLayout, PartRole, PartRoleConfig},
+        part_catalog::PartInfo,
+    },
+    parser::Manufacturer,
     Console,
 };
 use itertools::Itertools;
 use log::error;
 use maud::{Markup, Render};
+use slug::slugify;
 use std::{
     borrow::Cow,
-    collections::HashMap,
+    collections::{BTreeMap, BTreeSet, HashMap},
     fs::{self, create_dir_all},
     path::{Path, PathBuf},
     sync::OnceLock,
@@ -20,20 +25,35 @@ use std::{
 use time::OffsetDateTime;
 
 use crate::{
-    legacy::LegacySubmission,
+    contribution_dates::ContributionDates,
+    legacy::{console::LegacyConsoleMetadata, LegacyPhotos, LegacySubmission},
     template::{
         cartridge_page::CartridgePage,
         cartridges::Cartridges,
+        check_label::CheckLabel,
+        comparison::{comparison_path, Comparison},
         console_page::ConsolePage,
         console_submission_list::ConsoleSubmissionList,
+        contributor_page::{ContributorEntry, ContributorPage, Contributors},
         dmg_console_page::DmgConsolePage,
         dmg_submission_list::DmgSubmissionList,
         game::Game,
         home::Home,
+        listing_entry_cell::ListingEntryCell,
+        mainboard_page::MainboardPage,
+        manufacturer_page::{ManufacturerPage, ManufacturerParts, Manufacturers},
         mapper::{Mapper, MapperCfg},
         markdown::Markdown,
         markdown_page::MarkdownPage,
         page,
+        part_origins::PartOrigins,
+        part_page::{part_slug, PartCatalog, PartPage, PartUse},
+        parts_matrix::{matrix_pairs, matrix_slug, Matrix, MatrixIndex},
+        production_timeline::ProductionTimeline,
+        search::Search,
+        serial_correlation::SerialCorrelation,
+        statistics::{ConsoleStatistics, Statistics},
+        unit_lookup::UnitLookup,
     },
     SiteData,
 };
@@ -68,12 +88,33 @@ pub fn build_site() -> Site {
         SiteSection::Consoles(None),
         "site/content/consoles.markdown",
     );
+    site.add_page(["consoles", "lookup"], |_| {
+        Ok(Page {
+            title: Cow::Borrowed("What's inside my unit?"),
+            section: SiteSection::Consoles(None),
+            content: UnitLookup.render(),
+        })
+    });
+    site.add_page(["search"], |_| {
+        Ok(Page {
+            title: Cow::Borrowed("Search"),
+            section: SiteSection::Search,
+            content: Search.render(),
+        })
+    });
     site.add_markdown_page(
         ["contribute", "index"],
         "Contribute",
         SiteSection::Consoles(None),
         "site/content/contribute.markdown",
     );
+    site.add_page(["contribute", "check-label"], |_| {
+        Ok(Page {
+            title: Cow::Borrowed("Check a label"),
+            section: SiteSection::Consoles(None),
+            content: CheckLabel.render(),
+        })
+    });
     site.add_markdown_page(
         ["contribute", "sgb"],
         "Super Game Boy (SGB) contribution instructions",
@@ -98,6 +139,118 @@ pub fn build_site() -> Site {
         SiteSection::Consoles(None),
         "site/content/contribute-cartridges.markdown",
     );
+    site.add_page(["statistics", "index"], |data| {
+        let counts = data.counts();
+        Ok(Page {
+            title: Cow::Borrowed("Statistics"),
+            section: SiteSection::Consoles(None),
+            content: Statistics {
+                consoles: Console::ALL
+                    .into_iter()
+                    .map(|console| {
+                        let count = counts.consoles.get(&console).copied().unwrap_or(0);
+                        (console, count as usize)
+                    })
+                    .collect(),
+                cartridges: counts.cartridges as usize,
+            }
+            .render(),
+        })
+    });
+    // part and manufacturer pages share the part uses, which are costly to collect
+    site.page_sets.push(Box::new(|data| {
+        let uses = part_uses(data);
+        let mut pages = Vec::new();
+        let counts = uses
+            .iter()
+            .map(|(&kind, uses)| (kind, uses.len()))
+            .collect();
+        pages.push((
+            SitePath::from(["parts", "index"]),
+            Page {
+                title: Cow::Borrowed("Parts"),
+                section: SiteSection::Consoles(None),
+                content: PartCatalog { counts: &counts }.render(),
+            },
+        ));
+        let kinds = uses
+            .keys()
+            .copied()
+            .chain(PartInfo::all().iter().map(|info| info.kind))
+            .collect::<BTreeSet<_>>();
+        for kind in kinds {
+            let path = SitePath(vec![Cow::Borrowed("parts"), Cow::Owned(part_slug(kind))]);
+            let page = Page {
+                title: Cow::Owned(kind.to_owned()),
+                section: SiteSection::Consoles(None),
+                content: PartPage {
+                    kind,
+                    info: PartInfo::from_kind(kind),
+                    uses: uses.get(kind).map_or(&[], Vec::as_slice),
+                }
+                .render(),
+            };
+            pages.push((path, page));
+        }
+        let mut parts = parts_by_manufacturer(&uses);
+        pages.push((
+            SitePath::from(["manufacturers", "index"]),
+            Page {
+                title: Cow::Borrowed("Manufacturers"),
+                section: SiteSection::Consoles(None),
+                content: Manufacturers { parts: &parts }.render(),
+            },
+        ));
+        for manufacturer in Manufacturer::ALL {
+            let path = SitePath(vec![
+                Cow::Borrowed("manufacturers"),
+                Cow::Borrowed(manufacturer.id()),
+            ]);
+            let page = Page {
+                title: Cow::Borrowed(manufacturer.name()),
+                section: SiteSection::Consoles(None),
+                content: ManufacturerPage {
+                    manufacturer,
+                    parts: &parts.remove(&manufacturer).unwrap_or_default(),
+                }
+                .render(),
+            };
+            pages.push((path, page));
+        }
+        pages
+    }));
+    site.add_page(["contributors", "index"], |data| {
+        let contributors = contributor_entries(data).into_iter().collect::<Vec<_>>();
+        Ok(Page {
+            title: Cow::Borrowed("Contributors"),
+            section: SiteSection::Consoles(None),
+            content: Contributors {
+                contributors: &contributors,
+            }
+            .render(),
+        })
+    });
+    site.page_sets.push(Box::new(|data| {
+        contributor_entries(data)
+            .into_iter()
+            .map(|(contributor, entries)| {
+                let path = SitePath(vec![
+                    Cow::Borrowed("contributors"),
+                    Cow::Owned(slugify(contributor)),
+                ]);
+                let page = Page {
+                    title: Cow::Owned(contributor.to_owned()),
+                    section: SiteSection::Consoles(None),
+                    content: ContributorPage {
+                        contributor,
+                        entries: &entries,
+                    }
+                    .render(),
+                };
+                (path, page)
+            })
+            .collect()
+    }));
     for console in Console::ALL {
         site.add_page(["consoles", console.id(), "index"], move |data| {
             Ok(Page {
@@ -121,6 +274,12 @@ pub fn build_site() -> Site {
                 },
             })
         });
+        site.page_sets.push(Box::new(move |data| {
+            with_console(data, console, MatrixPages)
+        }));
+        site.page_sets.push(Box::new(move |data| {
+            with_console(data, console, BoardPages)
+        }));
         fn create_pages<M, P>(
             console: Console,
             submissions: &[LegacySubmission<M, P>],
@@ -182,6 +341,10 @@ pub fn build_site() -> Site {
             }),
         });
     }
+    site.add_console_pages("statistics", "statistics", StatisticsPage);
+    site.add_console_pages("timeline", "production timeline", TimelinePage);
+    site.add_console_pages("serials", "serial numbers", SerialsPage);
+    site.add_console_pages("origins", "part origins", OriginsPage);
     let mapper_cfgs = MAPPER_CFGS.get_or_init(|| {
         vec![
             MapperCfg {
@@ -471,6 +634,399 @@ pub fn build_site() -> Site {
     site
 }
 
+/// Function over the submissions of any one console, whatever its metadata and photo types are
+trait ConsoleFn<'a> {
+    type Output;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Self::Output
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos;
+}
+
+/// Calls the function with the submissions of the given console
+fn with_console<'a, F: ConsoleFn<'a>>(data: &'a SiteData, console: Console, f: F) -> F::Output {
+    match console {
+        Console::Dmg => f.call(&data.dmg),
+        Console::Sgb => f.call(&data.sgb),
+        Console::Mgb => f.call(&data.mgb),
+        Console::Mgl => f.call(&data.mgl),
+        Console::Sgb2 => f.call(&data.sgb2),
+        Console::Cgb => f.call(&data.cgb),
+        Console::Agb => f.call(&data.agb),
+        Console::Ags => f.call(&data.ags),
+        Console::Gbs => f.call(&data.gbs),
+        Console::Oxy => f.call(&data.oxy),
+    }
+}
+
+#[derive(Copy, Clone)]
+struct StatisticsPage;
+
+impl<'a> ConsoleFn<'a> for StatisticsPage {
+    type Output = Markup;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Markup
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        ConsoleStatistics { submissions }.render()
+    }
+}
+
+#[derive(Copy, Clone)]
+struct TimelinePage;
+
+impl<'a> ConsoleFn<'a> for TimelinePage {
+    type Output = Markup;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Markup
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        ProductionTimeline { submissions }.render()
+    }
+}
+
+#[derive(Copy, Clone)]
+struct SerialsPage;
+
+impl<'a> ConsoleFn<'a> for SerialsPage {
+    type Output = Markup;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Markup
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        SerialCorrelation { submissions }.render()
+    }
+}
+
+#[derive(Copy, Clone)]
+struct OriginsPage;
+
+impl<'a> ConsoleFn<'a> for OriginsPage {
+    type Output = Markup;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Markup
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        PartOrigins::new(submissions).render()
+    }
+}
+
+struct MatrixPages;
+
+impl<'a> ConsoleFn<'a> for MatrixPages {
+    type Output = Vec<(SitePath, Page)>;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Self::Output
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        create_matrix_pages(submissions)
+    }
+}
+
+fn create_matrix_pages<M, P>(submissions: &[LegacySubmission<M, P>]) -> Vec<(SitePath, Page)>
+where
+    M: LegacyConsoleMetadata,
+{
+    let console = M::CONSOLE;
+    let attributes = M::attributes();
+    let pairs = matrix_pairs(&attributes, submissions);
+    let path = |name: String| {
+        SitePath(vec![
+            Cow::Borrowed("consoles"),
+            Cow::Borrowed(console.id()),
+            Cow::Borrowed("matrix"),
+            Cow::Owned(name),
+        ])
+    };
+    let index = Page {
+        title: format!("{} ({}) parts matrix", console.name(), console.code()).into(),
+        section: SiteSection::Consoles(Some(console)),
+        content: MatrixIndex {
+            attributes: &attributes,
+            pairs: &pairs,
+        }
+        .render(),
+    };
+    let matrices = pairs.iter().map(|&(row, column)| {
+        let (row, column) = (&attributes[row], &attributes[column]);
+        let page = Page {
+            title: format!("{}: {} × {}", console.code(), row.label, column.label).into(),
+            section: SiteSection::Consoles(Some(console)),
+            content: Matrix {
+                row,
+                column,
+                submissions,
+            }
+            .render(),
+        };
+        (path(matrix_slug(&row.label, &column.label)), page)
+    });
+    [(path("index".to_owned()), index)]
+        .into_iter()
+        .chain(matrices)
+        .collect()
+}
+
+struct BoardPages;
+
+impl<'a> ConsoleFn<'a> for BoardPages {
+    type Output = Vec<(SitePath, Page)>;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Self::Output
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        create_board_pages(submissions)
+    }
+}
+
+fn create_board_pages<M, P>(submissions: &[LegacySubmission<M, P>]) -> Vec<(SitePath, Page)>
+where
+    M: LegacyConsoleMetadata,
+    P: LegacyPhotos,
+{
+    let console = M::CONSOLE;
+    submissions
+        .iter()
+        .into_group_map_by(|submission| submission.metadata.mainboard().kind)
+        .into_iter()
+        .map(|(kind, submissions)| {
+            let path = SitePath(vec![
+                Cow::Borrowed("consoles"),
+                Cow::Borrowed(console.id()),
+                Cow::Borrowed("boards"),
+                Cow::Owned(slugify(kind)),
+            ]);
+            let page = Page {
+                title: format!("{}: {kind}", console.code()).into(),
+                section: SiteSection::Consoles(Some(console)),
+                content: MainboardPage {
+                    kind,
+                    submissions: &submissions,
+                }
+                .render(),
+            };
+            (path, page)
+        })
+        .collect()
+}
+
+/// All parts in console and cartridge submissions, grouped by part kind
+fn part_uses(data: &SiteData) -> BTreeMap<&str, Vec<PartUse<'_>>> {
+    fn add_console_parts<'a, M: LegacyConsoleMetadata, P>(
+        uses: &mut BTreeMap<&'a str, Vec<PartUse<'a>>>,
+        submissions: &'a [LegacySubmission<M, P>],
+    ) {
+        let console = M::CONSOLE;
+        let chips = M::parts();
+        for submission in submissions {
+            for chip in &chips {
+                let Some(part) = (chip.getter)(&submission.metadata) else {
+                    continue;
+                };
+                let Some(kind) = part.kind.as_deref() else {
+                    continue;
+                };
+                uses.entry(kind).or_default().push(PartUse {
+                    source: console.code(),
+                    title: &submission.title,
+                    contributor: &submission.contributor,
+                    url: format!("/consoles/{}/{}.html", console.id(), submission.slug),
+                    designator: chip.designator,
+                    part,
+                });
+            }
+        }
+    }
+    let mut uses = BTreeMap::new();
+    add_console_parts(&mut uses, &data.dmg);
+    add_console_parts(&mut uses, &data.sgb);
+    add_console_parts(&mut uses, &data.mgb);
+    add_console_parts(&mut uses, &data.mgl);
+    add_console_parts(&mut uses, &data.sgb2);
+    add_console_parts(&mut uses, &data.cgb);
+    add_console_parts(&mut uses, &data.agb);
+    add_console_parts(&mut uses, &data.ags);
+    add_console_parts(&mut uses, &data.gbs);
+    add_console_parts(&mut uses, &data.oxy);
+    for submission in &data.cartridges {
+        let parts = &submission.metadata.board.parts;
+        for (designator, part) in parts
+            .iter()
+            .sorted_by_key(|(designator, _)| designator.as_str())
+        {
+            let Some(kind) = part.kind.as_deref() else {
+                continue;
+            };
+            uses.entry(kind).or_default().push(PartUse {
+                source: &submission.metadata.cfg.name,
+                title: &submission.title,
+                contributor: &submission.contributor,
+                url: format!("/cartridges/{}/{}.html", submission.code, submission.slug),
+                designator: designator.as_str(),
+                part,
+            });
+        }
+    }
+    uses
+}
+
+fn parts_by_manufacturer<'a>(
+    uses: &'a BTreeMap<&'a str, Vec<PartUse<'a>>>,
+) -> BTreeMap<Manufacturer, ManufacturerParts<'a>> {
+    let mut result: BTreeMap<Manufacturer, ManufacturerParts> = BTreeMap::new();
+    for (&kind, uses) in uses {
+        for part_use in uses {
+            if let Some(manufacturer) = part_use.part.manufacturer {
+                result
+                    .entry(manufacturer)
+                    .or_default()
+                    .entry(kind)
+                    .or_default()
+                    .push(part_use);
+            }
+        }
+    }
+    result
+}
+
+/// All console and cartridge submissions, grouped by contributor
+fn contributor_entries(data: &SiteData) -> BTreeMap<&str, Vec<ContributorEntry>> {
+    fn add_console_entries<'a, M: LegacyConsoleMetadata, P: LegacyPhotos>(
+        entries: &mut BTreeMap<&'a str, Vec<ContributorEntry>>,
+        dates: &ContributionDates,
+        submissions: &'a [LegacySubmission<M, P>],
+    ) {
+        let console = M::CONSOLE;
+        for submission in submissions {
+            let dir = format!("data/consoles/{}/{}", console.code(), submission.slug);
+            entries
+                .entry(&submission.contributor)
+                .or_default()
+                .push(ContributorEntry {
+                    group: console.name(),
+                    cell: ListingEntryCell {
+                        url_prefix: "/consoles",
+                        primary_text: &submission.title,
+                        secondary_texts: &[],
+                        submission,
+                    }
+                    .render(),
+                    added: dates.get(dir),
+                });
+        }
+    }
+    let dates = &data.contribution_dates;
+    let mut entries = BTreeMap::new();
+    add_console_entries(&mut entries, dates, &data.dmg);
+    add_console_entries(&mut entries, dates, &data.sgb);
+    add_console_entries(&mut entries, dates, &data.mgb);
+    add_console_entries(&mut entries, dates, &data.mgl);
+    add_console_entries(&mut entries, dates, &data.sgb2);
+    add_console_entries(&mut entries, dates, &data.cgb);
+    add_console_entries(&mut entries, dates, &data.agb);
+    add_console_entries(&mut entries, dates, &data.ags);
+    add_console_entries(&mut entries, dates, &data.gbs);
+    add_console_entries(&mut entries, dates, &data.oxy);
+    for submission in &data.cartridges {
+        let dir = format!("data/cartridges/{}/{}", submission.code, submission.slug);
+        entries
+            .entry(submission.contributor.as_str())
+            .or_default()
+            .push(ContributorEntry {
+                group: "Game cartridges",
+                cell: ListingEntryCell {
+                    url_prefix: "/cartridges",
+                    primary_text: &submission.metadata.cfg.name,
+                    secondary_texts: &[&submission.title],
+                    submission,
+                }
+                .render(),
+                added: dates.get(dir),
+            });
+    }
+    entries
+}
+
+/// Builds a page comparing the given submissions of one console (e.g. "cgb") or one game
+/// (e.g. "DMG-TRA-1"), in the given order
+pub fn comparison_page(
+    data: &SiteData,
+    kind: &str,
+    slugs: &[&str],
+) -> Result<(SitePath, Page), Error> {
+    if slugs.len() < 2 {
+        bail!("At least two submissions are needed for a comparison");
+    }
+    let (title, section, content) = match Console::ALL.into_iter().find(|c| c.id() == kind) {
+        Some(console) => {
+            let content = with_console(data, console, ConsoleComparison { code: kind, slugs })?;
+            let title = format!("{} comparison", console.code());
+            (title, SiteSection::Consoles(Some(console)), content)
+        }
+        None => {
+            let submissions = find_submissions(&data.cartridges, kind, slugs)?;
+            let content = Comparison::cartridges(&submissions).render();
+            (
+                format!("{kind} comparison"),
+                SiteSection::Cartridges,
+                content,
+            )
+        }
+    };
+    let path = SitePath(
+        comparison_path(kind, slugs)
+            .split('/')
+            .map(|segment| Cow::Owned(segment.to_owned()))
+            .collect(),
+    );
+    let page = Page {
+        title: title.into(),
+        section,
+        content,
+    };
+    Ok((path, page))
+}
+
+/// Looks up submissions by slug, in the given order
+fn find_submissions<'a, M, P>(
+    submissions: &'a [LegacySubmission<M, P>],
+    code: &str,
+    slugs: &[&str],
+) -> Result<Vec<&'a LegacySubmission<M, P>>, Error> {
+    slugs
+        .iter()
+        .map(|&slug| {
+            submissions
+                .iter()
+                .find(|submission| submission.code == code && submission.slug == slug)
+                .ok_or_else(|| anyhow!("Unknown {code} submission {slug}"))
+        })
+        .collect()
+}
+
+struct ConsoleComparison<'s> {
+    code: &'s str,
+    slugs: &'s [&'s str],
+}
+
+impl<'a, 's> ConsoleFn<'a> for ConsoleComparison<'s> {
+    type Output = Result<Markup, Error>;
+    fn call<M, P>(self, submissions: &'a [LegacySubmission<M, P>]) -> Self::Output
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        let submissions = find_submissions(submissions, self.code, self.slugs)?;
+        Ok(Comparison::consoles(&submissions).render())
+    }
+}
+
 pub struct Page {
     pub title: Cow<'static, str>,
     pub section: SiteSection,
@@ -535,6 +1091,22 @@ impl Site {
             }),
         );
     }
+    /// Adds a page for every console, e.g. consoles/cgb/statistics, rendered from the
+    /// submissions of that console
+    fn add_console_pages<F>(&mut self, name: &'static str, title: &'static str, f: F)
+    where
+        F: for<'a> ConsoleFn<'a, Output = Markup> + Copy + 'static,
+    {
+        for console in Console::ALL {
+            self.add_page(["consoles", console.id(), name], move |data| {
+                Ok(Page {
+                    title: format!("{} ({}) {title}", console.name(), console.code()).into(),
+                    section: SiteSection::Consoles(Some(console)),
+                    content: with_console(data, console, f),
+                })
+            });
+        }
+    }
     pub fn add_page<P: Into<SitePath>>(
         &mut self,
         path: P,
@@ -591,4 +1163,5 @@ impl SubmissionCounts {
 pub enum SiteSection {
     Consoles(Option<Console>),
     Cartridges,
+    Search,
 }
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -11,21 +11,36 @@ use crate::{
 
 pub mod cartridge_page;
 pub mod cartridges;
+pub mod chart;
+pub mod check_label;
+pub mod comparison;
 pub mod console_page;
 pub mod console_submission_list;
+pub mod contributor_page;
 pub mod dmg_console_page;
 pub mod dmg_submission_list;
 pub mod game;
 pub mod home;
 pub mod listing_entry_cell;
+pub mod listing_filters;
 pub mod listing_part;
 pub mod listing_photos_cell;
+pub mod mainboard_page;
+pub mod manufacturer_page;
 pub mod mapper;
 pub mod markdown;
 pub mod markdown_page;
+pub mod part_origins;
+pub mod part_page;
+pub mod parts_matrix;
+pub mod production_timeline;
+pub mod search;
+pub mod serial_correlation;
 pub mod site_footer;
 pub mod site_header;
+pub mod statistics;
 pub mod submission_part_table;
+pub mod unit_lookup;
 
 pub fn page(title: &str, section: SiteSection, content: Markup) -> String {
     html! {
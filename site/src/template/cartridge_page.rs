@@ -1,14 +1,20 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
-use gbhwdb_backend::config::cartridge::PartRoleConfig;
+use gbhwdb_backend::{
+    config::cartridge::{BoardMemory, PartRoleConfig},
+    parser::chip_spec::format_capacity,
+};
 use maud::{html, Markup, Render};
 use time::{format_description::FormatItem, macros::format_description};
 
 use crate::{
     legacy::{LegacyCartridgeSubmission, LegacyPhoto},
-    template::submission_part_table::{submission_part_table, SubmissionPart},
+    template::{
+        contributor_page::contributor_url,
+        submission_part_table::{submission_part_table, SubmissionPart},
+    },
 };
 
 pub struct CartridgePage<'a> {
@@ -50,7 +56,11 @@ impl<'a> Render for CartridgePage<'a> {
             });
         html! {
             article.page-cartridge {
-                h2 { (metadata.cfg.name) ": " (self.submission.title) " [" (self.submission.contributor) "]" }
+                h2 {
+                    (metadata.cfg.name) ": " (self.submission.title) " ["
+                    a href=(contributor_url(&self.submission.contributor)) { (self.submission.contributor) }
+                    "]"
+                }
                 div.page-cartridge__photo {
                     @if let Some(photo) = &photos.front {
                         (self.render_photo(photo))
@@ -81,6 +91,17 @@ impl<'a> Render for CartridgePage<'a> {
                 dl {
                     dt { "Board type" }
                     dd { (board.kind) }
+                    @if let Some(memory) = BoardMemory::from_label(&board.kind) {
+                        dt { "Board memory" }
+                        dd {
+                            "ROM up to " (format_capacity(memory.max_rom))
+                            @match memory.ram {
+                                Some(0) => ", no RAM",
+                                Some(ram) => ", " (format_capacity(ram)) " RAM",
+                                None => "",
+                            }
+                        }
+                    }
                     @if let Some(date) = board.date_code.calendar() {
                         dt { "Manufacture date" }
                         dd { (date) }
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -79,6 +79,8 @@ impl<'a> Render for Cartridges<'a> {
                 }
                 h3 { "Data dumps" }
                 a href="/static/export/cartridges.csv" { "UTF-8 encoded CSV" }
+                br;
+                a href="/static/export/cartridges.json" { "JSON with nested boards and parts" }
             }
         }
     }
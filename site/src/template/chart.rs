@@ -0,0 +1,322 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Inline SVG charts rendered at build time
+
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+
+const FONT_SIZE: usize = 12;
+const COLORS: [&str; 10] = [
+    "#234e8e", "#e0a030", "#5a9e4b", "#c0504d", "#8064a2", "#4bacc6", "#f79646", "#7f7f7f",
+    "#9bbb59", "#2c2c2c",
+];
+
+/// Color of the nth series in multi-series charts
+pub fn series_color(index: usize) -> &'static str {
+    COLORS[index % COLORS.len()]
+}
+
+/// Number of items per year from the first to the last year, including years with no items
+pub fn year_buckets(years: impl IntoIterator<Item = u16>) -> Vec<(u16, usize)> {
+    let counts = years.into_iter().counts();
+    match counts.keys().minmax().into_option() {
+        Some((&first, &last)) => (first..=last)
+            .map(|year| (year, counts.get(&year).copied().unwrap_or(0)))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Columns of a `ColumnChart` with one column per year
+pub fn year_columns(buckets: &[(u16, usize)]) -> Vec<(String, usize)> {
+    buckets
+        .iter()
+        .map(|&(year, count)| (year.to_string(), count))
+        .collect()
+}
+
+/// Years covered by the buckets, e.g. "1989–1994"
+pub fn year_span(buckets: &[(u16, usize)]) -> Option<String> {
+    let (&(first, _), &(last, _)) = buckets.first().zip(buckets.last())?;
+    Some(if first == last {
+        first.to_string()
+    } else {
+        format!("{first}–{last}")
+    })
+}
+
+/// Horizontal bars, one per label
+pub struct BarChart<'a> {
+    pub bars: &'a [(String, usize)],
+}
+
+impl<'a> Render for BarChart<'a> {
+    fn render(&self) -> Markup {
+        const LABEL_WIDTH: usize = 200;
+        const BAR_WIDTH: usize = 400;
+        const ROW_HEIGHT: usize = 22;
+
+        let max = self.bars.iter().map(|&(_, count)| count).max().unwrap_or(0);
+        let width = LABEL_WIDTH + BAR_WIDTH + 50;
+        let height = self.bars.len() * ROW_HEIGHT;
+        html! {
+            svg.chart xmlns="http://www.w3.org/2000/svg" width=(width) height=(height)
+                viewBox={ "0 0 " (width) " " (height) } role="img" font-size=(FONT_SIZE) {
+                @for (row, (label, count)) in self.bars.iter().enumerate() {
+                    @let y = row * ROW_HEIGHT;
+                    @let bar_width = (count * BAR_WIDTH).checked_div(max).unwrap_or(0);
+                    text x=(LABEL_WIDTH - 6) y=(y + ROW_HEIGHT / 2) text-anchor="end" dominant-baseline="middle" {
+                        (label)
+                    }
+                    rect x=(LABEL_WIDTH) y=(y + 3) width=(bar_width) height=(ROW_HEIGHT - 6) fill=(series_color(0)) {}
+                    text x=(LABEL_WIDTH + bar_width + 4) y=(y + ROW_HEIGHT / 2) dominant-baseline="middle" {
+                        (count)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Vertical bars, one per label, e.g. a histogram of years
+pub struct ColumnChart<'a> {
+    pub columns: &'a [(String, usize)],
+}
+
+impl<'a> Render for ColumnChart<'a> {
+    fn render(&self) -> Markup {
+        const COLUMN_WIDTH: usize = 36;
+        const CHART_HEIGHT: usize = 160;
+        const LABEL_HEIGHT: usize = 20;
+
+        let max = self
+            .columns
+            .iter()
+            .map(|&(_, count)| count)
+            .max()
+            .unwrap_or(0);
+        let width = self.columns.len() * COLUMN_WIDTH;
+        let height = CHART_HEIGHT + 2 * LABEL_HEIGHT;
+        html! {
+            svg.chart xmlns="http://www.w3.org/2000/svg" width=(width) height=(height)
+                viewBox={ "0 0 " (width) " " (height) } role="img" font-size=(FONT_SIZE) {
+                @for (index, (label, count)) in self.columns.iter().enumerate() {
+                    @let x = index * COLUMN_WIDTH;
+                    @let bar_height = (count * CHART_HEIGHT).checked_div(max).unwrap_or(0);
+                    @let top = LABEL_HEIGHT + CHART_HEIGHT - bar_height;
+                    rect x=(x + 4) y=(top) width=(COLUMN_WIDTH - 8) height=(bar_height) fill=(series_color(0)) {
+                        title { (label) ": " (count) }
+                    }
+                    @if *count > 0 {
+                        text x=(x + COLUMN_WIDTH / 2) y=(top - 4) text-anchor="middle" { (count) }
+                    }
+                    text x=(x + COLUMN_WIDTH / 2) y=(height - 4) text-anchor="middle" { (label) }
+                }
+            }
+        }
+    }
+}
+
+/// Vertical 100% stacked bars showing the share of each series per label
+pub struct ShareChart<'a> {
+    pub series: &'a [String],
+    /// Counts per series for each label, in the same order as `series`
+    pub columns: &'a [(String, Vec<usize>)],
+}
+
+impl<'a> Render for ShareChart<'a> {
+    fn render(&self) -> Markup {
+        const COLUMN_WIDTH: usize = 36;
+        const CHART_HEIGHT: usize = 160;
+        const LABEL_HEIGHT: usize = 20;
+        const LEGEND_WIDTH: usize = 180;
+
+        let chart_width = self.columns.len() * COLUMN_WIDTH;
+        let width = chart_width + LEGEND_WIDTH;
+        let height = (CHART_HEIGHT + LABEL_HEIGHT).max(self.series.len() * LABEL_HEIGHT);
+        html! {
+            svg.chart xmlns="http://www.w3.org/2000/svg" width=(width) height=(height)
+                viewBox={ "0 0 " (width) " " (height) } role="img" font-size=(FONT_SIZE) {
+                @for (index, (label, counts)) in self.columns.iter().enumerate() {
+                    @let x = index * COLUMN_WIDTH;
+                    @let total = counts.iter().sum::<usize>();
+                    @for (series, count, top, bottom) in stack(counts, CHART_HEIGHT) {
+                        rect x=(x + 4) y=(top) width=(COLUMN_WIDTH - 8) height=(bottom - top) fill=(series_color(series)) {
+                            title { (label) ", " (self.series[series]) ": " (count) "/" (total) }
+                        }
+                    }
+                    text x=(x + COLUMN_WIDTH / 2) y=(CHART_HEIGHT + LABEL_HEIGHT - 4) text-anchor="middle" { (label) }
+                }
+                @for (index, series) in self.series.iter().enumerate() {
+                    @let y = index * LABEL_HEIGHT;
+                    rect x=(chart_width + 10) y=(y + 4) width="12" height="12" fill=(series_color(index)) {}
+                    text x=(chart_width + 28) y=(y + LABEL_HEIGHT / 2) dominant-baseline="middle" { (series) }
+                }
+            }
+        }
+    }
+}
+
+/// Splits a column of the given height into segments proportional to the counts.
+///
+/// Returns (series index, count, top, bottom) for every non-zero count.
+fn stack(counts: &[usize], height: usize) -> Vec<(usize, usize, usize, usize)> {
+    let total = counts.iter().sum::<usize>();
+    let mut offset = 0;
+    let mut segments = Vec::new();
+    for (series, &count) in counts.iter().enumerate() {
+        if count > 0 {
+            let top = offset * height / total;
+            offset += count;
+            segments.push((series, count, top, offset * height / total));
+        }
+    }
+    segments
+}
+
+/// A point in a timeline or sequence chart
+pub struct TimelinePoint {
+    pub lane: usize,
+    pub series: usize,
+    /// Fractional year in a timeline chart, any number in a sequence chart
+    pub x: f64,
+    pub tooltip: String,
+}
+
+/// Horizontal axis of a chart with lanes
+struct Axis<'a> {
+    /// Width in pixels, including a margin after the last grid line
+    width: usize,
+    /// Offset of a value from the start of the axis in pixels
+    offset: &'a dyn Fn(f64) -> f64,
+    /// Values with a vertical grid line
+    grid: Vec<f64>,
+    /// Values with a label above the chart
+    labels: Vec<(f64, String)>,
+}
+
+/// Points with one horizontal lane per category and one color per series
+fn lane_chart(lanes: &[String], series: &[String], points: &[TimelinePoint], axis: Axis) -> Markup {
+    const LABEL_WIDTH: usize = 160;
+    const LANE_HEIGHT: usize = 30;
+    const AXIS_HEIGHT: usize = 20;
+    const LEGEND_HEIGHT: usize = 18;
+
+    let x = |value: f64| LABEL_WIDTH as f64 + (axis.offset)(value);
+    let lane_y = |lane: usize| AXIS_HEIGHT + lane * LANE_HEIGHT + LANE_HEIGHT / 2;
+    let chart_height = AXIS_HEIGHT + lanes.len() * LANE_HEIGHT;
+    let width = LABEL_WIDTH + axis.width;
+    let height = chart_height + 10 + series.len() * LEGEND_HEIGHT;
+    html! {
+        svg.chart xmlns="http://www.w3.org/2000/svg" width=(width) height=(height)
+            viewBox={ "0 0 " (width) " " (height) } role="img" font-size=(FONT_SIZE) {
+            @for &value in &axis.grid {
+                line x1=(x(value)) y1=(AXIS_HEIGHT) x2=(x(value)) y2=(chart_height) stroke="#ddd" {}
+            }
+            @for (value, label) in &axis.labels {
+                text x=(x(*value)) y=(AXIS_HEIGHT - 6) text-anchor="middle" { (label) }
+            }
+            @for (lane, label) in lanes.iter().enumerate() {
+                text x=(LABEL_WIDTH - 6) y=(lane_y(lane)) text-anchor="end" dominant-baseline="middle" { (label) }
+            }
+            @for (index, point) in points.iter().enumerate() {
+                // Spread points vertically a bit so that units with the same x don't overlap
+                @let offset = (index % 5) as f64 * 4.0 - 8.0;
+                circle cx=(format!("{:.1}", x(point.x))) cy=(lane_y(point.lane) as f64 + offset) r="3.5"
+                    fill=(series_color(point.series)) fill-opacity="0.8" {
+                    title { (point.tooltip) }
+                }
+            }
+            @for (index, series) in series.iter().enumerate() {
+                @let y = chart_height + 10 + index * LEGEND_HEIGHT;
+                circle cx=(LABEL_WIDTH + 6) cy=(y + LEGEND_HEIGHT / 2) r="5" fill=(series_color(index)) {}
+                text x=(LABEL_WIDTH + 16) y=(y + LEGEND_HEIGHT / 2) dominant-baseline="middle" { (series) }
+            }
+        }
+    }
+}
+
+/// Points on a time axis, one horizontal lane per category and one color per series
+pub struct TimelineChart<'a> {
+    pub lanes: &'a [String],
+    pub series: &'a [String],
+    pub points: &'a [TimelinePoint],
+}
+
+impl<'a> Render for TimelineChart<'a> {
+    fn render(&self) -> Markup {
+        const YEAR_WIDTH: usize = 60;
+
+        let (Some(first), Some(last)) = (
+            self.points.iter().map(|point| point.x.floor() as u16).min(),
+            self.points.iter().map(|point| point.x.ceil() as u16).max(),
+        ) else {
+            return html! {};
+        };
+        let last = last.max(first + 1);
+        let offset = |year: f64| (year - f64::from(first)) * YEAR_WIDTH as f64;
+        let axis = Axis {
+            width: usize::from(last - first) * YEAR_WIDTH + 20,
+            offset: &offset,
+            grid: (first..=last).map(f64::from).collect(),
+            labels: (first..last)
+                .map(|year| (f64::from(year) + 0.5, year.to_string()))
+                .collect(),
+        };
+        lane_chart(self.lanes, self.series, self.points, axis)
+    }
+}
+
+/// Points on a numeric axis, e.g. serial numbers, one horizontal lane per category and one color
+/// per series
+pub struct SequenceChart<'a> {
+    pub lanes: &'a [String],
+    pub series: &'a [String],
+    pub points: &'a [TimelinePoint],
+}
+
+impl<'a> Render for SequenceChart<'a> {
+    fn render(&self) -> Markup {
+        const AXIS_WIDTH: usize = 480;
+        const TICKS: usize = 4;
+
+        let (Some(first), Some(last)) = (
+            self.points
+                .iter()
+                .map(|point| point.x)
+                .min_by(f64::total_cmp),
+            self.points
+                .iter()
+                .map(|point| point.x)
+                .max_by(f64::total_cmp),
+        ) else {
+            return html! {};
+        };
+        let span = (last - first).max(1.0);
+        let offset = |value: f64| (value - first) / span * AXIS_WIDTH as f64;
+        let ticks = (0..=TICKS)
+            .map(|tick| first + span * tick as f64 / TICKS as f64)
+            .collect::<Vec<_>>();
+        let axis = Axis {
+            width: AXIS_WIDTH + 60,
+            offset: &offset,
+            labels: ticks
+                .iter()
+                .map(|&value| (value, value.round().to_string()))
+                .collect(),
+            grid: ticks,
+        };
+        lane_chart(self.lanes, self.series, self.points, axis)
+    }
+}
+
+#[test]
+fn test_year_buckets() {
+    let buckets = year_buckets([1991, 1989, 1991]);
+    assert_eq!(buckets, [(1989, 1), (1990, 0), (1991, 2)]);
+    assert_eq!(year_span(&buckets).as_deref(), Some("1989–1991"));
+    assert_eq!(year_span(&year_buckets([1995])).as_deref(), Some("1995"));
+    assert_eq!(year_span(&year_buckets([])), None);
+}
@@ -0,0 +1,51 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::{parser::PartFamily, Console};
+use maud::{html, Markup, Render};
+
+/// Label checker that runs the parsers client-side (see the gbhwdb-wasm crate)
+pub struct CheckLabel;
+
+impl Render for CheckLabel {
+    fn render(&self) -> Markup {
+        html! {
+            article.check-label {
+                h2 { "Check a label" }
+                p {
+                    "Type a label exactly as it is printed on the chip. "
+                    "The label is checked in your browser with the same parsers that are used to build this site."
+                }
+                noscript { p { strong { "This page requires JavaScript and WebAssembly." } } }
+                form #check-label-form {
+                    label for="check-label-family" { "Part" }
+                    select #check-label-family {
+                        option value="" { "Any" }
+                        @for family in PartFamily::ALL {
+                            option value=(family.id()) { (family.name()) }
+                        }
+                    }
+                    label for="check-label-text" { "Label" }
+                    input #check-label-text type="text" size="40" autocomplete="off";
+                    button type="submit" { "Check" }
+                }
+                div #check-label-result {}
+                h2 { "Check a metadata.json file" }
+                form #check-metadata-form {
+                    label for="check-metadata-kind" { "Type" }
+                    select #check-metadata-kind {
+                        @for console in Console::ALL {
+                            option value=(console.code()) { (console.code()) " - " (console.name()) }
+                        }
+                        option value="cartridge" { "Cartridge" }
+                    }
+                    textarea #check-metadata-text rows="20" cols="80" spellcheck="false" {}
+                    button type="submit" { "Check" }
+                }
+                div #check-metadata-result {}
+                script type="module" src="/check-label.js" {}
+            }
+        }
+    }
+}
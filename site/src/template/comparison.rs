@@ -0,0 +1,275 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::config::cartridge::PartRoleConfig;
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+
+use crate::{
+    legacy::{
+        console::LegacyConsoleMetadata, LegacyCartridgeSubmission, LegacyPhotos, LegacySubmission,
+    },
+    process::part::ProcessedPart,
+};
+
+/// Path of a comparison page relative to the site root, without the extension
+pub fn comparison_path(kind: &str, slugs: &[&str]) -> String {
+    format!("compare/{kind}/{}", slugs.join(","))
+}
+
+/// A row of values, one per compared submission
+struct Row {
+    label: String,
+    values: Vec<Option<String>>,
+}
+
+impl Row {
+    fn new(label: impl Into<String>, values: impl Iterator<Item = Option<String>>) -> Self {
+        Row {
+            label: label.into(),
+            values: values.collect(),
+        }
+    }
+    fn differs(&self) -> bool {
+        !self.values.iter().all_equal()
+    }
+}
+
+/// Rows for the label, kind, manufacturer and date of the part in the same position on every
+/// submission
+fn part_rows(designator: &str, label: &str, parts: &[Option<&ProcessedPart>]) -> Vec<Row> {
+    let row = |field: &str, value: fn(&ProcessedPart) -> Option<String>| {
+        Row::new(
+            format!("{label} ({designator}): {field}"),
+            parts.iter().map(|part| part.and_then(value)),
+        )
+    };
+    vec![
+        row("label", |part| part.label.clone()),
+        row("type", |part| part.kind.clone()),
+        row("manufacturer", |part| {
+            part.manufacturer.map(|m| m.name().to_owned())
+        }),
+        row("date", |part| part.date_code.calendar()),
+    ]
+}
+
+/// Two or more submissions of the same console or cartridge side by side
+pub struct Comparison<'a> {
+    heading: String,
+    /// Name and URL of each submission
+    submissions: Vec<(&'a str, String)>,
+    /// Rows of photo URLs
+    photos: Vec<Row>,
+    properties: Vec<Row>,
+    parts: Vec<Row>,
+}
+
+impl<'a> Comparison<'a> {
+    pub fn consoles<M, P>(submissions: &[&'a LegacySubmission<M, P>]) -> Self
+    where
+        M: LegacyConsoleMetadata,
+        P: LegacyPhotos,
+    {
+        let console = M::CONSOLE;
+        let shell = |f: fn(&M) -> Option<String>| {
+            move |submission: &&LegacySubmission<M, P>| f(&submission.metadata)
+        };
+        let properties = vec![
+            Row::new(
+                "Color",
+                submissions
+                    .iter()
+                    .map(shell(|m| m.shell().color.map(str::to_owned))),
+            ),
+            Row::new(
+                "Release code",
+                submissions
+                    .iter()
+                    .map(shell(|m| m.shell().release_code.map(str::to_owned))),
+            ),
+            Row::new(
+                "Stamp on case",
+                submissions
+                    .iter()
+                    .map(shell(|m| m.shell().stamp.map(str::to_owned))),
+            ),
+            Row::new(
+                "Assembly date",
+                submissions.iter().map(shell(|m| {
+                    let date = m.assembly_date()?;
+                    Some(format!("{} ({})", date.date_code.calendar()?, date.source))
+                })),
+            ),
+            Row::new(
+                "Board type",
+                submissions
+                    .iter()
+                    .map(shell(|m| Some(m.mainboard().kind.to_owned()))),
+            ),
+            Row::new(
+                "Board date",
+                submissions
+                    .iter()
+                    .map(shell(|m| m.mainboard().date_code.calendar())),
+            ),
+        ];
+        let parts = M::parts()
+            .into_iter()
+            .flat_map(|chip| {
+                let parts = submissions
+                    .iter()
+                    .map(|submission| (chip.getter)(&submission.metadata))
+                    .collect::<Vec<_>>();
+                part_rows(chip.designator, chip.label, &parts)
+            })
+            .collect();
+        Comparison {
+            heading: format!("{} ({})", console.name(), console.code()),
+            submissions: submissions
+                .iter()
+                .map(|submission| {
+                    let url = format!("/consoles/{}/{}.html", submission.code, submission.slug);
+                    (submission.title.as_str(), url)
+                })
+                .collect(),
+            photos: photo_rows(submissions),
+            properties,
+            parts,
+        }
+    }
+    pub fn cartridges(submissions: &[&'a LegacyCartridgeSubmission]) -> Self {
+        let heading = submissions
+            .first()
+            .map(|submission| submission.metadata.cfg.name.clone())
+            .unwrap_or_default();
+        // boards with different layouts can have different parts in the same position
+        let parts = submissions
+            .iter()
+            .flat_map(|submission| PartRoleConfig::from(submission.metadata.board.layout))
+            .unique_by(|&(designator, _)| designator)
+            .sorted_by_key(|&(designator, _)| designator)
+            .flat_map(|(designator, role)| {
+                let parts = submissions
+                    .iter()
+                    .map(|submission| submission.metadata.board.parts.get(&designator))
+                    .collect::<Vec<_>>();
+                part_rows(designator.as_str(), role.display(), &parts)
+            })
+            .collect();
+        let metadata = |f: fn(&LegacyCartridgeSubmission) -> Option<String>| {
+            submissions.iter().map(move |&submission| f(submission))
+        };
+        Comparison {
+            heading,
+            submissions: submissions
+                .iter()
+                .map(|submission| {
+                    let url = format!("/cartridges/{}/{}.html", submission.code, submission.slug);
+                    // cartridge titles are only unique within one contributor
+                    (submission.slug.as_str(), url)
+                })
+                .collect(),
+            photos: photo_rows(submissions),
+            properties: vec![
+                Row::new("Release", metadata(|s| s.metadata.code.clone())),
+                Row::new("Stamp on case", metadata(|s| s.metadata.stamp.clone())),
+                Row::new(
+                    "Board type",
+                    metadata(|s| Some(s.metadata.board.kind.clone())),
+                ),
+                Row::new(
+                    "Manufacture date",
+                    metadata(|s| s.metadata.board.date_code.calendar()),
+                ),
+                Row::new(
+                    "Circled letter(s) on board",
+                    metadata(|s| s.metadata.board.circled_letters.clone()),
+                ),
+            ],
+            parts,
+        }
+    }
+}
+
+fn photo_rows<M, P: LegacyPhotos>(submissions: &[&LegacySubmission<M, P>]) -> Vec<Row> {
+    P::infos()
+        .into_iter()
+        .map(|info| {
+            Row::new(
+                info.label,
+                submissions.iter().map(|submission| {
+                    let photo = (info.getter)(&submission.photos)?;
+                    Some(format!(
+                        "/static/{}/{}_{}",
+                        submission.code, submission.slug, photo.name
+                    ))
+                }),
+            )
+        })
+        .filter(|row| row.values.iter().any(Option::is_some))
+        .collect()
+}
+
+fn render_rows(rows: &[Row]) -> Markup {
+    html! {
+        @for row in rows.iter().filter(|row| row.values.iter().any(Option::is_some)) {
+            tr class=[row.differs().then_some("comparison__row--differs")] {
+                th { (row.label) }
+                @for value in &row.values {
+                    td { (value.as_deref().unwrap_or("-")) }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Render for Comparison<'a> {
+    fn render(&self) -> Markup {
+        let differences = self
+            .properties
+            .iter()
+            .chain(&self.parts)
+            .filter(|row| row.differs())
+            .count();
+        html! {
+            article.comparison {
+                h2 { (self.heading) ": comparison" }
+                p {
+                    @if differences == 0 {
+                        "No differences in the recorded data."
+                    } @else {
+                        (differences) " differences are highlighted."
+                    }
+                }
+                table {
+                    thead {
+                        tr {
+                            th {}
+                            @for (title, url) in &self.submissions {
+                                th { a href=(url) { (title) } }
+                            }
+                        }
+                    }
+                    tbody {
+                        (render_rows(&self.properties))
+                        (render_rows(&self.parts))
+                        @for row in &self.photos {
+                            tr {
+                                th { (row.label) }
+                                @for url in &row.values {
+                                    td.comparison__photo {
+                                        @if let Some(url) = url {
+                                            a href=(url) { img src=(url); }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -10,7 +10,9 @@ use crate::{
         PhotoInfo, PhotoKind,
     },
     template::{
-        submission_part_table::{submission_part_table, SubmissionPart},
+        contributor_page::contributor_url,
+        mainboard_page::mainboard_url,
+        submission_part_table::{submission_part_table, SubmissionPart, Substitutions},
         Optional,
     },
 };
@@ -79,7 +81,11 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for ConsolePage<'a, M
             .chain(self.extra_parts.iter().map(|f| f(metadata)));
         html! {
             article class=(format!("page-console page-console--{console}", console = M::CONSOLE.id())) {
-                h2 { (M::CONSOLE.code()) ": " (self.submission.title) " [" (self.submission.contributor) "]" }
+                h2 {
+                    (M::CONSOLE.code()) ": " (self.submission.title) " ["
+                    a href=(contributor_url(&self.submission.contributor)) { (self.submission.contributor) }
+                    "]"
+                }
                 div.page-console__photo {
                     @for info in P::infos() {
                         @if info.kind == PhotoKind::MainUnit {
@@ -107,7 +113,10 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for ConsolePage<'a, M
                     @if let Some(panel) = metadata.lcd_panel() {
                         @if let Some(label) = &panel.label {
                             dt { "LCD panel label" }
-                            dd { (label) }
+                            dd {
+                                (label)
+                                (Substitutions(&panel.substitutions))
+                            }
                         }
                         @if let Some(date) = panel.date_code().calendar() {
                             dt { "LCD panel date" }
@@ -125,7 +134,7 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for ConsolePage<'a, M
                 }
                 dl {
                     dt { "Board type" }
-                    dd { (mainboard.kind) }
+                    dd { a href=(mainboard_url(M::CONSOLE, mainboard.kind)) { (mainboard.kind) } }
                     @if let Some(date) = mainboard.date_code.calendar() {
                         dt { "Manufacture date" }
                         dd { (date) }
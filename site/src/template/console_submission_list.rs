@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -9,8 +9,12 @@ use crate::{
         console::{ChipInfo, LegacyConsoleMetadata},
         HasDateCode, LegacyPhotos, LegacySubmission,
     },
+    process::facet::{console_facets, facet_values, Facet},
     template::listing_part::ListingPart,
-    template::{listing_entry_cell::ListingEntryCell, listing_photos_cell::ListingPhotosCell},
+    template::{
+        listing_entry_cell::ListingEntryCell, listing_filters::ListingFilters,
+        listing_photos_cell::ListingPhotosCell, mainboard_page::mainboard_url,
+    },
 };
 
 pub struct ConsoleSubmissionList<'a, M, P> {
@@ -41,9 +45,16 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for ConsoleSubmission
     fn render(&self) -> Markup {
         let console = M::CONSOLE;
         let parts = M::parts();
+        let facets = console_facets();
+        let submissions = self.submissions.iter().collect::<Vec<_>>();
         html! {
             article {
                 h2 { (console.name()) " (" (console.code()) ")" }
+                (ListingFilters {
+                    facets: &facets,
+                    submissions: &submissions,
+                    compare_kind: console.id(),
+                })
                 table {
                     thead {
                         tr {
@@ -66,14 +77,31 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for ConsoleSubmission
                             (Submission {
                                 submission,
                                 parts: &parts,
+                                facets: &facets,
                                 extra_cells: &self.extra_cells,
                                 render_console_column: self.render_console_column
                             })
                         }
                     }
                 }
+                h3 { "Statistics" }
+                a href={ "/consoles/" (console.id()) "/statistics.html" } { "Charts" }
+                br;
+                a href={ "/consoles/" (console.id()) "/timeline.html" } { "Production timeline" }
+                br;
+                @if self.submissions.iter().any(|submission| submission.metadata.shell().serial.is_some()) {
+                    a href={ "/consoles/" (console.id()) "/serials.html" } { "Serial numbers and revisions" }
+                    br;
+                    a href="/consoles/lookup.html" { "What's inside my unit?" }
+                    br;
+                }
+                a href={ "/consoles/" (console.id()) "/origins.html" } { "Part origins by year" }
+                br;
+                a href={ "/consoles/" (console.id()) "/matrix/index.html" } { "Parts matrix" }
                 h3 { "Data dumps " }
                 a href={ "/static/export/consoles/" (console.id()) ".csv" } { "UTF-8 encoded CSV" }
+                br;
+                a href={ "/static/export/consoles/" (console.id()) ".json" } { "JSON with nested boards and parts" }
             }
         }
     }
@@ -82,6 +110,7 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for ConsoleSubmission
 struct Submission<'a, M: LegacyConsoleMetadata, P> {
     pub submission: &'a LegacySubmission<M, P>,
     pub parts: &'a [ChipInfo<M>],
+    pub facets: &'a [Facet<M, P>],
     pub render_console_column: bool,
     pub extra_cells: &'a [Box<dyn Fn(&M) -> Markup>],
 }
@@ -92,8 +121,9 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for Submission<'a, M,
         let lcd_date_code = metadata
             .lcd_panel()
             .and_then(|panel| panel.date_code().calendar());
+        let facets = facet_values(self.facets, self.submission);
         html! {
-            tr {
+            tr data-facets=(serde_json::to_string(&facets).unwrap_or_default()) data-slug=(self.submission.slug) {
                 (ListingEntryCell {
                     url_prefix: "/consoles",
                     primary_text: &self.submission.title,
@@ -117,7 +147,11 @@ impl<'a, M: LegacyConsoleMetadata, P: LegacyPhotos> Render for Submission<'a, M,
                     }
                 }
                 td {
-                    div { (metadata.mainboard().kind) }
+                    div {
+                        a href=(mainboard_url(M::CONSOLE, metadata.mainboard().kind)) {
+                            (metadata.mainboard().kind)
+                        }
+                    }
                     @if let Some(date_code) = metadata.mainboard().date_code.calendar() {
                         div { (date_code) }
                     }
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+use slug::slugify;
+use time::{macros::format_description, Date};
+
+use crate::template::Optional;
+
+pub fn contributor_url(contributor: &str) -> String {
+    format!("/contributors/{}.html", slugify(contributor))
+}
+
+fn format_date(date: Date) -> String {
+    date.format(format_description!(
+        "[month repr:long] [day padding:none], [year]"
+    ))
+    .unwrap_or_else(|_| "?".to_string())
+}
+
+/// A console or cartridge submission made by a contributor
+pub struct ContributorEntry {
+    /// Console name or "Game cartridges"
+    pub group: &'static str,
+    /// Rendered listing entry cell
+    pub cell: Markup,
+    pub added: Option<Date>,
+}
+
+pub struct ContributorPage<'a> {
+    pub contributor: &'a str,
+    pub entries: &'a [ContributorEntry],
+}
+
+impl<'a> Render for ContributorPage<'a> {
+    fn render(&self) -> Markup {
+        let first_contribution = self.entries.iter().filter_map(|entry| entry.added).min();
+        let groups = self
+            .entries
+            .iter()
+            .group_by(|entry| entry.group)
+            .into_iter()
+            .map(|(group, entries)| (group, entries.collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { (self.contributor) }
+                dl {
+                    dt { "Submissions" }
+                    dd { (self.entries.len()) }
+                    @if let Some(date) = first_contribution {
+                        dt { "First contribution" }
+                        dd { (format_date(date)) }
+                    }
+                }
+                table {
+                    thead {
+                        tr {
+                            th { "Category" }
+                            th { "Submissions" }
+                        }
+                    }
+                    tbody {
+                        @for (group, entries) in &groups {
+                            tr {
+                                td { (group) }
+                                td { (entries.len()) }
+                            }
+                        }
+                    }
+                }
+                @for (group, entries) in &groups {
+                    h3 { (group) }
+                    table {
+                        thead {
+                            tr {
+                                th { "Entry" }
+                                th { "Added" }
+                            }
+                        }
+                        tbody {
+                            @for entry in entries {
+                                tr {
+                                    (entry.cell)
+                                    td { (Optional(entry.added.map(format_date))) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Index of all contributors
+pub struct Contributors<'a> {
+    pub contributors: &'a [(&'a str, Vec<ContributorEntry>)],
+}
+
+impl<'a> Render for Contributors<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            article {
+                h2 { "Contributors" }
+                table {
+                    thead {
+                        tr {
+                            th { "Contributor" }
+                            th { "Submissions" }
+                            th { "First contribution" }
+                        }
+                    }
+                    tbody {
+                        @for (contributor, entries) in self.contributors {
+                            @let first_contribution = entries.iter().filter_map(|entry| entry.added).min();
+                            tr {
+                                td { a href=(contributor_url(contributor)) { (contributor) } }
+                                td { (entries.len()) }
+                                td { (Optional(first_contribution.map(format_date))) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,11 +6,12 @@ use gbhwdb_backend::config::cartridge::{GameConfig, PartRoleConfig};
 use maud::{html, Markup, Render};
 
 use crate::{
-    legacy::LegacyCartridgeSubmission,
+    legacy::{cartridge, LegacyCartridgeSubmission, LegacyDefaultPhotos},
+    process::facet::{cartridge_facets, facet_values, Facet},
     template::Optional,
     template::{
-        listing_entry_cell::ListingEntryCell, listing_part::ListingPart,
-        listing_photos_cell::ListingPhotosCell,
+        listing_entry_cell::ListingEntryCell, listing_filters::ListingFilters,
+        listing_part::ListingPart, listing_photos_cell::ListingPhotosCell,
     },
 };
 
@@ -24,9 +25,15 @@ impl<'a> Render for Game<'a> {
     fn render(&self) -> Markup {
         let layout = self.cfg.layouts[0];
         let parts = PartRoleConfig::from(layout);
+        let facets = cartridge_facets();
         html! {
             article {
                 h2 { (self.cfg.name) }
+                (ListingFilters {
+                    facets: &facets,
+                    submissions: &self.submissions,
+                    compare_kind: &self.cfg.rom_id,
+                })
                 table {
                     thead {
                         tr {
@@ -41,7 +48,7 @@ impl<'a> Render for Game<'a> {
                     }
                     tbody {
                         @for submission in &self.submissions {
-                            (render_submission(submission, &parts))
+                            (render_submission(submission, &parts, &facets))
                         }
                     }
                 }
@@ -50,10 +57,15 @@ impl<'a> Render for Game<'a> {
     }
 }
 
-fn render_submission(submission: &LegacyCartridgeSubmission, parts: &PartRoleConfig) -> Markup {
+fn render_submission(
+    submission: &LegacyCartridgeSubmission,
+    parts: &PartRoleConfig,
+    facets: &[Facet<cartridge::LegacyMetadata, LegacyDefaultPhotos>],
+) -> Markup {
     let metadata = &submission.metadata;
+    let facets = facet_values(facets, submission);
     html! {
-        tr {
+        tr data-facets=(serde_json::to_string(&facets).unwrap_or_default()) data-slug=(submission.slug) {
             (ListingEntryCell {
                 url_prefix: "/cartridges",
                 primary_text: &submission.title,
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -30,6 +30,8 @@ impl Render for Home {
                 "Console submission count: " (self.console_submission_count)
                 br;
                 "Cartridge submission count: " (self.cartridge_submission_count)
+                br;
+                a href="/statistics/index.html" { "More statistics" }
             }
         }
     }
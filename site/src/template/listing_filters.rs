@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+
+use crate::{
+    legacy::LegacySubmission, process::facet::Facet, template::comparison::comparison_path,
+};
+
+/// Filters for a listing table, whose rows carry their facet values in a `data-facets`
+/// attribute and their slug in a `data-slug` attribute.
+///
+/// The form stays hidden until listing-filters.js enables it, so the full table is still usable
+/// without JavaScript. Comparison pages only exist on the development server, so the script adds
+/// the compare button and a checkbox to every row only when the server answers for the
+/// comparison path.
+pub struct ListingFilters<'a, M, P> {
+    pub facets: &'a [Facet<M, P>],
+    pub submissions: &'a [&'a LegacySubmission<M, P>],
+    /// Console id or game code used in comparison page paths
+    pub compare_kind: &'a str,
+}
+
+impl<'a, M, P> Render for ListingFilters<'a, M, P> {
+    fn render(&self) -> Markup {
+        let facets = self
+            .facets
+            .iter()
+            .map(|facet| {
+                let values = self
+                    .submissions
+                    .iter()
+                    .flat_map(|submission| (facet.getter)(submission))
+                    .unique()
+                    .sorted()
+                    .collect::<Vec<_>>();
+                (facet, values)
+            })
+            // a single value would not filter anything out
+            .filter(|(_, values)| values.len() > 1)
+            .collect::<Vec<_>>();
+        html! {
+            form.listing-filters hidden {
+                @for (facet, values) in &facets {
+                    label {
+                        (facet.label)
+                        select data-facet=(facet.key) {
+                            option value="" { "Any" }
+                            @for value in values {
+                                option value=(value) { (value) }
+                            }
+                        }
+                    }
+                }
+                @if !facets.is_empty() {
+                    button type="reset" { "Clear" }
+                }
+                output.listing-filters__count {}
+                button.listing-filters__compare type="button" hidden disabled
+                    data-url=(format!("/{}", comparison_path(self.compare_kind, &[]))) {
+                    "Compare selected"
+                }
+            }
+            script src="/listing-filters.js" defer {}
+        }
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::{parser::Manufacturer, Console};
+use maud::{html, Markup, Render};
+use slug::slugify;
+use std::collections::BTreeMap;
+
+use crate::{
+    legacy::{console::LegacyConsoleMetadata, LegacyPhotos, LegacySubmission},
+    template::{
+        chart::{year_buckets, year_span},
+        listing_entry_cell::ListingEntryCell,
+        part_page::part_url,
+        Optional,
+    },
+};
+
+pub fn mainboard_url(console: Console, kind: &str) -> String {
+    format!("/consoles/{}/boards/{}.html", console.id(), slugify(kind))
+}
+
+/// All submissions of one console with the same mainboard
+pub struct MainboardPage<'a, M, P> {
+    pub kind: &'a str,
+    pub submissions: &'a [&'a LegacySubmission<M, P>],
+}
+
+impl<'a, M, P> Render for MainboardPage<'a, M, P>
+where
+    M: LegacyConsoleMetadata,
+    P: LegacyPhotos,
+{
+    fn render(&self) -> Markup {
+        let board_dates =
+            year_span(&year_buckets(self.submissions.iter().filter_map(
+                |submission| submission.metadata.mainboard().date_code.year,
+            )));
+        let chips = M::parts()
+            .into_iter()
+            .filter_map(|chip| {
+                let parts = self
+                    .submissions
+                    .iter()
+                    .filter_map(|submission| (chip.getter)(&submission.metadata))
+                    .collect::<Vec<_>>();
+                let mut variants: BTreeMap<(Option<&str>, Option<Manufacturer>), usize> =
+                    BTreeMap::new();
+                for part in &parts {
+                    let kind = part.kind.as_deref().filter(|_| !chip.hide_type);
+                    *variants.entry((kind, part.manufacturer)).or_default() += 1;
+                }
+                let dates = year_span(&year_buckets(
+                    parts.iter().filter_map(|part| part.date_code.year),
+                ));
+                (!parts.is_empty()).then_some((chip, variants, dates))
+            })
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { (M::CONSOLE.code()) ": " (self.kind) }
+                dl {
+                    dt { "Submissions" }
+                    dd { (self.submissions.len()) }
+                    @if let Some(dates) = board_dates {
+                        dt { "Board dates" }
+                        dd { (dates) }
+                    }
+                }
+                h3 { "Parts" }
+                table {
+                    thead {
+                        tr {
+                            th { "Part" }
+                            th { "Seen on this board" }
+                            th { "Dates" }
+                        }
+                    }
+                    tbody {
+                        @for (chip, variants, dates) in &chips {
+                            tr {
+                                td { (chip.label) " (" (chip.designator) ")" }
+                                td {
+                                    @for ((kind, manufacturer), count) in variants {
+                                        div {
+                                            @if let Some(kind) = kind {
+                                                a href=(part_url(kind)) { (kind) } " "
+                                            }
+                                            @if let Some(manufacturer) = manufacturer {
+                                                small { (manufacturer.name()) } " "
+                                            }
+                                            @if kind.is_none() && manufacturer.is_none() {
+                                                "Unknown "
+                                            }
+                                            "×" (count)
+                                        }
+                                    }
+                                }
+                                td { (Optional(dates.as_ref())) }
+                            }
+                        }
+                    }
+                }
+                h3 { "Submissions" }
+                table {
+                    thead {
+                        tr {
+                            th { "Entry" }
+                            th { "Board date" }
+                        }
+                    }
+                    tbody {
+                        @for submission in self.submissions {
+                            tr {
+                                (ListingEntryCell {
+                                    url_prefix: "/consoles",
+                                    primary_text: &submission.title,
+                                    secondary_texts: &[],
+                                    submission,
+                                })
+                                td { (Optional(submission.metadata.mainboard().date_code.calendar())) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
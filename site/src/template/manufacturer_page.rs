@@ -0,0 +1,173 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::parser::Manufacturer;
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+use std::collections::BTreeMap;
+
+use crate::template::{
+    chart::{TimelineChart, TimelinePoint},
+    part_page::{part_url, PartUse},
+    Optional,
+};
+
+pub fn manufacturer_url(manufacturer: Manufacturer) -> String {
+    format!("/manufacturers/{}.html", manufacturer.id())
+}
+
+/// Parts supplied by one manufacturer, grouped by part kind
+pub type ManufacturerParts<'a> = BTreeMap<&'a str, Vec<&'a PartUse<'a>>>;
+
+pub struct ManufacturerPage<'a> {
+    pub manufacturer: Manufacturer,
+    pub parts: &'a ManufacturerParts<'a>,
+}
+
+impl<'a> Render for ManufacturerPage<'a> {
+    fn render(&self) -> Markup {
+        let manufacturer = self.manufacturer;
+        html! {
+            article {
+                h2 { (manufacturer.name()) }
+                @if let Some(country) = manufacturer.country() {
+                    p { "Headquartered in " (country) }
+                }
+                dl {
+                    @if !manufacturer.aliases().is_empty() {
+                        dt { "Printed as" }
+                        dd { (manufacturer.aliases().join(", ")) }
+                    }
+                    @if let Some(logo) = manufacturer.logo() {
+                        dt { "Logo" }
+                        dd { (logo) }
+                    }
+                    @if !manufacturer.date_code_conventions().is_empty() {
+                        dt { "Date code formats" }
+                        dd {
+                            (manufacturer.date_code_conventions().iter().map(|convention| convention.name()).join(", "))
+                        }
+                    }
+                }
+                @if self.parts.is_empty() {
+                    p { "No parts made by this manufacturer have been submitted yet." }
+                } @else {
+                    h3 { "Timeline" }
+                    (Timeline { parts: self.parts })
+                    h3 { "Parts" }
+                    table {
+                        thead {
+                            tr {
+                                th { "Part" }
+                                th { "Count" }
+                                th { "Submissions" }
+                                th { "First seen" }
+                                th { "Last seen" }
+                            }
+                        }
+                        tbody {
+                            @for (kind, uses) in self.parts {
+                                @let years = || uses.iter().filter_map(|part_use| part_use.part.date_code.year);
+                                tr {
+                                    td { a href=(part_url(kind)) { (kind) } }
+                                    td { (uses.len()) }
+                                    td { (count_submissions(uses)) }
+                                    td { (Optional(years().min())) }
+                                    td { (Optional(years().max())) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn count_submissions(uses: &[&PartUse]) -> usize {
+    let mut urls = uses
+        .iter()
+        .map(|part_use| part_use.url.as_str())
+        .collect::<Vec<_>>();
+    urls.sort_unstable();
+    urls.dedup();
+    urls.len()
+}
+
+/// Dates of manufacture of the parts, one lane per part kind
+struct Timeline<'a> {
+    parts: &'a ManufacturerParts<'a>,
+}
+
+impl<'a> Render for Timeline<'a> {
+    fn render(&self) -> Markup {
+        let mut lanes = Vec::new();
+        let mut points = Vec::new();
+        for (&kind, uses) in self.parts {
+            let lane = lanes.len();
+            let len = points.len();
+            points.extend(uses.iter().filter_map(|part_use| {
+                let date_code = &part_use.part.date_code;
+                Some(TimelinePoint {
+                    lane,
+                    series: 0,
+                    x: date_code.fractional_year()?,
+                    tooltip: format!(
+                        "{kind}: {date} ({source}, {title})",
+                        date = date_code.calendar().unwrap_or_default(),
+                        source = part_use.source,
+                        title = part_use.title,
+                    ),
+                })
+            }));
+            if points.len() > len {
+                lanes.push(kind.to_owned());
+            }
+        }
+        if points.is_empty() {
+            return html! { p { "No parts with a known year of manufacture." } };
+        }
+        TimelineChart {
+            lanes: &lanes,
+            series: &[],
+            points: &points,
+        }
+        .render()
+    }
+}
+
+/// Index of all manufacturers with submitted parts
+pub struct Manufacturers<'a> {
+    pub parts: &'a BTreeMap<Manufacturer, ManufacturerParts<'a>>,
+}
+
+impl<'a> Render for Manufacturers<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            article {
+                h2 { "Manufacturers" }
+                table {
+                    thead {
+                        tr {
+                            th { "Manufacturer" }
+                            th { "Country" }
+                            th { "Part kinds" }
+                            th { "Parts" }
+                        }
+                    }
+                    tbody {
+                        @for (&manufacturer, parts) in self.parts {
+                            tr {
+                                td { a href=(manufacturer_url(manufacturer)) { (manufacturer.name()) } }
+                                td { (Optional(manufacturer.country())) }
+                                td { (parts.len()) }
+                                td { (parts.values().map(|uses| uses.len()).sum::<usize>()) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
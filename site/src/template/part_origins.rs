@@ -0,0 +1,89 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::parser::Country;
+use maud::{html, Markup, Render};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::legacy::{console::LegacyConsoleMetadata, LegacySubmission};
+
+/// Countries of origin of each console part, grouped by year of manufacture
+pub struct PartOrigins<'a, M, P> {
+    pub submissions: &'a [LegacySubmission<M, P>],
+}
+
+impl<'a, M, P> PartOrigins<'a, M, P> {
+    pub fn new(submissions: &'a [LegacySubmission<M, P>]) -> Self {
+        PartOrigins { submissions }
+    }
+}
+
+impl<'a, M: LegacyConsoleMetadata, P> Render for PartOrigins<'a, M, P> {
+    fn render(&self) -> Markup {
+        let console = M::CONSOLE;
+        let tables = M::parts()
+            .into_iter()
+            .filter_map(|chip| {
+                let mut counts: BTreeMap<Option<u16>, BTreeMap<Country, usize>> = BTreeMap::new();
+                for submission in self.submissions {
+                    let Some(part) = (chip.getter)(&submission.metadata) else {
+                        continue;
+                    };
+                    if let Some(country) = part.country {
+                        *counts
+                            .entry(part.date_code.year)
+                            .or_default()
+                            .entry(country)
+                            .or_default() += 1;
+                    }
+                }
+                let countries = counts
+                    .values()
+                    .flat_map(|by_country| by_country.keys().copied())
+                    .collect::<BTreeSet<_>>();
+                (!counts.is_empty()).then_some((chip, countries, counts))
+            })
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { (console.name()) " (" (console.code()) "): part origins" }
+                p {
+                    "Countries of origin printed on part labels, grouped by the year of manufacture of each part. "
+                    "Parts without a country on the label are not included."
+                }
+                @if tables.is_empty() {
+                    p { "No parts with a country of origin have been submitted yet." }
+                }
+                @for (chip, countries, counts) in &tables {
+                    h3 { (chip.label) " (" (chip.designator) ")" }
+                    table {
+                        thead {
+                            tr {
+                                th { "Year" }
+                                @for country in countries {
+                                    th { (country.name()) }
+                                }
+                            }
+                        }
+                        tbody {
+                            @for (year, by_country) in counts {
+                                tr {
+                                    td {
+                                        @match year {
+                                            Some(year) => (year),
+                                            None => "Unknown",
+                                        }
+                                    }
+                                    @for country in countries {
+                                        td { (by_country.get(country).copied().unwrap_or_default()) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::config::part_catalog::PartInfo;
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+use slug::slugify;
+use std::collections::BTreeMap;
+
+use crate::{
+    process::part::ProcessedPart,
+    template::{
+        chart::{year_buckets, year_columns, ColumnChart},
+        contributor_page::contributor_url,
+        manufacturer_page::manufacturer_url,
+        Optional,
+    },
+};
+
+/// File name of the page of a part kind, without the .html extension
+pub fn part_slug(kind: &str) -> String {
+    // slugify would turn the micro sign in μPD part numbers into "m"
+    slugify(kind.replace('μ', "u"))
+}
+
+pub fn part_url(kind: &str) -> String {
+    format!("/parts/{}.html", part_slug(kind))
+}
+
+/// A part of some kind found in a console or cartridge submission
+pub struct PartUse<'a> {
+    /// Console code or game name
+    pub source: &'a str,
+    pub title: &'a str,
+    pub contributor: &'a str,
+    pub url: String,
+    pub designator: &'static str,
+    pub part: &'a ProcessedPart,
+}
+
+pub struct PartPage<'a> {
+    pub kind: &'a str,
+    pub info: Option<&'static PartInfo>,
+    pub uses: &'a [PartUse<'a>],
+}
+
+impl<'a> Render for PartPage<'a> {
+    fn render(&self) -> Markup {
+        let compatible = self
+            .info
+            .and_then(|info| info.pinout)
+            .map(|pinout| {
+                PartInfo::all()
+                    .iter()
+                    .filter(|other| other.kind != self.kind && other.pinout == Some(pinout))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        html! {
+            article {
+                h2 { (self.kind) }
+                @if let Some(info) = self.info {
+                    dl {
+                        dt { "Function" }
+                        dd { (info.function) }
+                        dt { "Package" }
+                        dd { (info.package.unwrap_or("Unknown")) }
+                        dt { "Manufacturer" }
+                        dd { (info.manufacturer.map(|m| m.name()).unwrap_or("Various")) }
+                        @if let Some(pinout) = info.pinout {
+                            dt { "Pinout" }
+                            dd { (pinout) }
+                        }
+                        @if let Some(datasheet) = info.datasheet {
+                            dt { "Datasheet" }
+                            dd { a href={ "/" (datasheet) } { "PDF" } }
+                        }
+                    }
+                }
+                @if !compatible.is_empty() {
+                    h3 { "Pin-compatible parts" }
+                    ul {
+                        @for other in compatible {
+                            li { a href=(part_url(other.kind)) { (other.kind) } }
+                        }
+                    }
+                }
+                @if !self.uses.is_empty() {
+                    h3 { "Date codes" }
+                    (DateHistogram { uses: self.uses })
+                    h3 { "Found in" }
+                    table {
+                        thead {
+                            tr {
+                                th { "Device" }
+                                th { "Entry" }
+                                th { "Part" }
+                                th { "Manufacturer" }
+                                th { "Date" }
+                                th { "Label" }
+                            }
+                        }
+                        tbody {
+                            @for part_use in self.uses {
+                                tr {
+                                    td { (part_use.source) }
+                                    td {
+                                        a href=(part_use.url) { (part_use.title) }
+                                        br;
+                                        small {
+                                            a href=(contributor_url(part_use.contributor)) { (part_use.contributor) }
+                                        }
+                                    }
+                                    td { (part_use.designator) }
+                                    td {
+                                        @if let Some(manufacturer) = part_use.part.manufacturer {
+                                            a href=(manufacturer_url(manufacturer)) { (manufacturer.name()) }
+                                        }
+                                    }
+                                    td { (Optional(part_use.part.date_code.calendar())) }
+                                    td { (Optional(part_use.part.label.as_ref())) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Number of parts per year of manufacture
+struct DateHistogram<'a> {
+    uses: &'a [PartUse<'a>],
+}
+
+impl<'a> Render for DateHistogram<'a> {
+    fn render(&self) -> Markup {
+        let years = || {
+            self.uses
+                .iter()
+                .map(|part_use| part_use.part.date_code.year)
+        };
+        let mut columns = year_columns(&year_buckets(years().flatten()));
+        let unknown = years().filter(Option::is_none).count();
+        if unknown > 0 {
+            columns.push(("Unknown".to_owned(), unknown));
+        }
+        ColumnChart { columns: &columns }.render()
+    }
+}
+
+/// Index of all part kinds, grouped by function
+pub struct PartCatalog<'a> {
+    /// Number of parts of each kind in all submissions
+    pub counts: &'a BTreeMap<&'a str, usize>,
+}
+
+impl<'a> Render for PartCatalog<'a> {
+    fn render(&self) -> Markup {
+        let groups = PartInfo::all()
+            .iter()
+            .sorted_by_key(|info| info.function)
+            .group_by(|info| info.function);
+        let others = self
+            .counts
+            .iter()
+            .filter(|(kind, _)| PartInfo::from_kind(kind).is_none())
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { "Parts" }
+                @for (function, parts) in &groups {
+                    h3 { (function) }
+                    table {
+                        thead {
+                            tr {
+                                th { "Part" }
+                                th { "Package" }
+                                th { "Manufacturer" }
+                                th { "Pinout" }
+                                th { "Count" }
+                            }
+                        }
+                        tbody {
+                            @for info in parts {
+                                tr {
+                                    td { a href=(part_url(info.kind)) { (info.kind) } }
+                                    td { (Optional(info.package)) }
+                                    td { (Optional(info.manufacturer.map(|m| m.name()))) }
+                                    td { (Optional(info.pinout)) }
+                                    td { (self.counts.get(info.kind).copied().unwrap_or(0)) }
+                                }
+                            }
+                        }
+                    }
+                }
+                @if !others.is_empty() {
+                    h3 { "Other parts" }
+                    table {
+                        thead {
+                            tr {
+                                th { "Part" }
+                                th { "Count" }
+                            }
+                        }
+                        tbody {
+                            @for (kind, count) in others {
+                                tr {
+                                    td { a href=(part_url(kind)) { (kind) } }
+                                    td { (count) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
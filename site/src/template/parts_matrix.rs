@@ -0,0 +1,189 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::Console;
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+use slug::slugify;
+use std::collections::BTreeMap;
+
+use crate::legacy::{
+    console::{AttributeInfo, LegacyConsoleMetadata},
+    LegacySubmission,
+};
+
+pub fn matrix_url(console: Console, row: &str, column: &str) -> String {
+    format!(
+        "/consoles/{}/matrix/{}.html",
+        console.id(),
+        matrix_slug(row, column)
+    )
+}
+
+pub fn matrix_slug(row: &str, column: &str) -> String {
+    format!("{}--{}", slugify(row), slugify(column))
+}
+
+/// Value of an attribute, or "Unknown"
+fn value<'a, M>(attribute: &AttributeInfo<M>, metadata: &'a M) -> &'a str {
+    (attribute.getter)(metadata).unwrap_or("Unknown")
+}
+
+/// Pairs of attributes worth cross-tabulating, as indexes to `attributes`.
+///
+/// An attribute needs at least two different known values, otherwise the table would only have
+/// a single row or column.
+pub fn matrix_pairs<M, P>(
+    attributes: &[AttributeInfo<M>],
+    submissions: &[LegacySubmission<M, P>],
+) -> Vec<(usize, usize)> {
+    let varying = attributes
+        .iter()
+        .enumerate()
+        .filter(|(_, attribute)| {
+            submissions
+                .iter()
+                .filter_map(|submission| (attribute.getter)(&submission.metadata))
+                .unique()
+                .nth(1)
+                .is_some()
+        })
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    varying.into_iter().tuple_combinations().collect()
+}
+
+/// Index of all cross-tabulations of one console
+pub struct MatrixIndex<'a, M> {
+    pub attributes: &'a [AttributeInfo<M>],
+    pub pairs: &'a [(usize, usize)],
+}
+
+impl<'a, M: LegacyConsoleMetadata> Render for MatrixIndex<'a, M> {
+    fn render(&self) -> Markup {
+        let console = M::CONSOLE;
+        let rows = self
+            .pairs
+            .iter()
+            .map(|&(row, _)| row)
+            .unique()
+            .collect::<Vec<_>>();
+        let columns = self
+            .pairs
+            .iter()
+            .map(|&(_, column)| column)
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { (console.name()) " (" (console.code()) "): parts matrix" }
+                p {
+                    "Pick two properties to see how often each combination appears in the submissions. "
+                    "Properties that have the same value in every submission are left out."
+                }
+                @if self.pairs.is_empty() {
+                    p { "Not enough submissions yet." }
+                } @else {
+                    table.parts-matrix {
+                        thead {
+                            tr {
+                                th {}
+                                @for &column in &columns {
+                                    th { (self.attributes[column].label) }
+                                }
+                            }
+                        }
+                        tbody {
+                            @for &row in &rows {
+                                tr {
+                                    th { (self.attributes[row].label) }
+                                    @for &column in &columns {
+                                        td {
+                                            @if self.pairs.contains(&(row, column)) {
+                                                a href=(matrix_url(console, &self.attributes[row].label, &self.attributes[column].label)) { "×" }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Counts of every combination of two attributes, with links to the matching submissions
+pub struct Matrix<'a, M, P> {
+    pub row: &'a AttributeInfo<M>,
+    pub column: &'a AttributeInfo<M>,
+    pub submissions: &'a [LegacySubmission<M, P>],
+}
+
+impl<'a, M: LegacyConsoleMetadata, P> Render for Matrix<'a, M, P> {
+    fn render(&self) -> Markup {
+        let console = M::CONSOLE;
+        let mut cells: BTreeMap<(&str, &str), Vec<&LegacySubmission<M, P>>> = BTreeMap::new();
+        for submission in self.submissions {
+            let row = value(self.row, &submission.metadata);
+            let column = value(self.column, &submission.metadata);
+            cells.entry((row, column)).or_default().push(submission);
+        }
+        let rows = cells
+            .keys()
+            .map(|&(row, _)| row)
+            .unique()
+            .collect::<Vec<_>>();
+        let columns = cells
+            .keys()
+            .map(|&(_, column)| column)
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { (console.code()) ": " (self.row.label) " × " (self.column.label) }
+                p {
+                    a href={ "/consoles/" (console.id()) "/matrix/index.html" } { "All combinations" }
+                }
+                table.parts-matrix {
+                    thead {
+                        tr {
+                            th { (self.row.label) " \\ " (self.column.label) }
+                            @for column in &columns {
+                                th { (column) }
+                            }
+                            th { "Total" }
+                        }
+                    }
+                    tbody {
+                        @for row in &rows {
+                            tr {
+                                th { (row) }
+                                @for column in &columns {
+                                    td {
+                                        @if let Some(submissions) = cells.get(&(*row, *column)) {
+                                            details {
+                                                summary { (submissions.len()) }
+                                                @for submission in submissions {
+                                                    a href={ "/consoles/" (submission.code) "/" (submission.slug) ".html" } { (submission.title) }
+                                                    br;
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                                td {
+                                    (cells.iter().filter(|((r, _), _)| r == row).map(|(_, submissions)| submissions.len()).sum::<usize>())
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+
+use crate::{
+    legacy::{
+        console::{AssemblyDate, LegacyConsoleMetadata},
+        LegacySubmission,
+    },
+    template::{
+        chart::{TimelineChart, TimelinePoint},
+        Optional,
+    },
+};
+
+/// A dated unit on the timeline
+struct Unit<'a> {
+    title: &'a str,
+    board: &'a str,
+    cpu: &'a str,
+    date: AssemblyDate,
+    x: f64,
+}
+
+/// Assembly dates of all submitted units of one console against their hardware revisions
+pub struct ProductionTimeline<'a, M, P> {
+    pub submissions: &'a [LegacySubmission<M, P>],
+}
+
+impl<'a, M: LegacyConsoleMetadata, P> Render for ProductionTimeline<'a, M, P> {
+    fn render(&self) -> Markup {
+        let console = M::CONSOLE;
+        let cpu_chip = M::parts().into_iter().next();
+        let units = self
+            .submissions
+            .iter()
+            .filter_map(|submission| {
+                let metadata = &submission.metadata;
+                let date = metadata.assembly_date()?;
+                let cpu = cpu_chip
+                    .as_ref()
+                    .and_then(|chip| (chip.getter)(metadata))
+                    .and_then(|part| part.kind.as_deref())
+                    .unwrap_or("Unknown");
+                Some(Unit {
+                    title: &submission.title,
+                    board: metadata.mainboard().kind,
+                    cpu,
+                    x: date.date_code.fractional_year()?,
+                    date,
+                })
+            })
+            .sorted_by(|a, b| a.x.total_cmp(&b.x))
+            .collect::<Vec<_>>();
+        let boards = units
+            .iter()
+            .map(|unit| unit.board)
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+        let cpus = units
+            .iter()
+            .map(|unit| unit.cpu)
+            .unique()
+            .sorted()
+            .collect::<Vec<_>>();
+        let sources = units
+            .iter()
+            .map(|unit| unit.date.source)
+            .counts()
+            .into_iter()
+            .sorted_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)))
+            .collect::<Vec<_>>();
+        let cpu_label = cpu_chip.as_ref().map(|chip| chip.label).unwrap_or("CPU");
+        html! {
+            article {
+                h2 { (console.name()) " (" (console.code()) "): production timeline" }
+                p {
+                    "Estimated assembly dates of " (units.len()) " of " (self.submissions.len()) " submitted units"
+                    @if !sources.is_empty() {
+                        ", dated by "
+                        (sources.iter().map(|(source, count)| format!("{source} ({count})")).join(", "))
+                    }
+                    "."
+                }
+                @if units.is_empty() {
+                    p { "No units with a known date yet." }
+                } @else {
+                    h3 { "By mainboard revision" }
+                    (timeline(&units, &boards, &cpus, |unit| unit.board, |unit| unit.cpu))
+                    (revision_table("Mainboard", &units, &boards, |unit| unit.board))
+                    h3 { "By " (cpu_label) " revision" }
+                    (timeline(&units, &cpus, &boards, |unit| unit.cpu, |unit| unit.board))
+                    (revision_table(cpu_label, &units, &cpus, |unit| unit.cpu))
+                }
+            }
+        }
+    }
+}
+
+fn timeline<'a>(
+    units: &[Unit<'a>],
+    lanes: &[&'a str],
+    series: &[&'a str],
+    lane_of: impl Fn(&Unit<'a>) -> &'a str,
+    series_of: impl Fn(&Unit<'a>) -> &'a str,
+) -> Markup {
+    let points = units
+        .iter()
+        .map(|unit| TimelinePoint {
+            lane: lanes
+                .iter()
+                .position(|&lane| lane == lane_of(unit))
+                .unwrap_or(0),
+            series: series
+                .iter()
+                .position(|&s| s == series_of(unit))
+                .unwrap_or(0),
+            x: unit.x,
+            tooltip: format!(
+                "{title}: {board}, {cpu}, {date} ({source})",
+                title = unit.title,
+                board = unit.board,
+                cpu = unit.cpu,
+                date = unit.date.date_code.calendar().unwrap_or_default(),
+                source = unit.date.source,
+            ),
+        })
+        .collect::<Vec<_>>();
+    let to_strings = |values: &[&str]| {
+        values
+            .iter()
+            .map(|&value| value.to_owned())
+            .collect::<Vec<_>>()
+    };
+    TimelineChart {
+        lanes: &to_strings(lanes),
+        series: &to_strings(series),
+        points: &points,
+    }
+    .render()
+}
+
+/// Earliest and latest unit of each revision
+fn revision_table<'a>(
+    label: &str,
+    units: &[Unit<'a>],
+    revisions: &[&'a str],
+    revision_of: impl Fn(&Unit<'a>) -> &'a str,
+) -> Markup {
+    html! {
+        table {
+            thead {
+                tr {
+                    th { (label) }
+                    th { "Units" }
+                    th { "Earliest" }
+                    th { "Latest" }
+                }
+            }
+            tbody {
+                @for &revision in revisions {
+                    @let matching = units.iter().filter(|unit| revision_of(unit) == revision).collect::<Vec<_>>();
+                    tr {
+                        td { (revision) }
+                        td { (matching.len()) }
+                        td { (Optional(matching.first().map(|unit| UnitDate(&unit.date)))) }
+                        td { (Optional(matching.last().map(|unit| UnitDate(&unit.date)))) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Assembly date of a unit together with where it comes from
+struct UnitDate<'a>(&'a AssemblyDate);
+
+impl<'a> Render for UnitDate<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            (Optional(self.0.date_code.calendar()))
+            br;
+            small { "from " (self.0.source) }
+        }
+    }
+}
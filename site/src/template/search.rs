@@ -0,0 +1,32 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use maud::{html, Markup, Render};
+
+/// Search over all submissions that runs client-side using the index written by gbhwdb-site
+pub struct Search;
+
+impl Render for Search {
+    fn render(&self) -> Markup {
+        html! {
+            article.search {
+                h2 { "Search" }
+                p {
+                    "Search by serial number, contributor, game name, ROM code, board or part label, or part type. "
+                    "Every word must match some part of the submission, so "
+                    code { "dmg-cpu sharp" }
+                    " finds units with a DMG-CPU board and at least one Sharp chip."
+                }
+                noscript { p { strong { "This page requires JavaScript." } } }
+                form #search-form {
+                    input #search-query type="search" size="40" autocomplete="off" placeholder="e.g. C10203977, MBC5 or Pokémon";
+                    button type="submit" { "Search" }
+                }
+                p #search-status {}
+                ol #search-results {}
+                script type="module" src="/search.js" {}
+            }
+        }
+    }
+}
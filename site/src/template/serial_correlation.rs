@@ -0,0 +1,161 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::serial::Serial;
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+
+use crate::{
+    legacy::{console::LegacyConsoleMetadata, LegacySubmission},
+    process::serial::serial_runs,
+    template::{
+        chart::{SequenceChart, TimelinePoint},
+        mainboard_page::mainboard_url,
+        part_page::part_url,
+    },
+};
+
+/// A submitted unit with a readable serial
+struct Unit<'a> {
+    serial: Serial<'a>,
+    board: &'a str,
+    cpu: &'a str,
+}
+
+/// Serials of all submitted units of one console against their hardware revisions
+pub struct SerialCorrelation<'a, M, P> {
+    pub submissions: &'a [LegacySubmission<M, P>],
+}
+
+impl<'a, M: LegacyConsoleMetadata, P> Render for SerialCorrelation<'a, M, P> {
+    fn render(&self) -> Markup {
+        let console = M::CONSOLE;
+        let cpu_chip = M::parts().into_iter().next();
+        let units = self
+            .submissions
+            .iter()
+            .filter_map(|submission| {
+                let metadata = &submission.metadata;
+                let serial = Serial::parse(metadata.shell().serial?)?;
+                let cpu = cpu_chip
+                    .as_ref()
+                    .and_then(|chip| (chip.getter)(metadata))
+                    .and_then(|part| part.kind.as_deref())
+                    .unwrap_or("Unknown");
+                Some(Unit {
+                    serial,
+                    board: metadata.mainboard().kind,
+                    cpu,
+                })
+            })
+            .sorted_by_key(|unit| unit.serial)
+            .collect::<Vec<_>>();
+        let prefixes = units
+            .iter()
+            .map(|unit| unit.serial.prefix)
+            .dedup()
+            .collect::<Vec<_>>();
+        let cpu_label = cpu_chip.as_ref().map(|chip| chip.label).unwrap_or("CPU");
+        html! {
+            article {
+                h2 { (console.name()) " (" (console.code()) "): serial numbers" }
+                p {
+                    (units.len()) " of " (self.submissions.len()) " submitted units have a readable serial number. "
+                    "Units are grouped by the letters in front of the serial, since they depend on the region and factory, "
+                    "and the number only runs in sequence within one group. "
+                    "The ranges below only cover submitted units: a revision may have been used before the first "
+                    "or after the last serial listed here, and the exact changeover point lies somewhere in between two runs."
+                }
+                @if units.is_empty() {
+                    p { "No units with a serial number yet." }
+                } @else {
+                    p {
+                        a href={ "/static/export/consoles/" (console.id()) "-serials.csv" } { "Download as CSV" }
+                    }
+                    h3 { "Mainboard revisions" }
+                    (run_table("Mainboard", &units, |unit| unit.board, |board| mainboard_url(console, board)))
+                    h3 { (cpu_label) " revisions" }
+                    (run_table(cpu_label, &units, |unit| unit.cpu, part_url))
+                    @for prefix in &prefixes {
+                        @let units = units.iter().filter(|unit| unit.serial.prefix == *prefix).collect::<Vec<_>>();
+                        h3 { "Serials starting with " (prefix) }
+                        (sequence_chart(&units))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Serial ranges in which each revision was seen, in serial order
+fn run_table<'a>(
+    label: &str,
+    units: &[Unit<'a>],
+    revision_of: impl Fn(&Unit<'a>) -> &'a str,
+    url_of: impl Fn(&str) -> String,
+) -> Markup {
+    let runs = serial_runs(units.iter().map(|unit| (unit.serial, revision_of(unit))));
+    html! {
+        table {
+            thead {
+                tr {
+                    th { "First serial" }
+                    th { "Last serial" }
+                    th { (label) }
+                    th { "Units" }
+                }
+            }
+            tbody {
+                @for run in &runs {
+                    tr {
+                        td { (run.first) }
+                        td { (run.last) }
+                        td {
+                            @if run.value == "Unknown" {
+                                (run.value)
+                            } @else {
+                                a href=(url_of(run.value)) { (run.value) }
+                            }
+                        }
+                        td { (run.units) }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Units with the same serial prefix, one lane per mainboard revision and one color per CPU
+fn sequence_chart(units: &[&Unit]) -> Markup {
+    let boards = units
+        .iter()
+        .map(|unit| unit.board)
+        .unique()
+        .sorted()
+        .collect::<Vec<_>>();
+    let cpus = units
+        .iter()
+        .map(|unit| unit.cpu)
+        .unique()
+        .sorted()
+        .collect::<Vec<_>>();
+    let points = units
+        .iter()
+        .map(|unit| TimelinePoint {
+            lane: boards
+                .iter()
+                .position(|&board| board == unit.board)
+                .unwrap_or(0),
+            series: cpus.iter().position(|&cpu| cpu == unit.cpu).unwrap_or(0),
+            x: f64::from(unit.serial.number),
+            tooltip: format!("{}: {}, {}", unit.serial, unit.board, unit.cpu),
+        })
+        .collect::<Vec<_>>();
+    SequenceChart {
+        lanes: &boards.iter().map(|&board| board.to_owned()).collect_vec(),
+        series: &cpus.iter().map(|&cpu| cpu.to_owned()).collect_vec(),
+        points: &points,
+    }
+    .render()
+}
@@ -1,4 +1,4 @@
-// SPDX-FileCopyrightText: 2017-2023 Joonas Javanainen <joonas.javanainen@gmail.com>
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
 //
 // SPDX-License-Identifier: MIT
 
@@ -23,6 +23,9 @@ impl SiteHeader {
                     li.active[matches!(self.section, SiteSection::Cartridges)] {
                         a href="/cartridges" { "Game cartridges" }
                     }
+                    li.active[matches!(self.section, SiteSection::Search)] {
+                        a href="/search.html" { "Search" }
+                    }
                 }
             }
         }
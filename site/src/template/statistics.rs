@@ -0,0 +1,152 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::{parser::Manufacturer, Console};
+use itertools::Itertools;
+use maud::{html, Markup, Render};
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::{
+    legacy::{console::LegacyConsoleMetadata, LegacySubmission},
+    template::chart::{year_buckets, year_columns, BarChart, ColumnChart, ShareChart},
+};
+
+/// Site-wide statistics
+pub struct Statistics {
+    pub consoles: Vec<(Console, usize)>,
+    pub cartridges: usize,
+}
+
+impl Render for Statistics {
+    fn render(&self) -> Markup {
+        let bars = self
+            .consoles
+            .iter()
+            .map(|(console, count)| (console.name().to_owned(), *count))
+            .chain([("Game cartridges".to_owned(), self.cartridges)])
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { "Statistics" }
+                h3 { "Submissions" }
+                (BarChart { bars: &bars })
+                h3 { "Consoles" }
+                ul {
+                    @for (console, _) in &self.consoles {
+                        li {
+                            a href={ "/consoles/" (console.id()) "/statistics.html" } {
+                                (console.name()) " (" (console.code()) ")"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Charts of one console's submissions
+pub struct ConsoleStatistics<'a, M, P> {
+    pub submissions: &'a [LegacySubmission<M, P>],
+}
+
+impl<'a, M: LegacyConsoleMetadata, P> Render for ConsoleStatistics<'a, M, P> {
+    fn render(&self) -> Markup {
+        let console = M::CONSOLE;
+        let metadata = || {
+            self.submissions
+                .iter()
+                .map(|submission| &submission.metadata)
+        };
+        let boards = metadata()
+            .map(|metadata| metadata.mainboard().kind.to_owned())
+            .counts()
+            .into_iter()
+            .sorted()
+            .collect::<Vec<_>>();
+        let colors = metadata()
+            .map(|metadata| metadata.shell().color.unwrap_or("Unknown").to_owned())
+            .counts()
+            .into_iter()
+            .sorted_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then(a.cmp(b)))
+            .collect::<Vec<_>>();
+        let board_years = year_columns(&year_buckets(
+            metadata().filter_map(|metadata| metadata.mainboard().date_code.year),
+        ));
+        let shell_years = year_columns(&year_buckets(
+            metadata().filter_map(|metadata| metadata.shell().date_code.year),
+        ));
+        let manufacturer_shares = M::parts()
+            .into_iter()
+            .filter_map(|chip| {
+                let mut counts: BTreeMap<u16, BTreeMap<Manufacturer, usize>> = BTreeMap::new();
+                for part in metadata().filter_map(|metadata| (chip.getter)(metadata)) {
+                    if let (Some(year), Some(manufacturer)) =
+                        (part.date_code.year, part.manufacturer)
+                    {
+                        *counts
+                            .entry(year)
+                            .or_default()
+                            .entry(manufacturer)
+                            .or_default() += 1;
+                    }
+                }
+                let manufacturers = counts
+                    .values()
+                    .flat_map(|by_manufacturer| by_manufacturer.keys().copied())
+                    .collect::<BTreeSet<_>>();
+                let series = manufacturers
+                    .iter()
+                    .map(|manufacturer| manufacturer.name().to_owned())
+                    .collect::<Vec<_>>();
+                let columns = counts
+                    .into_iter()
+                    .map(|(year, by_manufacturer)| {
+                        let counts = manufacturers
+                            .iter()
+                            .map(|manufacturer| {
+                                by_manufacturer.get(manufacturer).copied().unwrap_or(0)
+                            })
+                            .collect();
+                        (year.to_string(), counts)
+                    })
+                    .collect::<Vec<_>>();
+                (!columns.is_empty()).then_some((chip, series, columns))
+            })
+            .collect::<Vec<_>>();
+        html! {
+            article {
+                h2 { (console.name()) " (" (console.code()) "): statistics" }
+                p {
+                    "Based on " (self.submissions.len()) " submissions. See also "
+                    a href={ "/consoles/" (console.id()) "/origins.html" } { "part origins by year" }
+                    "."
+                }
+                @if !boards.is_empty() {
+                    h3 { "Mainboard revisions" }
+                    (BarChart { bars: &boards })
+                }
+                @if !board_years.is_empty() {
+                    h3 { "Mainboard date codes" }
+                    (ColumnChart { columns: &board_years })
+                }
+                @if !shell_years.is_empty() {
+                    h3 { "Shell date codes" }
+                    (ColumnChart { columns: &shell_years })
+                }
+                @if colors.iter().any(|(color, _)| color != "Unknown") {
+                    h3 { "Shell colors" }
+                    (BarChart { bars: &colors })
+                }
+                @if !manufacturer_shares.is_empty() {
+                    h3 { "Part manufacturers by year" }
+                    @for (chip, series, columns) in &manufacturer_shares {
+                        h4 { (chip.label) " (" (chip.designator) ")" }
+                        (ShareChart { series, columns })
+                    }
+                }
+            }
+        }
+    }
+}
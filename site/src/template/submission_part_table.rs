@@ -1,6 +1,11 @@
-use maud::{html, Markup};
+use gbhwdb_backend::{config::part_catalog::PartInfo, parser::normalize::Substitution};
+use itertools::Itertools;
+use maud::{html, Markup, Render};
 
-use crate::{process::part::ProcessedPart, template::Optional};
+use crate::{
+    process::part::ProcessedPart,
+    template::{part_page::part_url, Optional},
+};
 
 pub struct SubmissionPart<'a> {
     pub designator: &'a str,
@@ -27,10 +32,30 @@ pub fn submission_part_table<'a>(parts: impl Iterator<Item = SubmissionPart<'a>>
                         td { (designator) }
                         td { (label) }
                         @if let Some(part) = part {
-                            td { (Optional(part.kind.as_ref())) }
+                            td {
+                                @if let Some(kind) = part.kind.as_deref() {
+                                    a href=(part_url(kind)) { (kind) }
+                                    @if let Some(info) = PartInfo::from_kind(kind) {
+                                        br;
+                                        small.submission-part__spec {
+                                            (info.function)
+                                            @if let Some(package) = info.package {
+                                                ", " (package)
+                                            }
+                                        }
+                                    }
+                                }
+                                @if let Some(spec) = part.spec() {
+                                    br;
+                                    small.submission-part__spec { (spec) }
+                                }
+                            }
                             td { (Optional(part.manufacturer.as_ref().map(|m| m.name()))) }
                             td { (Optional(part.date_code.calendar())) }
-                            td { (Optional(part.label.as_ref())) }
+                            td {
+                                (Optional(part.label.as_ref()))
+                                (Substitutions(&part.substitutions))
+                            }
                         } @else {
                             td;
                             td;
@@ -43,3 +68,19 @@ pub fn submission_part_table<'a>(parts: impl Iterator<Item = SubmissionPart<'a>>
         }
     }
 }
+
+/// Changes that were made to a label before it could be parsed
+pub struct Substitutions<'a>(pub &'a [Substitution]);
+
+impl<'a> Render for Substitutions<'a> {
+    fn render(&self) -> Markup {
+        html! {
+            @if !self.0.is_empty() {
+                br;
+                small.submission-part__substitutions {
+                    "Read as: " (self.0.iter().join(", "))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use gbhwdb_backend::Console;
+use maud::{html, Markup, Render};
+
+/// "What's inside my unit" lookup that runs client-side (see the gbhwdb-wasm crate)
+pub struct UnitLookup;
+
+impl Render for UnitLookup {
+    fn render(&self) -> Markup {
+        html! {
+            article.unit-lookup {
+                h2 { "What's inside my unit?" }
+                p {
+                    "Enter what you can see on the outside of your unit, and the most likely mainboard and chips are "
+                    "predicted from the most similar units in the database. "
+                    "Units with a nearby serial number count the most, followed by the release code, the stamp and the shell color."
+                }
+                p {
+                    "The prediction is only as good as the submissions it is based on, "
+                    "so check the confidence and the supporting units before relying on it."
+                }
+                noscript { p { strong { "This page requires JavaScript and WebAssembly." } } }
+                form #unit-lookup-form {
+                    label for="unit-lookup-console" { "Console" }
+                    select #unit-lookup-console {
+                        @for console in Console::ALL {
+                            option value=(console.id()) { (console.code()) " - " (console.name()) }
+                        }
+                    }
+                    label for="unit-lookup-serial" { "Serial" }
+                    input #unit-lookup-serial type="text" size="16" autocomplete="off";
+                    label for="unit-lookup-release-code" { "Release code" }
+                    input #unit-lookup-release-code type="text" size="12" autocomplete="off";
+                    label for="unit-lookup-stamp" { "Stamp" }
+                    input #unit-lookup-stamp type="text" size="8" autocomplete="off";
+                    label for="unit-lookup-color" { "Shell color" }
+                    input #unit-lookup-color type="text" size="12" autocomplete="off";
+                    button type="submit" { "Look up" }
+                }
+                div #unit-lookup-result {}
+                p {
+                    "The same lookup is available on the command line with "
+                    code { "gbhwdb-predict" }
+                    " in the gbhwdb-tools crate."
+                }
+                script type="module" src="/unit-lookup.js" {}
+            }
+        }
+    }
+}
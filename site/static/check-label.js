@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+import init, { checkLabel, checkMetadata, identify } from '/wasm/gbhwdb_wasm.js'
+
+function element(tag, text) {
+  const el = document.createElement(tag)
+  if (text !== undefined) el.textContent = text
+  return el
+}
+
+function renderDiagnostics(target, diagnostics) {
+  target.replaceChildren()
+  if (diagnostics.length === 0) {
+    target.append(element('p', 'No problems found'))
+    return
+  }
+  const list = element('ul')
+  for (const diagnostic of diagnostics) {
+    const prefix = diagnostic.pointer ? `${diagnostic.pointer}: ` : ''
+    const item = element('li', `${diagnostic.severity}: ${prefix}${diagnostic.message}`)
+    item.className = `check-label__${diagnostic.severity}`
+    list.append(item)
+  }
+  target.append(list)
+}
+
+function renderCandidates(target, candidates) {
+  target.replaceChildren()
+  if (candidates.length === 0) {
+    target.append(element('p', 'The label was not recognized by any parser'))
+    return
+  }
+  const table = element('table')
+  const header = element('tr')
+  for (const title of ['Part', 'Kind', 'Manufacturer', 'ROM code', 'Year', 'Month', 'Week', 'Country']) {
+    header.append(element('th', title))
+  }
+  table.append(header)
+  for (const candidate of candidates) {
+    const row = element('tr')
+    for (const value of [
+      candidate.familyName,
+      candidate.kind,
+      candidate.manufacturer,
+      candidate.romCode,
+      candidate.year,
+      candidate.month,
+      candidate.week,
+      candidate.country,
+    ]) {
+      row.append(element('td', value ?? ''))
+    }
+    table.append(row)
+  }
+  target.append(table)
+}
+
+async function main() {
+  await init()
+  const family = document.getElementById('check-label-family')
+  const label = document.getElementById('check-label-text')
+  const labelResult = document.getElementById('check-label-result')
+  document.getElementById('check-label-form').addEventListener('submit', (event) => {
+    event.preventDefault()
+    if (family.value) {
+      renderDiagnostics(labelResult, checkLabel(family.value, label.value))
+    } else {
+      renderCandidates(labelResult, identify(label.value))
+    }
+  })
+  const kind = document.getElementById('check-metadata-kind')
+  const metadata = document.getElementById('check-metadata-text')
+  const metadataResult = document.getElementById('check-metadata-result')
+  document.getElementById('check-metadata-form').addEventListener('submit', (event) => {
+    event.preventDefault()
+    renderDiagnostics(metadataResult, checkMetadata(kind.value, metadata.value))
+  })
+}
+
+main().catch((err) => {
+  document.getElementById('check-label-result').textContent = `Failed to load the label checker: ${err}`
+})
@@ -0,0 +1,62 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+for (const form of document.querySelectorAll('form.listing-filters')) {
+  const table = form.parentElement.querySelector('table')
+  const rows = Array.from(table.tBodies[0].rows, (row) => ({
+    row,
+    facets: JSON.parse(row.dataset.facets),
+  }))
+  const selects = Array.from(form.querySelectorAll('select[data-facet]'))
+  const count = form.querySelector('.listing-filters__count')
+  const compare = form.querySelector('.listing-filters__compare')
+
+  const update = () => {
+    const selected = selects.filter((select) => select.value !== '')
+    let visible = 0
+    for (const { row, facets } of rows) {
+      const matches = selected.every((select) =>
+        (facets[select.dataset.facet] || []).includes(select.value),
+      )
+      row.hidden = !matches
+      if (matches) visible++
+    }
+    count.textContent = `Showing ${visible} of ${rows.length} submissions`
+  }
+
+  form.addEventListener('change', update)
+  // the reset event fires before the selects are cleared
+  form.addEventListener('reset', () => setTimeout(update))
+  form.hidden = false
+  update()
+
+  // comparison pages are generated on demand by the development server, so the button is only
+  // shown if the server answers for the comparison path
+  fetch(compare.dataset.url, { method: 'HEAD' })
+    .then((response) => {
+      if (response.ok) enableCompare(table, rows, compare)
+    })
+    .catch(() => {})
+}
+
+function enableCompare(table, rows, compare) {
+  const checkboxes = rows.map(({ row }) => {
+    const checkbox = document.createElement('input')
+    checkbox.type = 'checkbox'
+    checkbox.value = row.dataset.slug
+    checkbox.title = 'Select for comparison'
+    row.cells[0].prepend(checkbox)
+    return checkbox
+  })
+  const selectedSlugs = () =>
+    checkboxes.filter((checkbox) => checkbox.checked).map((checkbox) => checkbox.value)
+
+  table.addEventListener('change', () => {
+    compare.disabled = selectedSlugs().length < 2
+  })
+  compare.addEventListener('click', () => {
+    window.location.href = `${compare.dataset.url}${selectedSlugs().join(',')}.html`
+  })
+  compare.hidden = false
+}
@@ -0,0 +1,66 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+const MAX_RESULTS = 100
+
+const form = document.getElementById('search-form')
+const query = document.getElementById('search-query')
+const status = document.getElementById('search-status')
+const results = document.getElementById('search-results')
+
+let index
+
+async function loadIndex() {
+  if (!index) {
+    const response = await fetch('/static/search-index.json')
+    if (!response.ok) throw new Error(`${response.status} ${response.statusText}`)
+    index = await response.json()
+  }
+  return index
+}
+
+function search(entries, text) {
+  const words = text.toLowerCase().split(/\s+/).filter((word) => word.length > 0)
+  if (words.length === 0) return []
+  return entries.filter((entry) => words.every((word) => entry.k.includes(word)))
+}
+
+function renderResults(matches) {
+  results.replaceChildren()
+  status.textContent =
+    matches.length > MAX_RESULTS
+      ? `${matches.length} matches, showing the first ${MAX_RESULTS}`
+      : `${matches.length} matches`
+  for (const entry of matches.slice(0, MAX_RESULTS)) {
+    const item = document.createElement('li')
+    const link = document.createElement('a')
+    link.href = entry.u
+    link.textContent = entry.t
+    item.append(link, ` - ${entry.d}`)
+    results.append(item)
+  }
+}
+
+async function run() {
+  const text = query.value
+  const url = new URL(window.location)
+  url.searchParams.set('q', text)
+  window.history.replaceState(null, '', url)
+  try {
+    renderResults(search(await loadIndex(), text))
+  } catch (err) {
+    status.textContent = `Failed to load the search index: ${err.message}`
+  }
+}
+
+form.addEventListener('submit', (event) => {
+  event.preventDefault()
+  run()
+})
+
+const initial = new URLSearchParams(window.location.search).get('q')
+if (initial) {
+  query.value = initial
+  run()
+}
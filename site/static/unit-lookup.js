@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+import init, { predict } from '/wasm/gbhwdb_wasm.js'
+
+function element(tag, text) {
+  const el = document.createElement(tag)
+  if (text !== undefined) el.textContent = text
+  return el
+}
+
+function link(href, text) {
+  const el = element('a', text)
+  el.href = href
+  return el
+}
+
+const indexes = new Map()
+
+async function loadIndex(consoleId) {
+  if (!indexes.has(consoleId)) {
+    const response = await fetch(`/static/export/consoles/${consoleId}-units.json`)
+    if (!response.ok) throw new Error(`${response.status} ${response.statusText}`)
+    indexes.set(consoleId, await response.json())
+  }
+  return indexes.get(consoleId)
+}
+
+function formatVote(vote, units) {
+  return `${vote.value} (${Math.round(vote.confidence * 100)}% confidence, ${vote.support} of ${units} units)`
+}
+
+function renderPrediction(target, consoleId, prediction) {
+  target.replaceChildren()
+  if (prediction.neighbours.length === 0) {
+    target.append(element('p', 'No similar units in the database'))
+    return
+  }
+  const units = prediction.neighbours.length
+  const table = element('table')
+  const rows = []
+  if (prediction.mainboard) rows.push(['Mainboard', prediction.mainboard])
+  for (const chip of prediction.chips) rows.push([`${chip.label} (${chip.designator})`, chip])
+  for (const [label, vote] of rows) {
+    const row = element('tr')
+    row.append(element('th', label), element('td', formatVote(vote, units)))
+    table.append(row)
+  }
+  target.append(table)
+  target.append(element('h3', 'Based on'))
+  const list = element('ul')
+  for (const neighbour of prediction.neighbours) {
+    const item = element('li')
+    item.append(
+      link(`/consoles/${consoleId}/${neighbour.slug}.html`, neighbour.title),
+      ` (similarity ${neighbour.similarity.toFixed(2)})`,
+    )
+    list.append(item)
+  }
+  target.append(list)
+}
+
+async function main() {
+  await init()
+  const consoleSelect = document.getElementById('unit-lookup-console')
+  const result = document.getElementById('unit-lookup-result')
+  const value = (id) => document.getElementById(id).value.trim() || null
+  document.getElementById('unit-lookup-form').addEventListener('submit', (event) => {
+    event.preventDefault()
+    const query = {
+      serial: value('unit-lookup-serial'),
+      releaseCode: value('unit-lookup-release-code'),
+      stamp: value('unit-lookup-stamp'),
+      color: value('unit-lookup-color'),
+    }
+    const id = consoleSelect.value
+    loadIndex(id)
+      .then((index) => renderPrediction(result, id, predict(index, query)))
+      .catch((err) => {
+        result.textContent = `Lookup failed: ${err}`
+      })
+  })
+}
+
+main().catch((err) => {
+  document.getElementById('unit-lookup-result').textContent = `Failed to load the lookup: ${err}`
+})
@@ -0,0 +1,57 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::Error;
+use gbhwdb_backend::parser::corpus::{format_corpus, parse_corpus, CorpusEntry, CORPUS_PATH};
+use gbhwdb_tools::labels::collect_label_entries;
+use std::{collections::BTreeMap, fs, path::Path};
+
+/// Regenerates the label regression corpus from data/
+fn main() -> Result<(), Error> {
+    let path = Path::new("backend").join(CORPUS_PATH);
+    let old = match fs::read_to_string(&path) {
+        Ok(json) => parse_corpus(&json)?,
+        Err(_) => Vec::new(),
+    };
+
+    // label formats are not installed, because the regression test only sees the hand-written
+    // parsers
+    let entries = collect_label_entries(Path::new("data"))?
+        .into_iter()
+        .filter(|entry| !entry.families.is_empty())
+        .filter_map(|entry| {
+            let label = entry.label?;
+            Some(CorpusEntry::new(&label, &entry.families))
+        })
+        .collect::<Vec<_>>();
+    let json = format_corpus(&entries)?;
+    let new = parse_corpus(&json)?;
+
+    let key = |entry: &CorpusEntry| (entry.label.clone(), entry.families.clone());
+    let old = old
+        .into_iter()
+        .map(|e| (key(&e), e))
+        .collect::<BTreeMap<_, _>>();
+    let new = new
+        .into_iter()
+        .map(|e| (key(&e), e))
+        .collect::<BTreeMap<_, _>>();
+    let added = new.keys().filter(|k| !old.contains_key(*k)).count();
+    let removed = old.keys().filter(|k| !new.contains_key(*k)).count();
+    let changed = new
+        .iter()
+        .filter(|(k, entry)| old.get(*k).is_some_and(|old| old != *entry))
+        .count();
+
+    fs::write(&path, json)?;
+    println!(
+        "Wrote {} labels to {} ({} added, {} removed, {} changed)",
+        new.len(),
+        path.display(),
+        added,
+        removed,
+        changed
+    );
+    Ok(())
+}
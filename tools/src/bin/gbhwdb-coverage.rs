@@ -0,0 +1,182 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::Error;
+use clap::{Arg, ArgAction, Command};
+use gbhwdb_backend::{config::label_format::load_label_formats, parser::PartFamily};
+use gbhwdb_tools::labels::{collect_label_entries, LabelEntry};
+use itertools::Itertools;
+use serde::Serialize;
+use std::{collections::BTreeMap, path::Path};
+
+#[derive(Clone, Debug, Default, Serialize)]
+struct Report {
+    parsers: Vec<ParserHits>,
+    unmatched: Vec<Finding>,
+    fallbacks: Vec<Finding>,
+    multiple_matches: Vec<Finding>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct ParserHits {
+    family: &'static str,
+    parser: &'static str,
+    hits: usize,
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct Finding {
+    path: String,
+    pointer: String,
+    label: Option<String>,
+    families: Vec<&'static str>,
+    /// Parsers that accepted the label, or the reason for a fallback
+    details: Vec<&'static str>,
+}
+
+impl Finding {
+    fn new(entry: &LabelEntry, details: Vec<&'static str>) -> Finding {
+        Finding {
+            path: entry.path.display().to_string(),
+            pointer: entry.pointer.clone(),
+            label: entry.label.clone(),
+            families: entry.families.iter().map(|family| family.id()).collect(),
+            details,
+        }
+    }
+}
+
+fn main() -> Result<(), Error> {
+    let matches = Command::new("gbhwdb-coverage")
+        .about("Reports which parsers match the labels in data/")
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Print the report as JSON"),
+        )
+        .get_matches();
+
+    load_label_formats("config/label-formats.json")?.install()?;
+    let entries = collect_label_entries(Path::new("data"))?;
+    let report = build_report(&entries);
+    if matches.get_flag("json") {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_text(&report);
+    }
+    Ok(())
+}
+
+fn build_report(entries: &[LabelEntry]) -> Report {
+    let mut hits = BTreeMap::new();
+    for family in PartFamily::ALL {
+        for parser in family.parser_names() {
+            hits.insert((family.id(), parser), 0);
+        }
+    }
+    let mut report = Report::default();
+    for entry in entries {
+        let label = match &entry.label {
+            Some(label) => label,
+            None => {
+                report.fallbacks.push(Finding::new(entry, vec!["no label"]));
+                continue;
+            }
+        };
+        if entry.families.is_empty() {
+            report
+                .fallbacks
+                .push(Finding::new(entry, vec!["no parser for role"]));
+            continue;
+        }
+        let matching = entry
+            .families
+            .iter()
+            .flat_map(|family| {
+                family
+                    .matching_parsers(label)
+                    .into_iter()
+                    .map(|parser| (family.id(), parser))
+            })
+            .unique()
+            .collect::<Vec<_>>();
+        for key in &matching {
+            *hits.entry(*key).or_default() += 1;
+        }
+        // label formats are only used if no hand-written parser accepts the label
+        let hand_written = matching.iter().any(|(_, parser)| !is_label_format(parser));
+        let parsers = matching
+            .iter()
+            .map(|(_, parser)| *parser)
+            .filter(|parser| !hand_written || !is_label_format(parser))
+            .collect::<Vec<_>>();
+        match parsers.as_slice() {
+            [] => report.unmatched.push(Finding::new(entry, Vec::new())),
+            [parser] if is_generic(parser) => {
+                report.fallbacks.push(Finding::new(entry, parsers.clone()))
+            }
+            [_] => (),
+            _ => report
+                .multiple_matches
+                .push(Finding::new(entry, parsers.clone())),
+        }
+    }
+    report.parsers = hits
+        .into_iter()
+        .map(|((family, parser), hits)| ParserHits {
+            family,
+            parser,
+            hits,
+        })
+        .collect();
+    report
+}
+
+/// Label formats defined in config/, e.g. "config:lcs5"
+fn is_label_format(parser: &str) -> bool {
+    parser.starts_with("config:")
+}
+
+/// Catch-all parsers, e.g. "oxy_u4::unknown"
+fn is_generic(parser: &str) -> bool {
+    parser
+        .rsplit("::")
+        .next()
+        .is_some_and(|name| name.starts_with("unknown"))
+}
+
+fn print_text(report: &Report) {
+    println!("Parser hits:");
+    for hits in &report.parsers {
+        if hits.parser.starts_with(&format!("{}::", hits.family)) {
+            println!("{:>6}  {}", hits.hits, hits.parser);
+        } else {
+            println!("{:>6}  {} (via {})", hits.hits, hits.parser, hits.family);
+        }
+    }
+    let dead = report.parsers.iter().filter(|hits| hits.hits == 0).count();
+    println!("{} of {} parsers have no hits", dead, report.parsers.len());
+    print_findings("Unmatched labels", &report.unmatched);
+    print_findings("Fallbacks", &report.fallbacks);
+    print_findings("Multiple matches", &report.multiple_matches);
+}
+
+fn print_findings(title: &str, findings: &[Finding]) {
+    println!();
+    println!("{} ({}):", title, findings.len());
+    for finding in findings {
+        println!(
+            "  {}#{}: {} [{}] {}",
+            finding.path,
+            finding.pointer,
+            match &finding.label {
+                Some(label) => format!("{:?}", label),
+                None => "-".to_owned(),
+            },
+            finding.families.join(", "),
+            finding.details.join(", ")
+        );
+    }
+}
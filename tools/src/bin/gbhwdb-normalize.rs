@@ -0,0 +1,114 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::Error;
+use clap::{Arg, ArgAction, Command};
+use gbhwdb_backend::{
+    config::label_format::load_label_formats,
+    parser::{
+        identify,
+        normalize::{normalize, normalize_with, Substitution},
+    },
+};
+use glob::glob;
+use itertools::Itertools;
+use serde_json::Value;
+use std::fs;
+
+fn main() -> Result<(), Error> {
+    let matches = Command::new("gbhwdb-normalize")
+        .about("Reports labels in data/ that are not in canonical form")
+        .arg(
+            Arg::new("write")
+                .long("write")
+                .action(ArgAction::SetTrue)
+                .help("Write canonical labels back to metadata.json files"),
+        )
+        .get_matches();
+    let write = matches.get_flag("write");
+    load_label_formats("config/label-formats.json")?.install()?;
+
+    let mut changed_files = 0;
+    for entry in glob("data/**/metadata.json")? {
+        let path = entry?;
+        let mut json: Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        let mut labels = Vec::new();
+        collect_labels(&json, String::new(), &mut labels);
+
+        let mut changed = false;
+        for (pointer, label) in labels {
+            let normalized = normalize(&label);
+            if normalized.is_changed() {
+                println!(
+                    "{}: {:?} -> {:?} ({})",
+                    path.display(),
+                    label,
+                    normalized.text,
+                    format_substitutions(&normalized.substitutions)
+                );
+                if let Some(value) = json.pointer_mut(&pointer) {
+                    *value = Value::String(normalized.text);
+                    changed = true;
+                }
+            } else if identify(&label).is_empty() {
+                // confusions are only suggested, because fixing them changes the meaning of the label
+                if let Ok((candidates, normalized)) = normalize_with(&label, |label| {
+                    Some(identify(label))
+                        .filter(|candidates| !candidates.is_empty())
+                        .ok_or_else(|| "no match".to_owned())
+                }) {
+                    println!(
+                        "{}: {:?} might be {:?} ({}), looks like {}",
+                        path.display(),
+                        label,
+                        normalized.text,
+                        format_substitutions(&normalized.substitutions),
+                        candidates[0].family.name()
+                    );
+                }
+            }
+        }
+        if changed {
+            changed_files += 1;
+            if write {
+                // serde_json preserves the key order, so only the changed labels differ
+                let mut text = serde_json::to_string_pretty(&json)?;
+                text.push('\n');
+                fs::write(&path, text)?;
+            }
+        }
+    }
+    if write {
+        println!("Wrote {changed_files} files");
+    } else if changed_files > 0 {
+        println!("{changed_files} files have non-canonical labels, use --write to fix them");
+    }
+    Ok(())
+}
+
+/// Collects every label in the document, along with its JSON pointer
+fn collect_labels(value: &Value, pointer: String, labels: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let key = key.replace('~', "~0").replace('/', "~1");
+                let pointer = format!("{pointer}/{key}");
+                match value {
+                    Value::String(label) if key == "label" => labels.push((pointer, label.clone())),
+                    _ => collect_labels(value, pointer, labels),
+                }
+            }
+        }
+        Value::Array(values) => {
+            for (idx, value) in values.iter().enumerate() {
+                collect_labels(value, format!("{pointer}/{idx}"), labels);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn format_substitutions(substitutions: &[Substitution]) -> String {
+    substitutions.iter().map(|s| s.to_string()).join(", ")
+}
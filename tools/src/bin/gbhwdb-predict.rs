@@ -0,0 +1,108 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{anyhow, Error};
+use clap::{Arg, ArgAction, Command};
+use gbhwdb_backend::{
+    prediction::{Query, UnitIndex, Vote},
+    Console,
+};
+use std::{fs::File, io::BufReader, path::Path};
+
+fn main() -> Result<(), Error> {
+    let matches = Command::new("gbhwdb-predict")
+        .about("Predicts the mainboard and chips of a console from its serial and shell markings")
+        .after_help(
+            "The prediction uses the unit indexes written by gbhwdb-site, so build the site first.",
+        )
+        .arg(
+            Arg::new("console")
+                .required(true)
+                .help("Console code, e.g. CGB"),
+        )
+        .arg(Arg::new("serial").help("Serial number on the shell, e.g. C10203977"))
+        .arg(Arg::new("color").long("color").help("Shell color"))
+        .arg(
+            Arg::new("release-code")
+                .long("release-code")
+                .help("Release code on the shell, e.g. CGB-JPN"),
+        )
+        .arg(
+            Arg::new("stamp")
+                .long("stamp")
+                .help("Stamp inside the shell"),
+        )
+        .arg(
+            Arg::new("index-dir")
+                .long("index-dir")
+                .default_value("build/static/export/consoles")
+                .help("Directory of the unit indexes"),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .action(ArgAction::SetTrue)
+                .help("Print the prediction as JSON"),
+        )
+        .get_matches();
+
+    let console = matches.get_one::<String>("console").unwrap();
+    let console = Console::ALL
+        .into_iter()
+        .find(|candidate| candidate.code().eq_ignore_ascii_case(console))
+        .ok_or_else(|| anyhow!("Unknown console {console}"))?;
+    let query = Query {
+        serial: matches.get_one::<String>("serial").cloned(),
+        color: matches.get_one::<String>("color").cloned(),
+        release_code: matches.get_one::<String>("release-code").cloned(),
+        stamp: matches.get_one::<String>("stamp").cloned(),
+    };
+    let path = Path::new(matches.get_one::<String>("index-dir").unwrap())
+        .join(format!("{}-units.json", console.id()));
+    let file = File::open(&path)
+        .map_err(|err| anyhow!("Failed to open unit index {}: {err}", path.display()))?;
+    let index: UnitIndex = serde_json::from_reader(BufReader::new(file))?;
+
+    let prediction = index.predict(&query);
+    if matches.get_flag("json") {
+        println!("{}", serde_json::to_string_pretty(&prediction)?);
+        return Ok(());
+    }
+    if prediction.neighbours.is_empty() {
+        println!("No similar {} units in the database", console.code());
+        return Ok(());
+    }
+    let format_vote = |vote: &Vote| {
+        format!(
+            "{} ({:.0}% confidence, {} of {} units)",
+            vote.value,
+            vote.confidence * 100.0,
+            vote.support,
+            prediction.neighbours.len()
+        )
+    };
+    if let Some(mainboard) = &prediction.mainboard {
+        println!("Mainboard: {}", format_vote(mainboard));
+    }
+    for chip in &prediction.chips {
+        println!(
+            "{} ({}): {}",
+            chip.label,
+            chip.designator,
+            format_vote(&chip.vote)
+        );
+    }
+    println!();
+    println!("Based on:");
+    for neighbour in &prediction.neighbours {
+        println!(
+            "  {} (similarity {:.2}): https://gbhwdb.gekkio.fi/consoles/{}/{}.html",
+            neighbour.title,
+            neighbour.similarity,
+            console.id(),
+            neighbour.slug
+        );
+    }
+    Ok(())
+}
@@ -5,15 +5,23 @@
 use anyhow::Error;
 use cursive::{traits::*, views::*, Cursive, CursiveExt};
 use gbhwdb_backend::{
-    config::cartridge::{BoardLayout, GameConfig, PartRole, PartRoleConfig},
+    config::{
+        cartridge::{BoardLayout, GameConfig, PartRole, PartRoleConfig},
+        label_format::load_label_formats,
+    },
     input::{
         cartridge::{Cartridge, CartridgeBoard, CartridgeShell},
         Part,
     },
-    parser::{self, LabelParser},
+    parser::{
+        self,
+        normalize::{normalize, parse_normalized},
+        LabelParser,
+    },
     time::Month,
 };
 use gbhwdb_tools::cursive::*;
+use itertools::Itertools;
 use slug::slugify;
 use std::{
     collections::BTreeMap,
@@ -40,6 +48,7 @@ fn load_cfgs<P: AsRef<Path>>(path: P) -> Result<BTreeMap<String, GameConfig>, Er
 
 fn main() -> Result<(), Error> {
     let cfgs = load_cfgs("config/games.json")?;
+    load_label_formats("config/label-formats.json")?.install()?;
     let mut siv = Cursive::default();
     siv.add_global_callback('q', |s| {
         QUIT.store(true, atomic::Ordering::SeqCst);
@@ -323,7 +332,7 @@ fn part_editor(id: &str, role: Option<PartRole>) -> LinearLayout {
                 &details_id,
                 parser::line_decoder::line_decoder(),
             ),
-            _ => (),
+            _ => add_identify_callback(&mut editor, &details_id),
         }
         result.add_child(editor.with_name(id));
         result.add_child(details);
@@ -338,20 +347,59 @@ fn add_details_callback<T: fmt::Debug, F: LabelParser<T>>(
 ) {
     let details_id = details_id.to_owned();
     editor.set_on_edit(move |siv, content, _| {
-        siv.call_on_name(&details_id, |view: &mut TextView| match f.parse(&content) {
-            Ok(part) => view.set_content(format!("{:?}", part)),
-            Err(err) => view.set_content(format!("{}", err)),
+        siv.call_on_name(&details_id, |view: &mut TextView| {
+            match parse_normalized(f, &content) {
+                Ok((part, label)) if label.is_changed() => view.set_content(format!(
+                    "{:?}\nas {:?}: {}",
+                    part,
+                    label.text,
+                    label.substitutions.iter().map(|s| s.to_string()).join(", ")
+                )),
+                Ok((part, _)) => view.set_content(format!("{:?}", part)),
+                Err(err) => match parser::identify(&content).first() {
+                    Some(candidate) => view.set_content(format!(
+                        "{} (looks like {})",
+                        err,
+                        describe_identification(candidate)
+                    )),
+                    None => view.set_content(format!("{}", err)),
+                },
+            }
+        })
+        .unwrap();
+    });
+}
+
+fn add_identify_callback(editor: &mut EditView, details_id: &str) {
+    let details_id = details_id.to_owned();
+    editor.set_on_edit(move |siv, content, _| {
+        siv.call_on_name(&details_id, |view: &mut TextView| {
+            match parser::identify(&content).first() {
+                Some(candidate) => view.set_content(describe_identification(candidate)),
+                None => view.set_content("no match"),
+            }
         })
         .unwrap();
     });
 }
 
+fn describe_identification(candidate: &parser::Identification) -> String {
+    let mut result = candidate.family.name().to_owned();
+    if let Some(kind) = &candidate.kind {
+        result.push_str(&format!(" {}", kind));
+    }
+    if let Some(manufacturer) = candidate.manufacturer {
+        result.push_str(&format!(" by {}", manufacturer.name()));
+    }
+    result
+}
+
 fn add_part(siv: &mut Cursive, role: Option<PartRole>, id: &str) -> Option<Part> {
     role.map(|_| match siv.get_edit_view_value(id).as_str() {
         "-" => Part {
             label: None,
             outlier: false,
         },
-        label => Part::from_label(trim(label)),
+        label => Part::from_label(trim(&normalize(label).text)),
     })
 }
@@ -0,0 +1,69 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+use anyhow::{anyhow, Error};
+use gbhwdb_backend::{
+    diagnostics::{document_labels, DocumentKind},
+    parser::PartFamily,
+    Console,
+};
+use glob::glob;
+use serde_json::Value;
+use std::{
+    fs::File,
+    io::BufReader,
+    path::{Path, PathBuf},
+};
+
+/// Label found in a metadata.json file, along with the part families that should parse it
+#[derive(Clone, Debug)]
+pub struct LabelEntry {
+    pub path: PathBuf,
+    /// JSON pointer to the label, e.g. "/mainboard/u1/label"
+    pub pointer: String,
+    /// Empty if the part role has no parser
+    pub families: Vec<PartFamily>,
+    /// None if the part is present but has no label
+    pub label: Option<String>,
+}
+
+/// Collects all labels in console and cartridge submissions under `root`
+pub fn collect_label_entries(root: &Path) -> Result<Vec<LabelEntry>, Error> {
+    let mut entries = Vec::new();
+    for console in Console::ALL {
+        let pattern = root
+            .join("consoles")
+            .join(console.code())
+            .join("*/metadata.json");
+        collect_document(&mut entries, DocumentKind::Console(console), &pattern)?;
+    }
+    let pattern = root.join("cartridges/*/*/metadata.json");
+    collect_document(&mut entries, DocumentKind::Cartridge, &pattern)?;
+    Ok(entries)
+}
+
+fn collect_document(
+    entries: &mut Vec<LabelEntry>,
+    kind: DocumentKind,
+    pattern: &Path,
+) -> Result<(), Error> {
+    for path in glob(&pattern.to_string_lossy())? {
+        let path = path?;
+        let json = read_json(&path)?;
+        let labels =
+            document_labels(kind, &json).map_err(|err| anyhow!("{err} in {}", path.display()))?;
+        entries.extend(labels.into_iter().map(|label| LabelEntry {
+            path: path.clone(),
+            pointer: label.pointer,
+            families: label.families,
+            label: label.label,
+        }));
+    }
+    Ok(())
+}
+
+fn read_json(path: &Path) -> Result<Value, Error> {
+    let file = File::open(path)?;
+    Ok(serde_json::from_reader(BufReader::new(file))?)
+}
@@ -4,3 +4,4 @@
 
 pub mod cursive;
 pub mod dat;
+pub mod labels;
@@ -0,0 +1,117 @@
+// SPDX-FileCopyrightText: 2017-2024 Joonas Javanainen <joonas.javanainen@gmail.com>
+//
+// SPDX-License-Identifier: MIT
+
+//! Label parsers and the unit lookup compiled to WebAssembly for use in the browser
+//!
+//! Build with `just build-wasm`, which writes the JS bindings to build/wasm.
+
+use gbhwdb_backend::{
+    diagnostics::{self, DocumentKind},
+    parser::{self, Identification, PartFamily, Year},
+    prediction::{Query, UnitIndex},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::prelude::*;
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Family {
+    pub id: &'static str,
+    pub name: &'static str,
+}
+
+/// Candidate identification of a label
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candidate {
+    pub family: &'static str,
+    pub family_name: &'static str,
+    pub kind: Option<String>,
+    pub manufacturer: Option<&'static str>,
+    pub rom_code: Option<String>,
+    /// Full year, or a single digit for partial years
+    pub year: Option<String>,
+    pub month: Option<u8>,
+    pub week: Option<u8>,
+    pub country: Option<&'static str>,
+    pub score: u32,
+}
+
+impl From<Identification> for Candidate {
+    fn from(part: Identification) -> Self {
+        let score = part.score();
+        Candidate {
+            family: part.family.id(),
+            family_name: part.family.name(),
+            kind: part.kind,
+            manufacturer: part.manufacturer.map(|manufacturer| manufacturer.name()),
+            rom_code: part.rom_code,
+            year: part.date_code.year.map(|year| match year {
+                Year::Full(year) => year.to_string(),
+                Year::Partial(year) => year.to_string(),
+            }),
+            month: part.date_code.month.map(u8::from),
+            week: part.date_code.week.map(u8::from),
+            country: part.country.map(|country| country.name()),
+            score,
+        }
+    }
+}
+
+/// All part families, in the form `{id, name}`
+#[wasm_bindgen]
+pub fn families() -> Result<JsValue, JsError> {
+    let families = PartFamily::ALL
+        .into_iter()
+        .map(|family| Family {
+            id: family.id(),
+            name: family.name(),
+        })
+        .collect::<Vec<_>>();
+    to_js(&families)
+}
+
+/// Identifies a label with every part family, best candidates first
+#[wasm_bindgen]
+pub fn identify(label: &str) -> Result<JsValue, JsError> {
+    let candidates = parser::identify(label)
+        .into_iter()
+        .map(Candidate::from)
+        .collect::<Vec<_>>();
+    to_js(&candidates)
+}
+
+/// Checks a label against a single part family, returning a list of diagnostics
+#[wasm_bindgen(js_name = checkLabel)]
+pub fn check_label(family: &str, label: &str) -> Result<JsValue, JsError> {
+    let family = PartFamily::from_id(family)
+        .ok_or_else(|| JsError::new(&format!("Unknown part family {family}")))?;
+    to_js(&diagnostics::check_label("", &[family], label))
+}
+
+/// Validates a full metadata.json document, returning a list of diagnostics
+///
+/// `kind` is a console code (e.g. "DMG") or "cartridge".
+#[wasm_bindgen(js_name = checkMetadata)]
+pub fn check_metadata(kind: &str, json: &str) -> Result<JsValue, JsError> {
+    let kind = DocumentKind::from_code(kind)
+        .ok_or_else(|| JsError::new(&format!("Unknown document kind {kind}")))?;
+    to_js(&diagnostics::check_metadata(kind, json))
+}
+
+/// Predicts the hardware of a unit from a unit index and a query `{serial, color, releaseCode, stamp}`
+#[wasm_bindgen]
+pub fn predict(index: JsValue, query: JsValue) -> Result<JsValue, JsError> {
+    let index: UnitIndex = from_js(index)?;
+    let query: Query = from_js(query)?;
+    to_js(&index.predict(&query))
+}
+
+fn from_js<T: DeserializeOwned>(value: JsValue) -> Result<T, JsError> {
+    serde_wasm_bindgen::from_value(value).map_err(|err| JsError::new(&err.to_string()))
+}
+
+fn to_js<T: Serialize>(value: &T) -> Result<JsValue, JsError> {
+    serde_wasm_bindgen::to_value(value).map_err(|err| JsError::new(&err.to_string()))
+}